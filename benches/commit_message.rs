@@ -1,7 +1,107 @@
-use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use criterion::{
+    BatchSize, BenchmarkId, Criterion, Throughput, criterion_group, criterion_main,
+    measurement::{Measurement, ValueFormatter},
+};
 use indoc::indoc;
 use mit_commit::{CommitMessage, Trailer};
 
+/// Counts every allocation, so parsing can be measured in allocations as
+/// well as time
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+/// A criterion measurement of how many allocations a routine makes
+struct Allocations;
+
+impl Measurement for Allocations {
+    type Intermediate = usize;
+    type Value = usize;
+
+    fn start(&self) -> Self::Intermediate {
+        ALLOCATIONS.load(Ordering::Relaxed)
+    }
+
+    fn end(&self, start: Self::Intermediate) -> Self::Value {
+        ALLOCATIONS.load(Ordering::Relaxed) - start
+    }
+
+    fn add(&self, first: &Self::Value, second: &Self::Value) -> Self::Value {
+        first + second
+    }
+
+    fn zero(&self) -> Self::Value {
+        0
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn to_f64(&self, value: &Self::Value) -> f64 {
+        *value as f64
+    }
+
+    fn formatter(&self) -> &dyn ValueFormatter {
+        &AllocationsFormatter
+    }
+}
+
+struct AllocationsFormatter;
+
+impl ValueFormatter for AllocationsFormatter {
+    fn scale_values(&self, _typical_value: f64, _values: &mut [f64]) -> &'static str {
+        "allocations"
+    }
+
+    fn scale_throughputs(
+        &self,
+        _typical_value: f64,
+        throughput: &Throughput,
+        values: &mut [f64],
+    ) -> &'static str {
+        #[allow(clippy::cast_precision_loss)]
+        let (per, unit) = match *throughput {
+            Throughput::Bytes(bytes) | Throughput::BytesDecimal(bytes) => {
+                (bytes as f64, "allocations/byte")
+            }
+            Throughput::Elements(elements) => (elements as f64, "allocations/element"),
+            Throughput::Bits(bits) => (bits as f64, "allocations/bit"),
+        };
+
+        for value in values {
+            *value /= per;
+        }
+
+        unit
+    }
+
+    fn scale_for_machines(&self, _values: &mut [f64]) -> &'static str {
+        "allocations"
+    }
+}
+
 const COMMIT_MESSAGE_WITH_NO_COMMENTS: &str = indoc!(
     "
         Update bashrc to include kubernetes completions
@@ -215,17 +315,17 @@ const COMMIT_WITH_ALL_FEATURES: &str = indoc!(
         "
 );
 
+const MESSAGES: &[&str] = &[
+    COMMIT_MESSAGE_WITH_NO_COMMENTS,
+    MULTIPLE_TRAILERS,
+    NON_STANDARD_COMMENT_CHARACTER,
+    NOT_VERBOSE_COMMIT,
+    LONG_SUBJECT_ONLY_COMMIT,
+    COMMIT_WITH_ALL_FEATURES,
+];
+
 pub fn criterion_benchmark(c: &mut Criterion) {
-    let messages: &[&str] = &[
-        COMMIT_MESSAGE_WITH_NO_COMMENTS,
-        MULTIPLE_TRAILERS,
-        NON_STANDARD_COMMENT_CHARACTER,
-        NOT_VERBOSE_COMMIT,
-        LONG_SUBJECT_ONLY_COMMIT,
-        COMMIT_WITH_ALL_FEATURES,
-    ];
-
-    messages.iter().enumerate().for_each(|(number, message)| {
+    MESSAGES.iter().enumerate().for_each(|(number, message)| {
         c.bench_with_input(
             BenchmarkId::new("from_str", number),
             &message,
@@ -254,5 +354,46 @@ pub fn criterion_benchmark(c: &mut Criterion) {
     });
 }
 
+/// Parsing a `&str` borrows from it, so it should make far fewer
+/// allocations than parsing a `String`, which has to copy the text of every
+/// part into the message
+fn allocations_benchmark(c: &mut Criterion<Allocations>) {
+    let mut group = c.benchmark_group("allocations");
+
+    MESSAGES.iter().enumerate().for_each(|(number, message)| {
+        group.bench_with_input(
+            BenchmarkId::new("from_str", number),
+            message,
+            |b, &message| b.iter(|| CommitMessage::from(message)),
+        );
+        // The `String` is made outside of what is measured, so only the
+        // parsing is counted
+        group.bench_with_input(
+            BenchmarkId::new("from_string", number),
+            message,
+            |b, &message| {
+                b.iter_batched(
+                    || message.to_string(),
+                    CommitMessage::from,
+                    BatchSize::SmallInput,
+                );
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("from_str_into_owned", number),
+            message,
+            |b, &message| b.iter(|| CommitMessage::from(message).into_owned()),
+        );
+    });
+
+    group.finish();
+}
+
 criterion_group!(benches, criterion_benchmark);
-criterion_main!(benches);
+criterion_group! {
+    name = allocations;
+    // Allocation counts don't vary between samples, which the plots can't draw
+    config = Criterion::default().with_measurement(Allocations).without_plots();
+    targets = allocations_benchmark
+}
+criterion_main!(benches, allocations);
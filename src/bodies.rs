@@ -1,12 +1,11 @@
 use std::{
-    convert::TryFrom,
     fmt,
     fmt::{Display, Formatter},
     slice::Iter,
     vec::IntoIter,
 };
 
-use crate::{body::Body, fragment::Fragment, trailer::Trailer};
+use crate::{Trailers, body::Body, fragment::Fragment};
 
 /// A collection of body paragraphs from a commit message.
 ///
@@ -28,11 +27,29 @@ use crate::{body::Body, fragment::Fragment, trailer::Trailer};
 /// assert_eq!(Some(Body::from("First")), Bodies::from(bodies).first());
 /// ```
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Bodies<'a> {
     bodies: Vec<Body<'a>>,
 }
 
 impl Bodies<'_> {
+    /// Give you a [`Bodies`] that owns its text, so it can outlive the message it
+    /// was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`Bodies`], with any borrowed text copied
+    #[must_use]
+    pub fn into_owned(self) -> Bodies<'static> {
+        Bodies {
+            bodies: self.bodies.into_iter().map(Body::into_owned).collect(),
+        }
+    }
+
     /// Get the first [`Body`] in this list of [`Bodies`]
     ///
     /// # Arguments
@@ -312,6 +329,17 @@ impl<'a> From<Vec<Fragment<'a>>> for Bodies<'a> {
     /// );
     /// ```
     fn from(bodies: Vec<Fragment<'a>>) -> Self {
+        Self::from_fragments_with_separators(&bodies, None)
+    }
+}
+
+impl<'a> Bodies<'a> {
+    /// Extract the [`Bodies`] from an ast, treating trailing paragraphs
+    /// split on the given trailer separators as trailers
+    pub(crate) fn from_fragments_with_separators(
+        bodies: &[Fragment<'a>],
+        separators: Option<&str>,
+    ) -> Self {
         // Extract all Body fragments
         let raw_body = bodies
             .iter()
@@ -326,7 +354,7 @@ impl<'a> From<Vec<Fragment<'a>>> for Bodies<'a> {
             .iter()
             .skip(1)
             .rev()
-            .take_while(|body| body.is_empty() || Trailer::try_from((*body).clone()).is_ok())
+            .take_while(|body| body.is_empty() || Trailers::is_trailer_paragraph(body, separators))
             .count();
 
         // Calculate how many non-trailer items to keep, excluding the subject line
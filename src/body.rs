@@ -4,13 +4,46 @@ use std::{
     fmt::{Display, Formatter},
 };
 
+use crate::{Span, span};
+
 /// A single contiguous block of [`CommitMessage`] text
-#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Body<'a> {
     text: Cow<'a, str>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    span: Option<Span>,
 }
 
-impl Body<'_> {
+impl<'a> Body<'a> {
+    /// Give you a [`Body`] that owns its text, so it can outlive the message it
+    /// was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`Body`], with any borrowed text copied
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::Body;
+    ///
+    /// let body: Body<'static> = Body::from(String::from("Text").as_str()).into_owned();
+    ///
+    /// assert_eq!(body, Body::from("Text"));
+    /// ```
+    #[must_use]
+    pub fn into_owned(self) -> Body<'static> {
+        Body {
+            text: Cow::Owned(self.text.into_owned()),
+            span: self.span,
+        }
+    }
+
     /// Append one [`Body`] onto another
     ///
     /// # Arguments
@@ -38,7 +71,42 @@ impl Body<'_> {
     /// ```
     #[must_use]
     pub fn append(&self, additional: &Self) -> Self {
-        Self::from(format!("{}\n{}", self.text, additional.text))
+        Self {
+            text: format!("{}\n{}", self.text, additional.text).into(),
+            span: self
+                .span
+                .zip(additional.span)
+                .map(|(span, additional)| span.extend(additional)),
+        }
+    }
+
+    /// Append the next line of a message onto this one, borrowing the joined
+    /// text from the message when it can
+    pub(crate) fn append_in(&self, message: &'a str, additional: &Self) -> Self {
+        self.span
+            .zip(additional.span)
+            .and_then(|(span, additional)| {
+                span::joined(message, span, additional).map(|joined| Self {
+                    text: joined.into(),
+                    span: Some(span.extend(additional)),
+                })
+            })
+            .unwrap_or_else(|| self.append(additional))
+    }
+
+    /// Split a grouped [`Body`] back into the lines it was grouped from,
+    /// each with its own span
+    pub(crate) fn lines(&self, line_spans: &[Span]) -> Vec<Self> {
+        let first_line = self.span.map(|span| span.get_line());
+
+        span::split_lines(&self.text)
+            .into_iter()
+            .enumerate()
+            .map(|(index, text)| Self {
+                text,
+                span: first_line.and_then(|line| line_spans.get(line + index).copied()),
+            })
+            .collect()
     }
 
     /// Checks if this [`Body`] is empty
@@ -59,8 +127,55 @@ impl Body<'_> {
     pub fn is_empty(&self) -> bool {
         self.text.is_empty()
     }
+
+    /// Where this [`Body`] came from in the parsed [`CommitMessage`]
+    ///
+    /// # Returns
+    ///
+    /// The span of the body, or None if it wasn't parsed from a message
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{Body, CommitMessage, Fragment, Span};
+    ///
+    /// let message = CommitMessage::from("Subject\n\nSome body text");
+    ///
+    /// assert_eq!(
+    ///     message.get_body().iter().nth(1).and_then(Body::get_span),
+    ///     Some(Span::new(9, 14, 2, 0))
+    /// );
+    /// assert_eq!(Body::from("Not parsed").get_span(), None);
+    /// ```
+    #[must_use]
+    pub const fn get_span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// The text of this [`Body`]
+    pub(crate) fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Set where this [`Body`] came from
+    #[must_use]
+    pub(crate) fn with_span(self, span: Span) -> Self {
+        Self {
+            span: Some(span),
+            ..self
+        }
+    }
+}
+
+impl PartialEq for Body<'_> {
+    /// Bodies are equal if their text is, wherever they came from
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
 }
 
+impl Eq for Body<'_> {}
+
 impl<'a> From<Cow<'a, str>> for Body<'a> {
     /// Create a Body from a Cow<_, str>
     ///
@@ -84,7 +199,10 @@ impl<'a> From<Cow<'a, str>> for Body<'a> {
     /// assert_eq!(Body::from(input).to_string(), expected)
     /// ```
     fn from(body: Cow<'a, str>) -> Self {
-        Self { text: body }
+        Self {
+            text: body,
+            span: None,
+        }
     }
 }
 
@@ -242,4 +360,13 @@ mod tests {
             "Non-empty body should not be identified as empty"
         );
     }
+
+    #[test]
+    fn test_equality_ignores_span() {
+        assert_eq!(
+            Body::from("Example Body").with_span(Span::new(4, 12, 1, 0)),
+            Body::from("Example Body"),
+            "Bodies with the same text should be equal wherever they came from"
+        );
+    }
 }
@@ -0,0 +1,100 @@
+/// How a [`crate::CommitMessage`] is cleaned up before git records it
+///
+/// These mirror the modes of `git commit --cleanup=<mode>` and the
+/// `commit.cleanup` config option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CleanupMode {
+    /// Strip leading and trailing empty lines, trailing whitespace, and
+    /// comments, and collapse consecutive empty lines
+    Strip,
+    /// Same as [`CleanupMode::Strip`] except comments are kept
+    Whitespace,
+    /// Do not change the message at all
+    Verbatim,
+    /// Same as [`CleanupMode::Whitespace`], except everything from the
+    /// [`crate::Scissors`] line onwards is removed
+    Scissors,
+    /// What git does when the message is edited in an editor, which is the
+    /// same as [`CleanupMode::Strip`]
+    #[default]
+    Default,
+}
+
+/// Clean up lines the way `git stripspace` does
+///
+/// Trailing whitespace is removed from every line, leading and trailing
+/// empty lines are dropped, and runs of empty lines are collapsed into one.
+/// Every line in the output, including the last, ends with a newline.
+pub fn strip_space<'a>(lines: impl IntoIterator<Item = &'a str>) -> String {
+    let mut output = String::new();
+    let mut empties = 0_usize;
+
+    for line in lines {
+        let line = line.trim_end_matches([' ', '\t', '\n', '\r']);
+
+        if line.is_empty() {
+            empties += 1;
+            continue;
+        }
+
+        if empties > 0 && !output.is_empty() {
+            output.push('\n');
+        }
+        empties = 0;
+
+        output.push_str(line);
+        output.push('\n');
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_space_removes_leading_and_trailing_empty_lines() {
+        assert_eq!(
+            strip_space(["", "  ", "Subject", "", "\t"]),
+            "Subject\n",
+            "Leading and trailing empty lines should be removed"
+        );
+    }
+
+    #[test]
+    fn test_strip_space_collapses_empty_lines() {
+        assert_eq!(
+            strip_space(["Subject", "", "", "", "Body"]),
+            "Subject\n\nBody\n",
+            "Runs of empty lines should collapse into one"
+        );
+    }
+
+    #[test]
+    fn test_strip_space_trims_trailing_whitespace() {
+        assert_eq!(
+            strip_space(["Subject \t", "  Indented body  "]),
+            "Subject\n  Indented body\n",
+            "Trailing whitespace should be removed but leading whitespace kept"
+        );
+    }
+
+    #[test]
+    fn test_strip_space_of_nothing_is_empty() {
+        assert_eq!(
+            strip_space(["", "", ""]),
+            "",
+            "A message with only empty lines should become empty"
+        );
+    }
+
+    #[test]
+    fn test_default_mode_is_default() {
+        assert_eq!(
+            CleanupMode::default(),
+            CleanupMode::Default,
+            "The default cleanup mode should be git's default"
+        );
+    }
+}
@@ -1,14 +1,37 @@
 use std::borrow::Cow;
 
+use crate::{Span, span};
+
 const LEGAL_CHARACTERS: [char; 10] = ['#', ';', '@', '!', '$', '%', '^', '&', '|', ':'];
 
 /// A single comment from a `CommitMessage`
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Comment<'a> {
     comment: Cow<'a, str>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    span: Option<Span>,
 }
 
-impl Comment<'_> {
+impl<'a> Comment<'a> {
+    /// Give you a [`Comment`] that owns its text, so it can outlive the message it
+    /// was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`Comment`], with any borrowed text copied
+    #[must_use]
+    pub fn into_owned(self) -> Comment<'static> {
+        Comment {
+            comment: Cow::Owned(self.comment.into_owned()),
+            span: self.span,
+        }
+    }
+
     /// Append one [`Comment`] onto another
     ///
     /// # Arguments
@@ -36,7 +59,80 @@ impl Comment<'_> {
     /// ```
     #[must_use]
     pub fn append(&self, additional: &Self) -> Self {
-        Self::from(format!("{}\n{}", self.comment, additional.comment))
+        Self {
+            comment: format!("{}\n{}", self.comment, additional.comment).into(),
+            span: self
+                .span
+                .zip(additional.span)
+                .map(|(span, additional)| span.extend(additional)),
+        }
+    }
+
+    /// Append the next line of a message onto this one, borrowing the joined
+    /// text from the message when it can
+    pub(crate) fn append_in(&self, message: &'a str, additional: &Self) -> Self {
+        self.span
+            .zip(additional.span)
+            .and_then(|(span, additional)| {
+                span::joined(message, span, additional).map(|joined| Self {
+                    comment: joined.into(),
+                    span: Some(span.extend(additional)),
+                })
+            })
+            .unwrap_or_else(|| self.append(additional))
+    }
+
+    /// Split a grouped [`Comment`] back into the lines it was grouped from,
+    /// each with its own span
+    pub(crate) fn lines(&self, line_spans: &[Span]) -> Vec<Self> {
+        let first_line = self.span.map(|span| span.get_line());
+
+        span::split_lines(&self.comment)
+            .into_iter()
+            .enumerate()
+            .map(|(index, comment)| Self {
+                comment,
+                span: first_line.and_then(|line| line_spans.get(line + index).copied()),
+            })
+            .collect()
+    }
+
+    /// Where this [`Comment`] came from in the parsed [`crate::CommitMessage`]
+    ///
+    /// # Returns
+    ///
+    /// The span of the comment, or None if it wasn't parsed from a message
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{Comment, CommitMessage, Span};
+    ///
+    /// let message = CommitMessage::from("Subject\n\n# Comment 1\n# Comment 2");
+    ///
+    /// assert_eq!(
+    ///     message.get_comments().iter().next().and_then(Comment::get_span),
+    ///     Some(Span::new(9, 23, 2, 0))
+    /// );
+    /// assert_eq!(Comment::from("# Not parsed").get_span(), None);
+    /// ```
+    #[must_use]
+    pub const fn get_span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// The text of the comment, with its comment markers
+    pub(crate) fn as_str(&self) -> &str {
+        &self.comment
+    }
+
+    /// Set where this [`Comment`] came from
+    #[must_use]
+    pub(crate) fn with_span(self, span: Span) -> Self {
+        Self {
+            span: Some(span),
+            ..self
+        }
     }
 
     /// Checks if a given character is a valid comment character
@@ -63,6 +159,15 @@ impl Comment<'_> {
     }
 }
 
+impl PartialEq for Comment<'_> {
+    /// Comments are equal if their text is, wherever they came from
+    fn eq(&self, other: &Self) -> bool {
+        self.comment == other.comment
+    }
+}
+
+impl Eq for Comment<'_> {}
+
 impl<'a> From<Cow<'a, str>> for Comment<'a> {
     /// Create a Comment from a Cow<_, str>
     ///
@@ -74,7 +179,10 @@ impl<'a> From<Cow<'a, str>> for Comment<'a> {
     ///
     /// A new Comment containing the provided string
     fn from(comment: Cow<'a, str>) -> Self {
-        Self { comment }
+        Self {
+            comment,
+            span: None,
+        }
     }
 }
 
@@ -91,6 +199,7 @@ impl From<String> for Comment<'_> {
     fn from(comment: String) -> Self {
         Self {
             comment: comment.into(),
+            span: None,
         }
     }
 }
@@ -108,6 +217,7 @@ impl<'a> From<&'a str> for Comment<'a> {
     fn from(comment: &'a str) -> Self {
         Self {
             comment: comment.into(),
+            span: None,
         }
     }
 }
@@ -127,6 +237,21 @@ impl<'a> From<Comment<'a>> for String {
     }
 }
 
+impl<'a> From<Comment<'a>> for Cow<'a, str> {
+    /// Convert a Comment to a Cow<_, str>, without copying borrowed text
+    ///
+    /// # Arguments
+    ///
+    /// * `comment` - The comment to convert
+    ///
+    /// # Returns
+    ///
+    /// A Cow<_, str> containing the comment's text
+    fn from(comment: Comment<'a>) -> Self {
+        comment.comment
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use indoc::indoc;
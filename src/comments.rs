@@ -1,14 +1,36 @@
 use std::slice::Iter;
 
-use crate::{comment::Comment, fragment::Fragment};
+use crate::{
+    comment::Comment,
+    fragment::Fragment,
+    git_status::{self, GitStatus},
+};
 
 /// A collection of comments from a [`CommitMessage`]
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Comments<'a> {
     comments: Vec<Comment<'a>>,
 }
 
 impl Comments<'_> {
+    /// Give you a [`Comments`] that owns its text, so it can outlive the message it
+    /// was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`Comments`], with any borrowed text copied
+    #[must_use]
+    pub fn into_owned(self) -> Comments<'static> {
+        Comments {
+            comments: self.comments.into_iter().map(Comment::into_owned).collect(),
+        }
+    }
+
     /// Iterate over the [`Comment`] in the [`Comments`]
     ///
     /// # Returns
@@ -35,6 +57,87 @@ impl Comments<'_> {
     pub fn iter(&self) -> Iter<'_, Comment<'_>> {
         self.comments.iter()
     }
+
+    /// Read the status git writes in the commit template
+    ///
+    /// This works whatever comment marker the message uses, and in any of
+    /// the languages [`Comments::get_locale`] can recognise.
+    ///
+    /// # Returns
+    ///
+    /// The branch, upstream, files and anything in progress, which is empty
+    /// if the comments don't have the status in them
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{Comment, Comments, git_status::Head};
+    ///
+    /// let comments = Comments::from(vec![Comment::from(concat!(
+    ///     "; HEAD detached at 5f3c0a1\n",
+    ///     ";\n",
+    ///     "; Untracked files:\n",
+    ///     ";\tnotes.txt\n",
+    ///     ";"
+    /// ))]);
+    /// let status = comments.git_status();
+    ///
+    /// assert_eq!(status.get_head(), Some(&Head::DetachedAt("5f3c0a1".into())));
+    /// assert_eq!(status.get_untracked(), &["notes.txt"]);
+    /// ```
+    #[must_use]
+    pub fn git_status(&self) -> GitStatus<'_> {
+        GitStatus::parse(&self.lines())
+    }
+
+    /// Get the language git wrote the template in
+    ///
+    /// These are the languages git has a translation of the template for,
+    /// which are `en`, `de`, `fr`, `es`, `zh_CN` and `pt_PT`. Git writes
+    /// the template in English for languages it has no translation for, like
+    /// Japanese or Brazilian Portuguese.
+    ///
+    /// # Returns
+    ///
+    /// The locale, or [`None`] if there is nothing from git's template in the
+    /// comments
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::CommitMessage;
+    ///
+    /// let commit = CommitMessage::from(concat!(
+    ///     "Eine Konfigurationsdatei hinzufügen\n",
+    ///     "\n",
+    ///     "# Auf Branch main\n",
+    ///     "# Ihr Branch ist auf demselben Stand wie 'origin/main'.\n",
+    ///     "#\n",
+    ///     "# Zum Commit vorgemerkte Änderungen:\n",
+    ///     "#\tneue Datei:     .mit-commit.toml\n",
+    ///     "#\n",
+    /// ));
+    /// let comments = commit.get_comments();
+    ///
+    /// assert_eq!(comments.get_locale(), Some("de"));
+    /// assert_eq!(comments.git_status().get_branch(), Some("main"));
+    /// assert_eq!(
+    ///     comments.git_status().get_staged()[0].get_path(),
+    ///     ".mit-commit.toml"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn get_locale(&self) -> Option<&'static str> {
+        git_status::locale(&self.lines())
+    }
+
+    /// The lines of every comment, with their comment markers still on
+    fn lines(&self) -> Vec<&str> {
+        self.comments
+            .iter()
+            .flat_map(|comment| comment.as_str().lines())
+            .collect()
+    }
 }
 
 impl<'a> IntoIterator for Comments<'a> {
@@ -150,10 +253,16 @@ impl<'a> From<Vec<Fragment<'a>>> for Comments<'a> {
     ///
     /// A new Comments collection containing only the Comment fragments
     fn from(ast: Vec<Fragment<'a>>) -> Self {
-        ast.into_iter()
+        Self::from(ast.as_slice())
+    }
+}
+
+impl<'a> From<&[Fragment<'a>]> for Comments<'a> {
+    fn from(ast: &[Fragment<'a>]) -> Self {
+        ast.iter()
             .filter_map(|values| {
                 if let Fragment::Comment(comment) = values {
-                    Some(comment)
+                    Some(comment.clone())
                 } else {
                     None
                 }
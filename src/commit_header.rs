@@ -0,0 +1,137 @@
+use std::borrow::Cow;
+
+use crate::Span;
+
+/// A header from a raw git commit object, like `tree` or `gpgsig`
+///
+/// Headers can run over several lines. Each line after the first starts
+/// with a space in the object, which isn't part of the value, so the value
+/// of a multi-line header like `gpgsig` is its lines joined with `\n`.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// use mit_commit::RawCommit;
+///
+/// let commit = RawCommit::try_from(
+///     "tree 4b825dc642cb6eb9a060e54bf8d69288fbee4904\ngpgsig line one\n line two\n\nSubject\n",
+/// )
+/// .unwrap();
+/// let header = &commit.get_headers()[1];
+///
+/// assert_eq!(header.get_key(), "gpgsig");
+/// assert_eq!(header.get_value(), "line one\nline two");
+/// assert_eq!(String::from(header.clone()), "gpgsig line one\n line two");
+/// ```
+#[derive(Debug, Clone, Eq)]
+pub struct CommitHeader<'a> {
+    key: Cow<'a, str>,
+    value: Cow<'a, str>,
+    span: Option<Span>,
+}
+
+impl<'a> CommitHeader<'a> {
+    /// Give you a [`CommitHeader`] that owns its text, so it can outlive the
+    /// object it was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`CommitHeader`], with any borrowed text copied
+    #[must_use]
+    pub fn into_owned(self) -> CommitHeader<'static> {
+        CommitHeader {
+            key: Cow::Owned(self.key.into_owned()),
+            value: Cow::Owned(self.value.into_owned()),
+            span: self.span,
+        }
+    }
+
+    /// Create a new [`CommitHeader`]
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The name of the header, like `parent`
+    /// * `value` - The value, with any continuation lines joined with `\n`
+    ///
+    /// # Returns
+    ///
+    /// A header that wasn't parsed from an object, so has no span
+    #[must_use]
+    pub const fn new(key: Cow<'a, str>, value: Cow<'a, str>) -> Self {
+        Self {
+            key,
+            value,
+            span: None,
+        }
+    }
+
+    /// Get the name of the header, like `author`
+    #[must_use]
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+
+    /// Get the value of the header, with continuation lines joined with `\n`
+    #[must_use]
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+
+    /// Where this [`CommitHeader`] came from in the parsed object
+    ///
+    /// The span covers every line of the header, but not the line feed at
+    /// the end of it.
+    ///
+    /// # Returns
+    ///
+    /// The span of the header, or None if it wasn't parsed from an object
+    #[must_use]
+    pub const fn get_span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// Set where this [`CommitHeader`] came from
+    #[must_use]
+    pub(crate) fn with_span(self, span: Span) -> Self {
+        Self {
+            span: Some(span),
+            ..self
+        }
+    }
+
+    /// The value of this [`CommitHeader`], and where the value came from in
+    /// the object
+    pub(crate) fn value_with_span(&self) -> (&Cow<'a, str>, Option<Span>) {
+        let span = self.span.map(|span| {
+            let text = String::from(self.clone());
+            span.within(&text, self.key.len() + 1..text.len())
+        });
+
+        (&self.value, span)
+    }
+
+    /// Add a continuation line onto the value
+    pub(crate) fn continue_with(&mut self, line: &str, span: Span) {
+        let value = self.value.to_mut();
+        value.push('\n');
+        value.push_str(line);
+        self.span = self.span.map(|existing| existing.extend(span));
+    }
+}
+
+impl PartialEq for CommitHeader<'_> {
+    /// Headers are equal if their text is, wherever they came from
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && self.value == other.value
+    }
+}
+
+impl From<CommitHeader<'_>> for String {
+    /// The header as it appears in the object, without the line feed at the
+    /// end
+    fn from(header: CommitHeader<'_>) -> Self {
+        format!("{} {}", header.key, header.value.replace('\n', "\n "))
+    }
+}
@@ -5,9 +5,10 @@ use std::{
     io,
     io::Read,
     path::{Path, PathBuf},
+    sync::OnceLock,
 };
 
-use miette::Diagnostic;
+use miette::{Diagnostic, SourceSpan};
 use regex::Regex;
 use thiserror::Error;
 
@@ -15,17 +16,59 @@ use super::{
     bodies::Bodies, body::Body, comment::Comment, comments::Comments, fragment::Fragment,
     subject::Subject, trailers::Trailers,
 };
-use crate::{Trailer, scissors::Scissors};
+use crate::{
+    CleanupMode, Fix, IfExists, ParseOptions, Span, Trailer, TrailerAliases, TrailerDetection,
+    TrailerPolicy, cleanup_mode::strip_space, scissors::Scissors, span,
+};
 
 /// A [`Self`], the primary entry point to the library
-#[derive(Debug, PartialEq, Eq, Clone, Default)]
+///
+/// Parsing only splits the message into its ast and [`Scissors`] section.
+/// The [`Subject`], [`Bodies`], [`Comments`] and [`Trailers`] are worked out
+/// from the ast the first time they are asked for, then kept, so a hook that
+/// only reads the subject doesn't pay for the rest.
+///
+/// # Serde
+///
+/// With the `serde` feature, a [`Self`] serialises to an object like this
+///
+/// ```json
+/// {
+///   "ast": [
+///     { "body": "Add a feature" },
+///     { "body": "" },
+///     { "body": "It does a thing." },
+///     { "body": "" },
+///     { "body": "Relates-to: #128" },
+///     { "comment": "# A comment" }
+///   ],
+///   "scissors": null,
+///   "options": {
+///     "comment_marker": "guess",
+///     "trailer_detection": "strict",
+///     "trailer_separators": null
+///   },
+///   "subject": "Add a feature",
+///   "bodies": ["", "It does a thing."],
+///   "trailers": [{ "key": "Relates-to", "value": "#128", "separator": ": " }],
+///   "comments": ["# A comment"]
+/// }
+/// ```
+///
+/// Only `ast`, `scissors` and `options` are read when deserialising, the
+/// rest is worked out again from them, so a deserialised message turns back
+/// into the same string. Spans aren't serialised, but are worked out again
+/// for a deserialised message too.
+#[derive(Debug, Clone, Default)]
 pub struct CommitMessage<'a> {
     scissors: Option<Scissors<'a>>,
     ast: Vec<Fragment<'a>>,
-    subject: Subject<'a>,
-    trailers: Trailers<'a>,
-    comments: Comments<'a>,
-    bodies: Bodies<'a>,
+    line_spans: Vec<Span>,
+    options: ParseOptions,
+    subject: OnceLock<Subject<'a>>,
+    trailers: OnceLock<Trailers<'a>>,
+    comments: OnceLock<Comments<'a>>,
+    bodies: OnceLock<Bodies<'a>>,
 }
 
 impl<'a> CommitMessage<'a> {
@@ -77,6 +120,16 @@ impl<'a> CommitMessage<'a> {
     /// ```
     #[must_use]
     pub fn from_fragments(fragments: Vec<Fragment<'_>>, scissors: Option<Scissors<'_>>) -> Self {
+        Self::from_fragments_with_options(fragments, scissors, ParseOptions::default())
+    }
+
+    /// Convert from [`Fragment`] back into a [`CommitMessage`], reparsing
+    /// with the given [`ParseOptions`]
+    fn from_fragments_with_options(
+        fragments: Vec<Fragment<'_>>,
+        scissors: Option<Scissors<'_>>,
+        options: ParseOptions,
+    ) -> Self {
         let body = fragments
             .into_iter()
             .map(|x| match x {
@@ -90,7 +143,66 @@ impl<'a> CommitMessage<'a> {
             .map(|contents| format!("\n{}", String::from(contents)))
             .unwrap_or_default();
 
-        Self::from(format!("{body}{scissors}"))
+        CommitMessage::parse_commit_message(&format!("{body}{scissors}"), options).into_owned()
+    }
+
+    /// Give you a [`CommitMessage`] that owns all of its text, so it can
+    /// outlive the string it was parsed from
+    ///
+    /// Parsing a `&str` borrows the text of each part from it, rather than
+    /// copying it.
+    ///
+    /// # Returns
+    ///
+    /// The same [`CommitMessage`], with any borrowed text copied, for any
+    /// lifetime including `'static`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{CommitMessage, Subject};
+    ///
+    /// fn parse(text: &str) -> CommitMessage<'static> {
+    ///     CommitMessage::from(text).into_owned()
+    /// }
+    ///
+    /// let text = String::from("Subject\n\nBody\n");
+    /// let commit = parse(&text);
+    /// drop(text);
+    ///
+    /// assert_eq!(commit.get_subject(), Subject::from("Subject"));
+    /// ```
+    #[must_use]
+    pub fn into_owned<'b>(self) -> CommitMessage<'b> {
+        // The views are kept if they've been worked out already
+        CommitMessage {
+            scissors: self.scissors.map(Scissors::into_owned),
+            ast: self.ast.into_iter().map(Fragment::into_owned).collect(),
+            line_spans: self.line_spans,
+            options: self.options,
+            subject: self
+                .subject
+                .into_inner()
+                .map_or_else(OnceLock::new, |subject| {
+                    OnceLock::from(subject.into_owned())
+                }),
+            trailers: self
+                .trailers
+                .into_inner()
+                .map_or_else(OnceLock::new, |trailers| {
+                    OnceLock::from(trailers.into_owned())
+                }),
+            comments: self
+                .comments
+                .into_inner()
+                .map_or_else(OnceLock::new, |comments| {
+                    OnceLock::from(comments.into_owned())
+                }),
+            bodies: self
+                .bodies
+                .into_inner()
+                .map_or_else(OnceLock::new, |bodies| OnceLock::from(bodies.into_owned())),
+        }
     }
 
     /// A helper method to let you insert [`Trailer`]
@@ -164,12 +276,13 @@ impl<'a> CommitMessage<'a> {
 
         // Only add an empty body if we have no bodies or all bodies are empty,
         // and we have no trailers
-        let needs_empty_body = self.bodies.iter().all(Body::is_empty) && self.trailers.is_empty();
+        let needs_empty_body =
+            self.bodies().iter().all(Body::is_empty) && self.trailers().is_empty();
 
         if needs_empty_body {
             fragments.push(Body::default().into());
             fragments.push(Body::default().into());
-        } else if self.trailers.is_empty() {
+        } else if self.trailers().is_empty() {
             // Only add a separator if we have non-empty bodies but no trailers
             fragments.push(Body::default().into());
         }
@@ -179,6 +292,477 @@ impl<'a> CommitMessage<'a> {
         self.insert_after_last_full_body(fragments)
     }
 
+    /// Remove every [`Trailer`] with the given key, ignoring case like git
+    /// does
+    ///
+    /// Comments, the [`Scissors`] section and the rest of the message are
+    /// left as they are. If no trailers are left, the empty line that
+    /// separated them from the body is removed too.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the trailers to remove, like `Co-authored-by`
+    ///
+    /// # Returns
+    ///
+    /// A new `CommitMessage` without those trailers
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::CommitMessage;
+    ///
+    /// let commit = CommitMessage::from(
+    ///     "Subject\n\nBody\n\nCo-authored-by: Someone <someone@example.com>\nRelates-to: #128\n\n# A comment\n",
+    /// );
+    ///
+    /// assert_eq!(
+    ///     String::from(commit.remove_trailers("Co-authored-by")),
+    ///     "Subject\n\nBody\n\nRelates-to: #128\n\n# A comment\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn remove_trailers(&self, key: &str) -> Self {
+        self.retain_trailers(|trailer| !trailer.has_key(key))
+    }
+
+    /// Keep only the [`Trailer`]s that match a predicate
+    ///
+    /// Comments, the [`Scissors`] section and the rest of the message are
+    /// left as they are. If no trailers are left, the empty line that
+    /// separated them from the body is removed too.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Returns true for the trailers to keep
+    ///
+    /// # Returns
+    ///
+    /// A new `CommitMessage` with only the matching trailers
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::CommitMessage;
+    ///
+    /// let commit = CommitMessage::from(
+    ///     "Subject\n\nCo-authored-by: Stale <stale@example.com>\nCo-authored-by: Current <current@example.com>\n",
+    /// );
+    ///
+    /// assert_eq!(
+    ///     String::from(commit.retain_trailers(|trailer| !trailer.get_value().contains("stale"))),
+    ///     "Subject\n\nCo-authored-by: Current <current@example.com>\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn retain_trailers(&self, mut predicate: impl FnMut(&Trailer<'_>) -> bool) -> Self {
+        self.rewrite_trailers(|trailer| {
+            if predicate(trailer) {
+                TrailerEdit::Keep
+            } else {
+                TrailerEdit::Remove
+            }
+        })
+    }
+
+    /// Replace the value of the [`Trailer`] with the given key
+    ///
+    /// Keys are compared ignoring case, like git does. The first trailer with
+    /// the key keeps its place and separator, and any later trailers with the
+    /// same key are removed. If there is no trailer with the key, the message
+    /// is left as it is.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the trailer to replace, like `Relates-to`
+    /// * `value` - The new value of the trailer
+    ///
+    /// # Returns
+    ///
+    /// A new `CommitMessage` with the trailer replaced
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::CommitMessage;
+    ///
+    /// let commit = CommitMessage::from(
+    ///     "Subject\n\nRelates-to: #128\nSigned-off-by: Someone <someone@example.com>\n",
+    /// );
+    ///
+    /// assert_eq!(
+    ///     String::from(commit.replace_trailer("Relates-to", "#129")),
+    ///     "Subject\n\nRelates-to: #129\nSigned-off-by: Someone <someone@example.com>\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn replace_trailer(&self, key: &str, value: &str) -> Self {
+        let mut replaced = false;
+
+        self.rewrite_trailers(|trailer| {
+            if !trailer.has_key(key) {
+                TrailerEdit::Keep
+            } else if replaced {
+                TrailerEdit::Remove
+            } else {
+                replaced = true;
+                TrailerEdit::Replace(
+                    trailer
+                        .clone()
+                        .with_value(value.to_string().into())
+                        .into_owned(),
+                )
+            }
+        })
+    }
+
+    /// Add a [`Trailer`], deciding what to do if its key is already present
+    ///
+    /// Keys are compared ignoring case, like git does.
+    ///
+    /// # Arguments
+    ///
+    /// * `trailer` - The trailer to add
+    /// * `if_exists` - What to do if there is already a trailer with the key
+    ///
+    /// # Returns
+    ///
+    /// A new `CommitMessage` with the trailer added or replaced
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{CommitMessage, IfExists, Trailer};
+    ///
+    /// let commit = CommitMessage::from("Subject\n\nRelates-to: #128\n");
+    /// let trailer = Trailer::new("Relates-to".into(), "#129".into());
+    ///
+    /// assert_eq!(
+    ///     String::from(commit.upsert_trailer(trailer.clone(), IfExists::DoNothing)),
+    ///     "Subject\n\nRelates-to: #128\n"
+    /// );
+    /// assert_eq!(
+    ///     String::from(commit.upsert_trailer(trailer.clone(), IfExists::Replace)),
+    ///     "Subject\n\nRelates-to: #129\n"
+    /// );
+    /// assert_eq!(
+    ///     String::from(commit.upsert_trailer(trailer, IfExists::Add)),
+    ///     "Subject\n\nRelates-to: #128\nRelates-to: #129\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn upsert_trailer(&self, trailer: Trailer<'_>, if_exists: IfExists) -> Self {
+        let key = trailer.get_key();
+        let exists = self
+            .trailers()
+            .iter()
+            .any(|existing| existing.has_key(&key));
+
+        match if_exists {
+            _ if !exists => self.add_trailer(trailer),
+            IfExists::Add => self.add_trailer(trailer),
+            IfExists::AddIfDifferent
+                if !self
+                    .trailers()
+                    .iter()
+                    .any(|existing| existing.is_same_trailer(&trailer)) =>
+            {
+                self.add_trailer(trailer)
+            }
+            IfExists::AddIfDifferentNeighbor
+                if !self
+                    .trailers()
+                    .iter()
+                    .last()
+                    .is_some_and(|existing| existing.is_same_trailer(&trailer)) =>
+            {
+                self.add_trailer(trailer)
+            }
+            IfExists::AddIfDifferent | IfExists::AddIfDifferentNeighbor | IfExists::DoNothing => {
+                self.clone()
+            }
+            IfExists::Replace => {
+                let mut replaced = false;
+
+                self.rewrite_trailers(|existing| {
+                    if !existing.has_key(&key) {
+                        TrailerEdit::Keep
+                    } else if replaced {
+                        TrailerEdit::Remove
+                    } else {
+                        replaced = true;
+                        TrailerEdit::Replace(trailer.clone().into_owned())
+                    }
+                })
+            }
+        }
+    }
+
+    /// Rewrite the key of every [`Trailer`] to its canonical spelling
+    ///
+    /// Only the keys change; values, separators, comments and the rest of
+    /// the message are left as they are.
+    ///
+    /// # Arguments
+    ///
+    /// * `aliases` - The canonical keys, and the other spellings of them
+    ///
+    /// # Returns
+    ///
+    /// A new `CommitMessage` with the trailer keys rewritten
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{CommitMessage, TrailerAliases};
+    ///
+    /// let aliases = TrailerAliases::default().with_alias("Coauthored-by", "Co-authored-by");
+    /// let commit = CommitMessage::from(
+    ///     "Subject\n\nCoauthored-by: Someone <someone@example.com>\nco-authored-by: Another <another@example.com>\n",
+    /// );
+    ///
+    /// assert_eq!(
+    ///     String::from(commit.canonicalise_trailers(&aliases)),
+    ///     "Subject\n\nCo-authored-by: Someone <someone@example.com>\nCo-authored-by: Another <another@example.com>\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn canonicalise_trailers(&self, aliases: &TrailerAliases) -> Self {
+        self.rewrite_trailers(|trailer| {
+            let key = trailer.get_key();
+
+            match aliases.get_canonical_key(&key) {
+                Cow::Owned(canonical) if canonical != key => {
+                    TrailerEdit::Replace(trailer.clone().with_key(canonical.into()).into_owned())
+                }
+                _ => TrailerEdit::Keep,
+            }
+        })
+    }
+
+    /// Add a [`Trailer`] the way `git interpret-trailers --trailer` does
+    ///
+    /// The [`TrailerPolicy`] decides where the trailer goes and what happens
+    /// if there is already a trailer with the same key. The result is the
+    /// same as git's, byte for byte, which means the existing trailers are
+    /// printed again as `Key: value`, and comments in the trailer block are
+    /// dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `trailer` - The trailer to add
+    /// * `policy` - How to add it
+    ///
+    /// # Returns
+    ///
+    /// A new `CommitMessage` with the trailer added
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{CommitMessage, IfExists, Trailer, TrailerPolicy, Where};
+    ///
+    /// let commit = CommitMessage::from(
+    ///     "Subject\n\nAcked-by: Someone <someone@example.com>\nFixes: #128\n\n# A comment\n",
+    /// );
+    /// let policy = TrailerPolicy::default()
+    ///     .with_where(Where::After)
+    ///     .with_if_exists(IfExists::Add);
+    /// let trailer = Trailer::new("Acked-by".into(), "Another <another@example.com>".into());
+    ///
+    /// assert_eq!(
+    ///     String::from(commit.add_trailer_with_policy(trailer, policy)),
+    ///     "Subject\n\nAcked-by: Someone <someone@example.com>\nAcked-by: Another <another@example.com>\nFixes: #128\n\n# A comment\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn add_trailer_with_policy(&self, trailer: Trailer<'_>, policy: TrailerPolicy) -> Self {
+        let text = String::from(self);
+        let comment_marker = self.options.get_comment_marker().resolve(&text);
+        let (rest, _) = Scissors::parse_sections(&text);
+        let per_line_ast = CommitMessage::convert_to_per_line_ast(
+            comment_marker.as_deref(),
+            &text,
+            &rest,
+            &span::line_spans(&text),
+        );
+        let separators = self.options.get_trailer_separators().unwrap_or(":");
+
+        CommitMessage::parse_commit_message(
+            &policy.apply(&text, &per_line_ast, separators, trailer),
+            self.options.clone(),
+        )
+        .into_owned()
+    }
+
+    /// Rewrite the text of each [`Trailer`] in place, then reparse
+    ///
+    /// The message is reparsed first so the spans of the trailers point at
+    /// the text being edited.
+    fn rewrite_trailers(&self, mut edit: impl FnMut(&Trailer<'_>) -> TrailerEdit) -> Self {
+        let text = String::from(self);
+        let current = CommitMessage::parse_commit_message(&text, self.options.clone());
+
+        let mut rewritten = String::with_capacity(text.len());
+        let mut position = 0;
+        let mut kept_any = false;
+        let mut block_start = None;
+
+        for trailer in current.trailers().iter() {
+            let Some(span) = trailer.get_span() else {
+                continue;
+            };
+            block_start.get_or_insert_with(|| span.get_offset());
+
+            match edit(trailer) {
+                TrailerEdit::Keep => kept_any = true,
+                TrailerEdit::Replace(replacement) => {
+                    kept_any = true;
+                    rewritten.push_str(&text[position..span.get_offset()]);
+                    rewritten.push_str(&String::from(replacement));
+                    position = span.get_end();
+                }
+                TrailerEdit::Remove => {
+                    rewritten.push_str(&text[position..span.get_offset()]);
+                    position = span::end_of_line(&text, span.get_end());
+                }
+            }
+        }
+        rewritten.push_str(&text[position..]);
+
+        // With the whole block gone, the empty line that separated it from
+        // the body has nothing to separate
+        if let (false, Some(block_start)) = (kept_any, block_start) {
+            let before = &text[..block_start];
+            let separator = before
+                .strip_suffix("\r\n")
+                .or_else(|| before.strip_suffix('\n'))
+                .filter(|before| before.ends_with('\n'))
+                .map_or(0, |stripped| before.len() - stripped.len());
+            rewritten.replace_range(block_start - separator..block_start, "");
+        }
+
+        // Removing the last line leaves the line ending before it behind,
+        // which the message didn't end with
+        if !text.ends_with('\n') {
+            let trimmed = rewritten
+                .strip_suffix("\r\n")
+                .or_else(|| rewritten.strip_suffix('\n'))
+                .map_or(rewritten.len(), str::len);
+            rewritten.truncate(trimmed);
+        }
+
+        CommitMessage::parse_commit_message(&rewritten, self.options.clone()).into_owned()
+    }
+
+    /// Apply [`Fix`]es to the text of the message, then reparse it
+    ///
+    /// The spans of the fixes should point into the message as it is now,
+    /// like the spans on a [`crate::lint::Problem`] do. That is the text
+    /// `String::from` gives, which has `\n` line endings even if the message
+    /// was parsed from text with `\r\n` ones. Fixes can be in any order, and
+    /// the same fix given twice is only applied once.
+    ///
+    /// # Arguments
+    ///
+    /// * `fixes` - The fixes to apply
+    ///
+    /// # Returns
+    ///
+    /// The commit message with every fix applied
+    ///
+    /// # Errors
+    ///
+    /// If fixes overlap each other, if a fix would change a comment or the
+    /// [`Scissors`] section, or if a fix is outside the message. When this
+    /// happens no fixes are applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{CommitMessage, Fix, Span};
+    ///
+    /// let commit = CommitMessage::from("fix the bug.\n# A comment\n");
+    /// let fixes = vec![
+    ///     Fix::new(Span::new(11, 1, 0, 11), String::new()),
+    ///     Fix::new(Span::new(0, 1, 0, 0), "F".into()),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     String::from(commit.apply_fixes(&fixes).unwrap()),
+    ///     "Fix the bug\n# A comment\n"
+    /// );
+    ///
+    /// let overlapping = vec![
+    ///     Fix::new(Span::new(0, 3, 0, 0), "Fixed".into()),
+    ///     Fix::new(Span::new(0, 1, 0, 0), "F".into()),
+    /// ];
+    ///
+    /// assert!(commit.apply_fixes(&overlapping).is_err());
+    /// ```
+    pub fn apply_fixes(&self, fixes: &[Fix]) -> Result<Self, Error> {
+        let text = String::from(self);
+        let current = CommitMessage::parse_commit_message(&text, self.options.clone());
+
+        let mut fixes: Vec<&Fix> = fixes.iter().collect();
+        fixes.sort();
+        fixes.dedup();
+
+        if let Some(fix) = fixes.iter().find(|fix| {
+            let span = fix.get_span();
+            span.get_end() > text.len()
+                || !text.is_char_boundary(span.get_offset())
+                || !text.is_char_boundary(span.get_end())
+        }) {
+            let span = fix.get_span();
+            return Err(Error::FixOutOfBounds(span.get_offset(), span.get_end()));
+        }
+
+        let conflicting: Vec<SourceSpan> = fixes
+            .iter()
+            .filter(|fix| fixes.iter().any(|other| fix.conflicts_with(other)))
+            .map(|fix| fix.get_span().into())
+            .collect();
+        if !conflicting.is_empty() {
+            return Err(Error::ConflictingFixes(text, conflicting));
+        }
+
+        // Anything after the start of the scissors is part of the scissors
+        // section, even text added at the very end
+        let scissors_start = current
+            .scissors
+            .map(|scissors| text.len() - String::from(scissors).len());
+        let protected = current
+            .ast
+            .iter()
+            .filter_map(|fragment| match fragment {
+                Fragment::Comment(comment) => comment
+                    .get_span()
+                    .map(|span| span.get_offset()..span.get_end()),
+                Fragment::Body(_) => None,
+            })
+            .chain(scissors_start.map(|start| start..usize::MAX))
+            .collect::<Vec<_>>();
+        if let Some(fix) = fixes
+            .iter()
+            .find(|fix| protected.iter().any(|range| fix.overlaps(range)))
+        {
+            let span = fix.get_span();
+            return Err(Error::FixChangesComments(
+                text,
+                (span.get_offset(), span.get_length()),
+            ));
+        }
+
+        let mut edited = text.clone();
+        for fix in fixes.iter().rev() {
+            let span = fix.get_span();
+            edited.replace_range(span.get_offset()..span.get_end(), fix.get_replacement());
+        }
+
+        Ok(CommitMessage::parse_commit_message(&edited, self.options.clone()).into_owned())
+    }
+
     /// Insert text in the place you're most likely to want it
     ///
     /// In the case you don't have any full [`Body`] in there, it inserts it at
@@ -251,28 +835,35 @@ impl<'a> CommitMessage<'a> {
             new_ast.extend_from_slice(&self.ast);
         }
 
-        Self::from_fragments(new_ast, self.get_scissors())
+        Self::from_fragments_with_options(new_ast, self.get_scissors(), self.options.clone())
     }
 
-    fn convert_to_per_line_ast(comment_character: Option<char>, rest: &str) -> Vec<Fragment<'a>> {
-        rest.lines()
-            .map(|line| {
-                comment_character.map_or_else(
-                    || Body::from(line.to_string()).into(),
-                    |comment_character| {
-                        if line.starts_with(comment_character) {
-                            Comment::from(line.to_string()).into()
-                        } else {
-                            Body::from(line.to_string()).into()
-                        }
-                    },
-                )
+    /// Split the part of the message before the scissors into lines,
+    /// borrowed from the message
+    fn convert_to_per_line_ast(
+        comment_marker: Option<&str>,
+        message: &'a str,
+        rest: &str,
+        line_spans: &[Span],
+    ) -> Vec<Fragment<'a>> {
+        // The lines before the scissors are the same in both, but only the
+        // message lives long enough to borrow from
+        message
+            .lines()
+            .take(rest.lines().count())
+            .zip(line_spans)
+            .map(|(line, span)| match comment_marker {
+                Some(comment_marker) if line.starts_with(comment_marker) => {
+                    Comment::from(line).with_span(*span).into()
+                }
+                _ => Body::from(line).with_span(*span).into(),
             })
             .collect()
     }
 
-    /// Group consecutive fragments of the same type
-    fn group_ast(ungrouped_ast: Vec<Fragment<'a>>) -> Vec<Fragment<'a>> {
+    /// Group consecutive fragments of the same type, borrowing the grouped
+    /// text from the message where the lines are next to each other in it
+    fn group_ast(message: &'a str, ungrouped_ast: Vec<Fragment<'a>>) -> Vec<Fragment<'a>> {
         // Using a more functional approach with fold
         ungrouped_ast
             .into_iter()
@@ -280,7 +871,7 @@ impl<'a> CommitMessage<'a> {
                 match (acc.last_mut(), fragment) {
                     // Consecutive Comment fragments
                     (Some(Fragment::Comment(existing)), Fragment::Comment(new)) => {
-                        *existing = existing.append(&new);
+                        *existing = existing.append_in(message, &new);
                     }
 
                     // Consecutive Body fragments
@@ -288,7 +879,7 @@ impl<'a> CommitMessage<'a> {
                         if new.is_empty() || existing.is_empty() {
                             acc.push(Fragment::from(new));
                         } else {
-                            *existing = existing.append(&new);
+                            *existing = existing.append_in(message, &new);
                         }
                     }
 
@@ -345,7 +936,7 @@ impl<'a> CommitMessage<'a> {
     /// ```
     #[must_use]
     pub fn get_subject(&self) -> Subject<'a> {
-        self.subject.clone()
+        self.subject().clone()
     }
 
     /// Get the underlying data structure that represents the [`CommitMessage`]
@@ -497,7 +1088,7 @@ impl<'a> CommitMessage<'a> {
     /// ```
     #[must_use]
     pub fn get_body(&self) -> Bodies<'_> {
-        self.bodies.clone()
+        self.bodies().clone()
     }
 
     /// Get the [`Comments`] from the [`CommitMessage`]
@@ -563,7 +1154,7 @@ impl<'a> CommitMessage<'a> {
     /// ```
     #[must_use]
     pub fn get_comments(&self) -> Comments<'_> {
-        self.comments.clone()
+        self.comments().clone()
     }
 
     /// Get the [`Scissors`] from the [`CommitMessage`]
@@ -719,7 +1310,7 @@ impl<'a> CommitMessage<'a> {
     /// ```
     #[must_use]
     pub fn get_trailers(&self) -> Trailers<'_> {
-        self.trailers.clone()
+        self.trailers().clone()
     }
 
     /// Checks if the [`CommitMessage`] matches a given pattern in the saved portions
@@ -785,10 +1376,6 @@ impl<'a> CommitMessage<'a> {
         re.is_match(&text)
     }
 
-    fn guess_comment_character(message: &str) -> Option<char> {
-        Scissors::guess_comment_character(message)
-    }
-
     /// Give you a new [`CommitMessage`] with the provided subject
     ///
     /// # Arguments
@@ -826,24 +1413,18 @@ impl<'a> CommitMessage<'a> {
         if !ast.is_empty() {
             ast.remove(0);
         }
-        ast.insert(0, Body::from(subject.to_string()).into());
+        ast.insert(0, Body::from(String::from(subject)).into());
 
-        let comments = Comments::from(ast.clone());
-        let bodies = Bodies::from(ast.clone());
-        // Changing the subject (first Body fragment) does not affect which of
-        // the remaining bodies are trailers, so we keep the correctly-extracted
-        // trailers rather than re-extracting from the grouped AST, which would
-        // merge consecutive trailer lines into a single multi-line Body and
-        // produce a single bogus trailer.
-        let trailers = self.trailers;
+        // The spans of everything after the subject move with it
+        let (ast, line_spans) = Self::respan(ast);
 
+        // The other views are worked out from the new ast if they're needed
         Self {
             scissors: self.scissors,
             ast,
-            subject,
-            trailers,
-            comments,
-            bodies,
+            line_spans,
+            options: self.options,
+            ..Self::default()
         }
     }
 
@@ -910,15 +1491,14 @@ impl<'a> CommitMessage<'a> {
             let string: String = s.into();
             Scissors::from(string)
         });
-        let body = format!("Unused\n\n{contents}");
-        let inner_commit = Self::from(body);
+        let text = format!("{}\n\n{contents}", String::from(existing_subject));
+        let mut result = CommitMessage::parse_commit_message(&text, self.options).into_owned();
 
-        let inner_scissors = inner_commit.scissors.clone().map(|s| {
+        let inner_scissors = result.scissors.take().map(|s| {
             let string: String = s.into();
             Scissors::from(string)
         });
 
-        let mut result = inner_commit.with_subject(existing_subject);
         // Preserve the outer scissors if present, otherwise keep any inner
         // scissors so content containing the scissors marker is not silently
         // dropped.
@@ -926,6 +1506,70 @@ impl<'a> CommitMessage<'a> {
         result
     }
 
+    /// The message git would record after cleaning it up with a
+    /// [`CleanupMode`]
+    ///
+    /// The [`Scissors`] section is always removed, as git only writes it when
+    /// running `git commit --verbose` or `git commit --cleanup=scissors`, and
+    /// in both cases it truncates the message there.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The cleanup mode to apply, as in `git commit --cleanup`
+    ///
+    /// # Returns
+    ///
+    /// The message exactly as git would store it in the commit object
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{CleanupMode, CommitMessage};
+    ///
+    /// let commit = CommitMessage::from(
+    ///     "Example Commit Message  \n\n\nThis is an example commit message\n\n# Please enter the \
+    ///      commit message for your changes.\n# ------------------------ >8 \
+    ///      ------------------------\ndiff --git a/file b/file\n",
+    /// );
+    ///
+    /// assert_eq!(
+    ///     commit.cleanup(CleanupMode::Strip),
+    ///     "Example Commit Message\n\nThis is an example commit message\n"
+    /// );
+    /// assert_eq!(
+    ///     commit.cleanup(CleanupMode::Whitespace),
+    ///     "Example Commit Message\n\nThis is an example commit message\n\n# Please enter the commit message for your changes.\n"
+    /// );
+    /// assert_eq!(
+    ///     commit.cleanup(CleanupMode::Verbatim),
+    ///     "Example Commit Message  \n\n\nThis is an example commit message\n\n# Please enter the commit message for your changes.\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn cleanup(&self, mode: CleanupMode) -> String {
+        let keep_comments = !matches!(mode, CleanupMode::Strip | CleanupMode::Default);
+        let text = self
+            .ast
+            .iter()
+            .filter_map(|fragment| match fragment {
+                Fragment::Body(body) => Some(body.to_string()),
+                Fragment::Comment(comment) if keep_comments => Some(String::from(comment.clone())),
+                Fragment::Comment(_) => None,
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        match mode {
+            // The newline before the scissors line belongs to the message
+            CleanupMode::Verbatim if self.scissors.is_some() => format!("{text}\n"),
+            CleanupMode::Verbatim => text,
+            CleanupMode::Whitespace
+            | CleanupMode::Scissors
+            | CleanupMode::Strip
+            | CleanupMode::Default => strip_space(text.split('\n')),
+        }
+    }
+
     /// Get the comment character used in the commit message
     ///
     /// # Returns
@@ -950,86 +1594,294 @@ impl<'a> CommitMessage<'a> {
     /// assert!(commit.get_comment_char().is_none());
     /// ```
     #[must_use]
-    pub fn get_comment_char(&self) -> Option<char> {
-        self.comments
-            .iter()
-            .next()
-            .map(|comment| -> String { comment.clone().into() })
-            .and_then(|comment| comment.chars().next())
+    pub fn get_comment_char(&self) -> Option<char> {
+        self.comments()
+            .iter()
+            .next()
+            .map(|comment| -> String { comment.clone().into() })
+            .and_then(|comment| comment.chars().next())
+    }
+
+    /// Get the [`ParseOptions`] this message was parsed with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{CommentMarker, CommitMessage, ParseOptions};
+    ///
+    /// let options = ParseOptions::default().with_comment_marker(CommentMarker::Disabled);
+    /// let commit = CommitMessage::parse_with("Subject", options.clone());
+    ///
+    /// assert_eq!(commit.get_options(), &options);
+    /// ```
+    #[must_use]
+    pub const fn get_options(&self) -> &ParseOptions {
+        &self.options
+    }
+
+    /// The [`Subject`], worked out the first time it's needed
+    fn subject(&self) -> &Subject<'a> {
+        self.subject
+            .get_or_init(|| Subject::from(self.ast.as_slice()))
+    }
+
+    /// The [`Bodies`], worked out the first time they're needed
+    fn bodies(&self) -> &Bodies<'a> {
+        self.bodies.get_or_init(|| {
+            Bodies::from_fragments_with_separators(&self.ast, self.options.get_trailer_separators())
+        })
+    }
+
+    /// The [`Comments`], worked out the first time they're needed
+    fn comments(&self) -> &Comments<'a> {
+        self.comments
+            .get_or_init(|| Comments::from(self.ast.as_slice()))
+    }
+
+    /// The [`Trailers`], worked out the first time they're needed
+    fn trailers(&self) -> &Trailers<'a> {
+        self.trailers.get_or_init(|| {
+            // Trailers are found line by line, so the grouped ast is split
+            // back up, rather than letting a paragraph of trailers look like
+            // one long trailer
+            let lines: Vec<Fragment<'a>> = self
+                .ast
+                .iter()
+                .flat_map(|fragment| fragment.lines(&self.line_spans))
+                .collect();
+            let separators = self.options.get_trailer_separators();
+
+            match self.options.get_trailer_detection() {
+                TrailerDetection::Strict => {
+                    Trailers::from_fragments_with_separators(&lines, separators)
+                }
+                TrailerDetection::Git => {
+                    Trailers::from_git_trailer_block(&lines, separators.unwrap_or(":"))
+                }
+            }
+        })
+    }
+}
+
+fn commit_message_to_string(commit_message: &CommitMessage<'_>) -> String {
+    let basic_commit = commit_message
+        .get_ast()
+        .iter()
+        .map(|item| match item {
+            Fragment::Body(contents) => String::from(contents.clone()),
+            Fragment::Comment(contents) => String::from(contents.clone()),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if let Some(scissors) = commit_message.get_scissors() {
+        format!("{basic_commit}\n{}", String::from(scissors))
+    } else {
+        basic_commit
+    }
+}
+
+impl From<CommitMessage<'_>> for String {
+    fn from(commit_message: CommitMessage<'_>) -> Self {
+        commit_message_to_string(&commit_message)
+    }
+}
+
+impl From<&CommitMessage<'_>> for String {
+    fn from(commit_message: &CommitMessage<'_>) -> Self {
+        commit_message_to_string(commit_message)
+    }
+}
+
+impl PartialEq for CommitMessage<'_> {
+    /// Messages are equal if their text and options are, as everything else
+    /// is worked out from those
+    fn eq(&self, other: &Self) -> bool {
+        self.ast == other.ast && self.scissors == other.scissors && self.options == other.options
+    }
+}
+
+impl Eq for CommitMessage<'_> {}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CommitMessage<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("CommitMessage", 7)?;
+        state.serialize_field("ast", &self.ast)?;
+        state.serialize_field("scissors", &self.scissors)?;
+        state.serialize_field("options", &self.options)?;
+        state.serialize_field("subject", self.subject())?;
+        state.serialize_field("bodies", self.bodies())?;
+        state.serialize_field("trailers", self.trailers())?;
+        state.serialize_field("comments", self.comments())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CommitMessage<'_> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// The parts of a message the rest can be worked out from
+        #[derive(serde::Deserialize)]
+        struct Parts<'a> {
+            ast: Vec<Fragment<'a>>,
+            #[serde(default)]
+            scissors: Option<Scissors<'a>>,
+            #[serde(default)]
+            options: ParseOptions,
+        }
+
+        let parts = Parts::deserialize(deserializer)?;
+
+        Ok(Self::from_fragments_with_options(
+            parts.ast,
+            parts.scissors,
+            parts.options,
+        ))
+    }
+}
+
+/// How to change a [`Trailer`] when rewriting the trailers of a
+/// [`CommitMessage`]
+enum TrailerEdit {
+    Keep,
+    Remove,
+    Replace(Trailer<'static>),
+}
+
+/// Parse a commit message using parsers
+impl<'a> CommitMessage<'a> {
+    /// Parse a [`CommitMessage`] with the given [`ParseOptions`]
+    ///
+    /// Use this rather than [`CommitMessage::from`] when you already know how
+    /// the message should be read, for example because you have read
+    /// `core.commentChar` or `core.commentString` from the git config.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The commit message to parse
+    /// * `options` - The options to parse it with
+    ///
+    /// # Returns
+    ///
+    /// The parsed `CommitMessage`, which keeps the options for any later edits
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use indoc::indoc;
+    /// use mit_commit::{Body, Comment, CommentMarker, CommitMessage, Fragment, ParseOptions};
+    ///
+    /// let message = CommitMessage::parse_with(
+    ///     indoc!(
+    ///         "
+    ///         Update bashrc
+    ///
+    ///         #123 is the ticket this relates to
+    ///
+    ///         // Please enter the commit message for your changes.
+    ///         //
+    ///         // On branch main"
+    ///     ),
+    ///     ParseOptions::default().with_comment_marker(CommentMarker::from("//")),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     message.get_ast(),
+    ///     vec![
+    ///         Fragment::Body(Body::from("Update bashrc")),
+    ///         Fragment::Body(Body::default()),
+    ///         Fragment::Body(Body::from("#123 is the ticket this relates to")),
+    ///         Fragment::Body(Body::default()),
+    ///         Fragment::Comment(Comment::from(
+    ///             "// Please enter the commit message for your changes.\n//\n// On branch main"
+    ///         )),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn parse_with(message: &'a str, options: ParseOptions) -> Self {
+        Self::parse_commit_message(message, options)
     }
-}
 
-fn commit_message_to_string(commit_message: &CommitMessage<'_>) -> String {
-    let basic_commit = commit_message
-        .get_ast()
-        .iter()
-        .map(|item| match item {
-            Fragment::Body(contents) => String::from(contents.clone()),
-            Fragment::Comment(contents) => String::from(contents.clone()),
-        })
-        .collect::<Vec<_>>()
-        .join("\n");
+    /// Work out where each fragment is in the text they render to, as if it
+    /// had been parsed from it
+    ///
+    /// # Returns
+    ///
+    /// The fragments with their spans replaced, and the span of every line
+    fn respan(ast: Vec<Fragment<'a>>) -> (Vec<Fragment<'a>>, Vec<Span>) {
+        let mut line_spans = Vec::new();
+        let mut offset = 0;
 
-    if let Some(scissors) = commit_message.get_scissors() {
-        format!("{basic_commit}\n{}", String::from(scissors))
-    } else {
-        basic_commit
-    }
-}
+        let ast = ast
+            .into_iter()
+            .map(|fragment| {
+                let text = match &fragment {
+                    Fragment::Body(body) => body.as_str(),
+                    Fragment::Comment(comment) => comment.as_str(),
+                };
+                let span = Span::new(offset, text.len(), line_spans.len(), 0);
+
+                for line in text.split('\n') {
+                    line_spans.push(Span::new(offset, line.len(), line_spans.len(), 0));
+                    offset += line.len() + 1;
+                }
 
-impl From<CommitMessage<'_>> for String {
-    fn from(commit_message: CommitMessage<'_>) -> Self {
-        commit_message_to_string(&commit_message)
-    }
-}
+                match fragment {
+                    Fragment::Body(body) => body.with_span(span).into(),
+                    Fragment::Comment(comment) => comment.with_span(span).into(),
+                }
+            })
+            .collect();
 
-impl From<&CommitMessage<'_>> for String {
-    fn from(commit_message: &CommitMessage<'_>) -> Self {
-        commit_message_to_string(commit_message)
+        (ast, line_spans)
     }
-}
 
-/// Parse a commit message using parsers
-impl CommitMessage<'_> {
-    fn parse_commit_message(message: &str) -> Self {
+    pub(crate) fn parse_commit_message(message: &'a str, options: ParseOptions) -> Self {
         // Step 1: Split the message into body and scissors sections
         let (rest, scissors) = Scissors::parse_sections(message);
 
-        // Step 2: Guess the comment character
-        let comment_character = Self::guess_comment_character(message);
-
-        // Step 3: Convert the body to a per-line AST
-        let per_line_ast = Self::convert_to_per_line_ast(comment_character, &rest);
+        // Step 2: Work out the comment marker, from the sections so the diff
+        // after the scissors isn't searched
+        let comment_marker = options.get_comment_marker().resolve_with(|| {
+            Scissors::guess_comment_character_from_sections(&rest, scissors.as_ref())
+        });
 
-        // Step 4: Extract trailers before grouping to avoid cloning the entire AST
-        let trailers = Trailers::from(per_line_ast.clone());
+        // Step 3: Convert the body to a per-line AST. Only the lines before
+        // the scissors are spanned, plus one for a trailing empty line, so a
+        // large diff after them isn't read.
+        let line_count = rest.lines().count();
+        let line_spans = span::leading_line_spans(message, line_count + 1);
+        let per_line_ast =
+            Self::convert_to_per_line_ast(comment_marker.as_deref(), message, &rest, &line_spans);
 
-        // Step 5: Group consecutive fragments of the same type
-        let mut ast: Vec<Fragment<'_>> = Self::group_ast(per_line_ast);
+        // Step 4: Group consecutive fragments of the same type
+        let mut ast: Vec<Fragment<'_>> = Self::group_ast(message, per_line_ast);
 
-        // Step 6: Handle trailing newline case
+        // Step 5: Handle trailing newline case
         // Base this on the body portion (`rest`) rather than the whole `message`:
         // when a scissors section is present, `rest` still ends with the newline
         // that separates the body from the scissors marker (the blank line). Using
         // `rest` preserves that blank line through a round-trip.
         if rest.ends_with('\n') {
-            ast.push(Body::default().into());
+            let span = line_spans
+                .get(line_count)
+                .copied()
+                .unwrap_or_else(|| Span::new(message.len(), 0, line_count, 0));
+            ast.push(Body::default().with_span(span).into());
         }
 
-        // Step 7: Create subject, comments, and bodies from the AST
-        // We need to clone here because the From implementations require owned vectors
-        let subject = Subject::from(ast.clone());
-        let comments = Comments::from(ast.clone());
-        let bodies = Bodies::from(ast.clone());
-
-        // Step 8: Create and return the CommitMessage
+        // Step 6: The subject, bodies, comments and trailers are worked out
+        // from the AST when they're first asked for
         Self {
             scissors,
             ast,
-            subject,
-            trailers,
-            comments,
-            bodies,
+            line_spans,
+            options,
+            ..Self::default()
         }
     }
 }
@@ -1084,8 +1936,15 @@ impl<'a> From<Cow<'a, str>> for CommitMessage<'a> {
     /// config this feels like a reasonable compromise, there are a lot of
     /// non-whitespace characters as options otherwise, and we don't want to
     /// confuse a genuine body with a comment
+    ///
+    /// If you do know the comment character, use [`CommitMessage::parse_with`]
     fn from(message: Cow<'a, str>) -> Self {
-        Self::parse_commit_message(&message)
+        match message {
+            Cow::Borrowed(message) => Self::parse_commit_message(message, ParseOptions::default()),
+            Cow::Owned(message) => {
+                CommitMessage::parse_commit_message(&message, ParseOptions::default()).into_owned()
+            }
+        }
     }
 }
 
@@ -1200,6 +2059,36 @@ pub enum Error {
         help("check the file is readable")
     )]
     Io(#[from] io::Error),
+    /// Some of the fixes overlap, so they can't all be applied
+    #[error("some of the fixes overlap")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::commit_message::error::conflicting_fixes),
+        help("apply the fixes that don't overlap, then lint the message again")
+    )]
+    ConflictingFixes(
+        #[source_code] String,
+        #[label(collection, "these fixes overlap")] Vec<SourceSpan>,
+    ),
+    /// A fix would change a comment or the scissors section
+    #[error("a fix would change text that isn't part of the message")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::commit_message::error::fix_changes_comments),
+        help("fixes can only change the subject and body")
+    )]
+    FixChangesComments(
+        #[source_code] String,
+        #[label("this fix changes a comment or the scissors section")] (usize, usize),
+    ),
+    /// A fix doesn't fit in the message
+    #[error("the fix from byte {0} to {1} is outside the message")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::commit_message::error::fix_out_of_bounds),
+        help("check the fix was made for this message")
+    )]
+    FixOutOfBounds(usize, usize),
 }
 
 #[cfg(test)]
@@ -1213,8 +2102,14 @@ mod tests {
 
     use super::*;
     use crate::{
-        Fragment, bodies::Bodies, body::Body, comment::Comment, scissors::Scissors,
-        subject::Subject, trailer::Trailer,
+        CommentMarker, Fragment,
+        bodies::Bodies,
+        body::Body,
+        comment::Comment,
+        lint::{BodyLineLength, Lint},
+        scissors::Scissors,
+        subject::Subject,
+        trailer::Trailer,
     };
 
     #[test]
@@ -1923,6 +2818,91 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_with_subject_moves_the_spans_after_it() {
+        let commit = CommitMessage::from("S\n\nThe body\n# A comment\n")
+            .with_subject("A much longer subject here".into());
+        let text = String::from(&commit);
+
+        let spanned = commit
+            .get_ast()
+            .iter()
+            .map(|fragment| match fragment {
+                Fragment::Body(body) => body
+                    .get_span()
+                    .map(|span| &text[span.get_offset()..span.get_end()]),
+                Fragment::Comment(comment) => comment
+                    .get_span()
+                    .map(|span| &text[span.get_offset()..span.get_end()]),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            spanned,
+            vec![
+                Some("A much longer subject here"),
+                Some(""),
+                Some("The body"),
+                Some("# A comment"),
+                Some(""),
+            ],
+            "Every span should point at its fragment in the edited message"
+        );
+    }
+
+    #[test]
+    fn test_fixes_can_be_applied_after_editing() {
+        let long_line = "a very long line ".repeat(6);
+        let commit = CommitMessage::from(format!("S\n\n{long_line}\n"))
+            .with_subject("A much longer subject here".into());
+        let fixes = BodyLineLength::default()
+            .check(&commit)
+            .into_iter()
+            .flat_map(|problem| problem.get_fixes().to_vec())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            String::from(commit.apply_fixes(&fixes).expect("the fixes apply")),
+            String::from(BodyLineLength::default().rewrap(&commit)),
+            "The body should be rewrapped where it is in the edited message"
+        );
+
+        let commit = CommitMessage::from("Subject\n\nBody\n").with_body_contents(&long_line);
+        let fixes = BodyLineLength::default()
+            .check(&commit)
+            .into_iter()
+            .flat_map(|problem| problem.get_fixes().to_vec())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            String::from(commit.apply_fixes(&fixes).expect("the fixes apply")),
+            String::from(BodyLineLength::default().rewrap(&commit)),
+            "The new body should be rewrapped where it is in the message"
+        );
+    }
+
+    #[test]
+    fn test_with_body_contents_spans_point_into_the_message() {
+        let commit = CommitMessage::from("Subject\nstill the subject\n\nOld body\n")
+            .with_body_contents("New body\n# A comment\n");
+        let text = String::from(&commit);
+        let ast = commit.get_ast();
+
+        for fragment in &ast {
+            let (span, expected) = match fragment {
+                Fragment::Body(body) => (body.get_span(), body.as_str()),
+                Fragment::Comment(comment) => (comment.get_span(), comment.as_str()),
+            };
+            let span = span.expect("every fragment has a span");
+
+            assert_eq!(
+                &text[span.get_offset()..span.get_end()],
+                expected,
+                "The span of {fragment:?} should point at it in {text:?}"
+            );
+        }
+    }
+
     #[test]
     fn test_with_body_contents_preserves_scissors() {
         let commit = CommitMessage::from(indoc!(
@@ -1996,4 +2976,357 @@ mod tests {
             "Round-tripping a commit message with a body and scissors should preserve the blank line between the body and the scissors marker"
         );
     }
+
+    #[test]
+    fn test_parse_with_explicit_multi_character_marker() {
+        let commit = CommitMessage::parse_with(
+            indoc!(
+                "
+                Example Commit Message
+
+                # This is not a comment
+
+                Relates-to: #153
+
+                // Please enter the commit message for your changes.
+                //
+                // On branch main
+                "
+            ),
+            ParseOptions::default().with_comment_marker(CommentMarker::from("//")),
+        );
+
+        assert_eq!(
+            commit.get_comments(),
+            Comments::from(vec![Comment::from(
+                "// Please enter the commit message for your changes.\n//\n// On branch main"
+            )]),
+            "Only lines starting with the explicit marker should be comments"
+        );
+        assert_eq!(
+            commit.get_body(),
+            Bodies::from(vec![Body::default(), Body::from("# This is not a comment")]),
+            "Lines starting with the guessable character should stay in the body"
+        );
+        assert_eq!(
+            commit.get_trailers(),
+            Trailers::from(vec![Trailer::new("Relates-to".into(), "#153".into())]),
+            "Trailers should be found before the explicit comments"
+        );
+    }
+
+    #[test]
+    fn test_parse_with_disabled_marker_has_no_comments() {
+        let commit = CommitMessage::parse_with(
+            "Example Commit Message\n\n# Kept in the message\n",
+            ParseOptions::default().with_comment_marker(CommentMarker::Disabled),
+        );
+
+        assert_eq!(
+            commit.get_comments(),
+            Comments::default(),
+            "Disabling the comment marker should leave no comments"
+        );
+        assert_eq!(
+            commit.get_body(),
+            Bodies::from(vec![Body::default(), Body::from("# Kept in the message")]),
+            "Lines that would have been guessed as comments should be body text"
+        );
+    }
+
+    #[test]
+    fn test_edits_keep_the_parse_options() {
+        let commit = CommitMessage::parse_with(
+            "Example Commit Message\n\nBody\n\n// Comment\n",
+            ParseOptions::default().with_comment_marker(CommentMarker::from("//")),
+        )
+        .add_trailer(Trailer::new("Relates-to".into(), "#153".into()))
+        .with_subject("New Subject".into());
+
+        assert_eq!(
+            commit.get_comments(),
+            Comments::from(vec![Comment::from("// Comment")]),
+            "Edited messages should still use the explicit comment marker"
+        );
+        assert_eq!(
+            String::from(commit),
+            "New Subject\n\nBody\n\nRelates-to: #153\n\n// Comment\n",
+            "Edited messages should still round-trip"
+        );
+    }
+
+    #[allow(clippy::needless_pass_by_value)]
+    #[quickcheck]
+    fn test_parse_with_default_options_matches_from(input: String) -> bool {
+        CommitMessage::parse_with(&input, ParseOptions::default())
+            == CommitMessage::from(input.clone())
+    }
+
+    #[test]
+    fn test_cleanup_strips_comments_using_the_parse_options() {
+        let commit = CommitMessage::parse_with(
+            "Subject\n\n# Not a comment\n\n// A comment\n",
+            ParseOptions::default().with_comment_marker(CommentMarker::from("//")),
+        );
+
+        assert_eq!(
+            commit.cleanup(CleanupMode::Strip),
+            "Subject\n\n# Not a comment\n",
+            "Only lines starting with the explicit marker should be stripped"
+        );
+    }
+
+    #[test]
+    fn test_ast_fragments_have_spans_into_the_input() {
+        let commit = CommitMessage::from(indoc!(
+            "
+            Subject
+
+            Body line 1
+            Body line 2
+
+            # Comment
+            # ------------------------ >8 ------------------------
+            diff --git a/file b/file
+            "
+        ));
+
+        assert_eq!(
+            commit
+                .get_ast()
+                .iter()
+                .map(|fragment| match fragment {
+                    Fragment::Body(body) => body.get_span(),
+                    Fragment::Comment(comment) => comment.get_span(),
+                })
+                .collect::<Vec<_>>(),
+            vec![
+                Some(Span::new(0, 7, 0, 0)),
+                Some(Span::new(8, 0, 1, 0)),
+                Some(Span::new(9, 23, 2, 0)),
+                Some(Span::new(33, 0, 4, 0)),
+                Some(Span::new(34, 9, 5, 0)),
+            ],
+            "Each fragment should know which lines of the input it came from"
+        );
+    }
+
+    #[test]
+    fn test_spans_skip_carriage_returns() {
+        let commit = CommitMessage::from("Subject\r\n\r\nBody\r\n");
+
+        assert_eq!(
+            commit.get_subject().get_span(),
+            Some(Span::new(0, 7, 0, 0)),
+            "The subject span should not include the line ending"
+        );
+        assert_eq!(
+            commit.get_body().iter().nth(1).and_then(Body::get_span),
+            Some(Span::new(11, 4, 2, 0)),
+            "Body spans should be offset by the full line endings"
+        );
+    }
+
+    #[test]
+    fn test_parsing_a_str_borrows_from_it() {
+        let commit = CommitMessage::from(indoc!(
+            "
+            Subject
+
+            Body line one
+            Body line two
+
+            Relates-to: #128
+            Fixes #1
+            # A comment
+            # ------------------------ >8 ------------------------
+            diff --git a/file b/file
+            "
+        ));
+
+        assert!(
+            commit.ast.iter().all(|fragment| match fragment {
+                Fragment::Body(body) => matches!(Cow::from(body.clone()), Cow::Borrowed(_)),
+                Fragment::Comment(comment) =>
+                    matches!(Cow::from(comment.clone()), Cow::Borrowed(_)),
+            }),
+            "Grouped lines should be borrowed from the input"
+        );
+        assert!(
+            commit
+                .scissors
+                .is_some_and(|scissors| matches!(Cow::from(scissors), Cow::Borrowed(_))),
+            "The scissors section should be borrowed from the input"
+        );
+    }
+
+    #[test]
+    fn test_parsing_with_carriage_returns_still_groups_lines() {
+        let commit = CommitMessage::from("Subject\r\n\r\nLine one\r\nLine two\r\n");
+
+        assert_eq!(
+            commit.get_body().iter().nth(1).map(Body::as_str),
+            Some("Line one\nLine two"),
+            "Lines that aren't next to each other in the input are joined"
+        );
+    }
+
+    #[test]
+    fn test_views_are_worked_out_when_asked_for() {
+        let commit = CommitMessage::from("Subject\n\nBody\n\nRelates-to: #128\n# A comment\n");
+
+        assert!(
+            commit.subject.get().is_none()
+                && commit.bodies.get().is_none()
+                && commit.comments.get().is_none()
+                && commit.trailers.get().is_none(),
+            "Parsing shouldn't work out any views"
+        );
+
+        assert_eq!(commit.get_subject(), Subject::from("Subject"));
+        assert!(
+            commit.subject.get().is_some() && commit.trailers.get().is_none(),
+            "Only the view asked for should be worked out"
+        );
+        assert_eq!(
+            commit.get_trailers(),
+            Trailers::from(vec![Trailer::new("Relates-to".into(), "#128".into())]),
+            "The trailers should be worked out from the ast"
+        );
+    }
+
+    #[test]
+    fn test_with_subject_leaves_the_other_views_alone() {
+        let commit = CommitMessage::from("Subject\n\nRelates-to: #128\nFixes: #129\n");
+        let _ = commit.get_body();
+        let edited = commit.with_subject("New subject".into());
+
+        assert!(
+            edited.bodies.get().is_none() && edited.trailers.get().is_none(),
+            "Views should be worked out again only when they're asked for"
+        );
+        assert_eq!(
+            edited.get_trailers().len(),
+            2,
+            "A paragraph of trailers should still be split into each trailer"
+        );
+    }
+
+    #[test]
+    fn test_trailer_spans_are_worked_out_with_carriage_returns() {
+        let commit = CommitMessage::from("Subject\r\n\r\nRelates-to: #128\r\nFixes: #129\r\n");
+
+        assert_eq!(
+            commit
+                .get_trailers()
+                .iter()
+                .map(Trailer::get_span)
+                .collect::<Vec<_>>(),
+            vec![Some(Span::new(11, 16, 2, 0)), Some(Span::new(29, 11, 3, 0))],
+            "Each trailer should point at its own line in the input"
+        );
+    }
+
+    #[test]
+    fn test_lines_after_the_scissors_are_not_read() {
+        // If the diff were searched for the comment character, the scissors
+        // line and the lines starting with ';' in it would be found
+        let message = format!(
+            "Subject\n\n# A comment\n# ------------------------ >8 ------------------------\n{}; ------------------------ >8 ------------------------\n",
+            "; line\n".repeat(1000)
+        );
+        let commit = CommitMessage::from(message.as_str());
+
+        assert_eq!(
+            commit.get_comment_char(),
+            Some('#'),
+            "The comment character should come from the scissors line, not the diff"
+        );
+        assert_eq!(
+            commit.line_spans.len(),
+            4,
+            "Only the lines up to and including the scissors line should be spanned"
+        );
+    }
+
+    #[test]
+    fn test_the_comment_character_is_not_guessed_from_the_diff() {
+        let commit = CommitMessage::from(concat!(
+            "Subject\n",
+            "\n",
+            "# A comment\n",
+            "------------------------ >8 ------------------------\n",
+            "; A line of the diff\n",
+        ));
+
+        assert_eq!(
+            commit.get_comment_char(),
+            Some('#'),
+            "Without a comment character on the scissors line, only the lines before it should be searched"
+        );
+    }
+
+    #[allow(clippy::needless_pass_by_value)]
+    #[quickcheck]
+    fn test_into_owned_is_the_same_message(input: String) -> bool {
+        let commit = CommitMessage::from(input.as_str());
+
+        commit.clone().into_owned() == commit
+    }
+
+    #[allow(clippy::needless_pass_by_value)]
+    #[quickcheck]
+    fn test_spans_point_at_the_fragment_text(input: String) -> TestResult {
+        if input.contains('\r') {
+            return TestResult::discard();
+        }
+
+        let commit = CommitMessage::from(input.as_str());
+
+        TestResult::from_bool(commit.get_ast().into_iter().all(|fragment| {
+            let (text, span) = match fragment {
+                Fragment::Body(body) => (body.to_string(), body.get_span()),
+                Fragment::Comment(comment) => {
+                    let span = comment.get_span();
+                    (String::from(comment), span)
+                }
+            };
+
+            span.is_some_and(|span| input[span.get_offset()..span.get_end()] == text)
+        }))
+    }
+
+    #[test]
+    fn test_remove_trailers_from_crlf_message() {
+        let commit =
+            CommitMessage::from("Subject\r\n\r\nBody\r\n\r\nRelates-to: #128\r\nFixes: #129\r\n");
+
+        assert_eq!(
+            commit.remove_trailers("Relates-to"),
+            CommitMessage::from("Subject\n\nBody\n\nFixes: #129\n"),
+            "The whole trailer line should be removed"
+        );
+    }
+
+    #[test]
+    fn test_remove_trailers_without_a_match_changes_nothing() {
+        let commit = CommitMessage::from("Subject\n\nRelates-to: #128\n");
+
+        assert_eq!(
+            commit.remove_trailers("Fixes"),
+            commit,
+            "Removing a key that is not present should leave the message alone"
+        );
+    }
+
+    #[test]
+    fn test_retain_trailers_on_message_without_trailers() {
+        let commit = CommitMessage::from("Subject\n\nBody\n\n# Comment\n");
+
+        assert_eq!(
+            String::from(commit.retain_trailers(|_| false)),
+            "Subject\n\nBody\n\n# Comment\n",
+            "Without trailers there is no empty line to remove"
+        );
+    }
 }
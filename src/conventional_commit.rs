@@ -0,0 +1,549 @@
+use std::{borrow::Cow, convert::TryFrom};
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::{Bodies, Body, CommitMessage, Fragment, Span, Trailer, Trailers, span};
+
+/// The footer keys that mark a breaking change
+///
+/// These are the only footer keys allowed to contain a space.
+const BREAKING_CHANGE_KEYS: [&str; 2] = ["BREAKING CHANGE", "BREAKING-CHANGE"];
+
+/// The separators allowed between a footer key and its value
+const FOOTER_SEPARATORS: [&str; 2] = [": ", " #"];
+
+/// A [`CommitMessage`] read as a [Conventional Commit](https://www.conventionalcommits.org/en/v1.0.0/)
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// use mit_commit::{CommitMessage, ConventionalCommit};
+///
+/// let commit = CommitMessage::from(
+///     "feat(parser)!: read conventional commits\n\nThe header is split into parts.\n\nBREAKING CHANGE: the subject is no longer enough\nRefs #128\n",
+/// );
+/// let conventional = ConventionalCommit::try_from(&commit).expect("should be a conventional commit");
+///
+/// assert_eq!(conventional.get_type(), "feat");
+/// assert_eq!(conventional.get_scope(), Some("parser"));
+/// assert!(conventional.has_breaking_marker());
+/// assert_eq!(conventional.get_description(), "read conventional commits");
+/// assert_eq!(
+///     conventional.get_breaking_change(),
+///     Some("the subject is no longer enough".into())
+/// );
+/// assert_eq!(conventional.get_footers().len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConventionalCommit<'a> {
+    commit_type: Cow<'a, str>,
+    scope: Option<Cow<'a, str>>,
+    breaking_marker: bool,
+    description: Cow<'a, str>,
+    body: Bodies<'a>,
+    footers: Trailers<'a>,
+}
+
+impl ConventionalCommit<'_> {
+    /// Get the type, like `feat` or `fix`
+    #[must_use]
+    pub fn get_type(&self) -> &str {
+        &self.commit_type
+    }
+
+    /// Get the scope, the part in brackets after the type, if there is one
+    #[must_use]
+    pub fn get_scope(&self) -> Option<&str> {
+        self.scope.as_deref()
+    }
+
+    /// Is there a `!` after the type and scope, marking a breaking change
+    #[must_use]
+    pub const fn has_breaking_marker(&self) -> bool {
+        self.breaking_marker
+    }
+
+    /// Get the description, the part of the header after the `: `
+    #[must_use]
+    pub fn get_description(&self) -> &str {
+        &self.description
+    }
+
+    /// Get the paragraphs of the body, between the header and the footers
+    ///
+    /// Unlike [`CommitMessage::get_body`] the empty lines between paragraphs
+    /// are not included.
+    #[must_use]
+    pub fn get_body(&self) -> Bodies<'_> {
+        self.body.clone()
+    }
+
+    /// Get the footers, including any `BREAKING CHANGE` footer
+    ///
+    /// Footer values can run over several lines, up until the next footer.
+    #[must_use]
+    pub fn get_footers(&self) -> Trailers<'_> {
+        self.footers.clone()
+    }
+
+    /// Is this a breaking change, either because of the `!` marker or a
+    /// `BREAKING CHANGE` footer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    ///
+    /// use mit_commit::{CommitMessage, ConventionalCommit};
+    ///
+    /// let commit = CommitMessage::from("fix: stop\n\nBREAKING-CHANGE: it stops\n");
+    /// let conventional = ConventionalCommit::try_from(&commit).expect("should be a conventional commit");
+    ///
+    /// assert!(conventional.is_breaking_change());
+    /// assert!(!conventional.has_breaking_marker());
+    /// ```
+    #[must_use]
+    pub fn is_breaking_change(&self) -> bool {
+        self.breaking_marker || self.get_breaking_change_footer().is_some()
+    }
+
+    /// Describe the breaking change, if this is one
+    ///
+    /// This is the value of the `BREAKING CHANGE` footer, or the description
+    /// if there is only a `!` marker.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    ///
+    /// use mit_commit::{CommitMessage, ConventionalCommit};
+    ///
+    /// let commit = CommitMessage::from("feat!: drop support for Node 6");
+    /// let conventional = ConventionalCommit::try_from(&commit).expect("should be a conventional commit");
+    ///
+    /// assert_eq!(
+    ///     conventional.get_breaking_change(),
+    ///     Some("drop support for Node 6".into())
+    /// );
+    /// ```
+    #[must_use]
+    pub fn get_breaking_change(&self) -> Option<String> {
+        self.get_breaking_change_footer().map_or_else(
+            || self.breaking_marker.then(|| self.description.to_string()),
+            |footer| Some(footer.get_value()),
+        )
+    }
+
+    fn get_breaking_change_footer(&self) -> Option<&Trailer<'_>> {
+        self.footers.iter().find(|footer| {
+            BREAKING_CHANGE_KEYS
+                .iter()
+                .any(|key| footer.get_key() == *key)
+        })
+    }
+}
+
+impl<'a> TryFrom<&CommitMessage<'a>> for ConventionalCommit<'a> {
+    type Error = Error;
+
+    /// Read a [`CommitMessage`] as a Conventional Commit
+    ///
+    /// # Errors
+    ///
+    /// If the header isn't in the form `type(scope)!: description`. The
+    /// error points at the problem in the message.
+    fn try_from(commit: &CommitMessage<'a>) -> Result<Self, Self::Error> {
+        // Reparse so the spans point at the text being read, even if the
+        // message has been edited since it was parsed
+        let text = String::from(commit);
+        let reparsed = CommitMessage::parse_with(&text, commit.get_options().clone());
+        let line_spans = span::line_spans(&text);
+
+        let mut bodies = reparsed
+            .get_ast()
+            .into_iter()
+            .filter_map(|fragment| match fragment {
+                Fragment::Body(body) => Some(body),
+                Fragment::Comment(_) => None,
+            })
+            .collect::<Vec<_>>()
+            .into_iter();
+
+        let subject = bodies.next().unwrap_or_default();
+        let header_offset = subject.get_span().map_or(0, |span| span.get_offset());
+        let Header {
+            commit_type,
+            scope,
+            breaking_marker,
+            description,
+        } = Header::parse(subject.as_str())
+            .map_err(|problem| problem.into_error(&text, header_offset))?;
+
+        let paragraphs = bodies.filter(|body| !body.is_empty()).collect::<Vec<_>>();
+        let footer_start = paragraphs
+            .iter()
+            .rposition(|paragraph| find_footer_separator(paragraph.as_str()).is_none())
+            .map_or(0, |position| position + 1);
+
+        let body = paragraphs[..footer_start]
+            .iter()
+            .map(|paragraph| owned_body(paragraph))
+            .collect::<Vec<_>>();
+        let footers = paragraphs[footer_start..]
+            .iter()
+            .flat_map(|paragraph| parse_footers(paragraph, &line_spans))
+            .collect::<Vec<_>>();
+
+        Ok(Self {
+            commit_type: commit_type.to_string().into(),
+            scope: scope.map(|scope| scope.to_string().into()),
+            breaking_marker,
+            description: description.to_string().into(),
+            body: body.into(),
+            footers: footers.into(),
+        })
+    }
+}
+
+/// The parts of a Conventional Commit header
+struct Header<'h> {
+    commit_type: &'h str,
+    scope: Option<&'h str>,
+    breaking_marker: bool,
+    description: &'h str,
+}
+
+/// What is wrong with a header, and where in the header it is
+enum HeaderProblem {
+    MissingType(usize),
+    UnclosedScope(usize, usize),
+    EmptyScope(usize, usize),
+    MissingSeparator(usize, usize),
+    MissingDescription(usize, usize),
+    ExtraLines(usize, usize),
+}
+
+impl HeaderProblem {
+    fn into_error(self, source: &str, offset: usize) -> Error {
+        let source = source.to_string();
+
+        match self {
+            Self::MissingType(start) => Error::MissingType(source, (offset + start, 0)),
+            Self::UnclosedScope(start, length) => {
+                Error::UnclosedScope(source, (offset + start, length))
+            }
+            Self::EmptyScope(start, length) => Error::EmptyScope(source, (offset + start, length)),
+            Self::MissingSeparator(start, length) => {
+                Error::MissingSeparator(source, (offset + start, length))
+            }
+            Self::MissingDescription(start, length) => {
+                Error::MissingDescription(source, (offset + start, length))
+            }
+            Self::ExtraLines(start, length) => Error::ExtraLines(source, (offset + start, length)),
+        }
+    }
+}
+
+impl<'h> Header<'h> {
+    /// Read the header from the first paragraph of the message, which should
+    /// only be one line
+    fn parse(paragraph: &'h str) -> Result<Self, HeaderProblem> {
+        let header = paragraph.split('\n').next().unwrap_or_default();
+        let type_length = header
+            .find(|character: char| {
+                !(character.is_alphanumeric() || character == '-' || character == '_')
+            })
+            .unwrap_or(header.len());
+        if type_length == 0 {
+            return Err(HeaderProblem::MissingType(0));
+        }
+
+        let (commit_type, mut rest) = header.split_at(type_length);
+        let mut position = type_length;
+
+        let scope = if let Some(after_bracket) = rest.strip_prefix('(') {
+            let Some(close) = after_bracket.find(')') else {
+                return Err(HeaderProblem::UnclosedScope(position, rest.len()));
+            };
+            let scope = &after_bracket[..close];
+            if scope.trim().is_empty() {
+                return Err(HeaderProblem::EmptyScope(position, close + 2));
+            }
+
+            rest = &after_bracket[close + 1..];
+            position += close + 2;
+            Some(scope)
+        } else {
+            None
+        };
+
+        let breaking_marker = rest.starts_with('!');
+        if breaking_marker {
+            rest = &rest[1..];
+            position += 1;
+        }
+
+        let Some(after_colon) = rest.strip_prefix(':') else {
+            let length = rest.chars().next().map_or(0, char::len_utf8);
+            return Err(HeaderProblem::MissingSeparator(position, length));
+        };
+        if after_colon.trim().is_empty() {
+            return Err(HeaderProblem::MissingDescription(
+                position + 1,
+                after_colon.len(),
+            ));
+        }
+        let Some(description) = after_colon.strip_prefix(' ') else {
+            return Err(HeaderProblem::MissingSeparator(position, 1));
+        };
+        if header.len() < paragraph.len() {
+            return Err(HeaderProblem::ExtraLines(
+                header.len() + 1,
+                paragraph.len() - header.len() - 1,
+            ));
+        }
+
+        Ok(Self {
+            commit_type,
+            scope,
+            breaking_marker,
+            description,
+        })
+    }
+}
+
+/// Find the end of the key and the separator, if the line starts a footer
+///
+/// # Returns
+///
+/// The length of the key, and the separator after it
+fn find_footer_separator(line: &str) -> Option<(usize, &'static str)> {
+    let token_length = line
+        .find(|character: char| !(character.is_alphanumeric() || character == '-'))
+        .unwrap_or(line.len());
+
+    BREAKING_CHANGE_KEYS
+        .iter()
+        .filter(|key| line.starts_with(*key))
+        .map(|key| key.len())
+        .chain((token_length > 0).then_some(token_length))
+        .find_map(|key_length| {
+            FOOTER_SEPARATORS
+                .iter()
+                .find(|separator| line[key_length..].starts_with(*separator))
+                .map(|separator| (key_length, *separator))
+        })
+}
+
+/// Split a paragraph of footers into footers, each running until the next
+/// line that starts a footer
+fn parse_footers<'a>(paragraph: &Body<'_>, line_spans: &[Span]) -> Vec<Trailer<'a>> {
+    let first_line = paragraph.get_span().map_or(0, |span| span.get_line());
+    let mut footers: Vec<(String, &str, String, Option<Span>)> = Vec::new();
+
+    for (index, line) in paragraph.as_str().split('\n').enumerate() {
+        let line_span = line_spans.get(first_line + index).copied();
+
+        match (find_footer_separator(line), footers.last_mut()) {
+            (None, Some((_, _, value, span))) => {
+                value.push('\n');
+                value.push_str(line);
+                *span = span.zip(line_span).map(|(span, line)| span.extend(line));
+            }
+            (Some((key_length, separator)), _) => {
+                footers.push((
+                    line[..key_length].to_string(),
+                    separator,
+                    line[key_length + separator.len()..].to_string(),
+                    line_span,
+                ));
+            }
+            // Footer paragraphs always start with a footer line
+            (None, None) => {}
+        }
+    }
+
+    footers
+        .into_iter()
+        .map(|(key, separator, value, span)| {
+            let footer = Trailer::new_with_separator(key.into(), value.into(), separator.into());
+            match span {
+                Some(span) => footer.with_span(span),
+                None => footer,
+            }
+        })
+        .collect()
+}
+
+/// Copy a [`Body`] so it no longer borrows from the text it was parsed from
+fn owned_body<'a>(body: &Body<'_>) -> Body<'a> {
+    let owned = Body::from(body.as_str().to_string());
+
+    match body.get_span() {
+        Some(span) => owned.with_span(span),
+        None => owned,
+    }
+}
+
+/// Errors in reading a [`CommitMessage`] as a Conventional Commit
+#[derive(Error, Debug, Diagnostic)]
+pub enum Error {
+    /// The header does not start with a type
+    #[error("the header has no type")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::conventional_commit::error::missing_type),
+        help("start the header with a type, like `feat: add a button`")
+    )]
+    MissingType(
+        #[source_code] String,
+        #[label("expected a type here")] (usize, usize),
+    ),
+    /// The scope has an opening bracket but no closing one
+    #[error("the scope is not closed")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::conventional_commit::error::unclosed_scope),
+        help("close the scope with `)`, like `feat(parser): add a rule`")
+    )]
+    UnclosedScope(
+        #[source_code] String,
+        #[label("this scope has no `)`")] (usize, usize),
+    ),
+    /// The scope has brackets but nothing inside them
+    #[error("the scope is empty")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::conventional_commit::error::empty_scope),
+        help("put a scope in the brackets, or remove them")
+    )]
+    EmptyScope(
+        #[source_code] String,
+        #[label("this scope is empty")] (usize, usize),
+    ),
+    /// The type and scope are not followed by `: `
+    #[error("the header has no `: ` after the type")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::conventional_commit::error::missing_separator),
+        help("separate the type from the description with `: `, like `fix: stop crashing`")
+    )]
+    MissingSeparator(
+        #[source_code] String,
+        #[label("expected `: ` here")] (usize, usize),
+    ),
+    /// There is nothing after the `: `
+    #[error("the header has no description")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::conventional_commit::error::missing_description),
+        help("describe the change after the `: `")
+    )]
+    MissingDescription(
+        #[source_code] String,
+        #[label("expected a description here")] (usize, usize),
+    ),
+    /// The header runs on to more lines, without an empty line before the
+    /// body
+    #[error("the header is more than one line")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::conventional_commit::error::extra_lines),
+        help("put an empty line between the header and the body")
+    )]
+    ExtraLines(
+        #[source_code] String,
+        #[label("these lines are part of the header")] (usize, usize),
+    ),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_a_plain_header() {
+        let header = Header::parse("fix: stop crashing")
+            .ok()
+            .expect("should be a valid header");
+
+        assert_eq!(header.commit_type, "fix", "The type is before the colon");
+        assert_eq!(header.scope, None, "There is no scope");
+        assert!(!header.breaking_marker, "There is no breaking marker");
+        assert_eq!(
+            header.description, "stop crashing",
+            "The description is after the colon"
+        );
+    }
+
+    #[test]
+    fn test_parses_scope_and_breaking_marker() {
+        let header = Header::parse("feat(api)!: remove the v1 endpoints")
+            .ok()
+            .expect("should be a valid header");
+
+        assert_eq!(header.scope, Some("api"), "The scope is in brackets");
+        assert!(header.breaking_marker, "The ! marks a breaking change");
+    }
+
+    #[test]
+    fn test_finds_footer_separators() {
+        assert_eq!(
+            find_footer_separator("Refs #128"),
+            Some((4, " #")),
+            "A hash separated footer"
+        );
+        assert_eq!(
+            find_footer_separator("BREAKING CHANGE: it broke"),
+            Some((15, ": ")),
+            "BREAKING CHANGE may contain a space"
+        );
+        assert_eq!(
+            find_footer_separator("BREAKING-CHANGES: still a token"),
+            Some((16, ": ")),
+            "Keys that only start like BREAKING-CHANGE are normal tokens"
+        );
+        assert_eq!(
+            find_footer_separator("Some prose: not a footer"),
+            None,
+            "Other keys may not contain spaces"
+        );
+    }
+
+    #[test]
+    fn test_problems_point_into_the_header() {
+        let cases = [
+            ("(scope): no type", 0, 0),
+            ("feat(scope: unclosed", 4, 16),
+            ("feat(): empty scope", 4, 2),
+            ("feat add a thing", 4, 1),
+            ("feat:no space", 4, 1),
+            ("feat: ", 5, 1),
+            ("feat: add\nmore", 10, 4),
+        ];
+
+        for (header, expected_start, expected_length) in cases {
+            let Err(problem) = Header::parse(header) else {
+                panic!("{header:?} should not be a valid header");
+            };
+            let (start, length) = match problem {
+                HeaderProblem::MissingType(start) => (start, 0),
+                HeaderProblem::UnclosedScope(start, length)
+                | HeaderProblem::EmptyScope(start, length)
+                | HeaderProblem::MissingSeparator(start, length)
+                | HeaderProblem::MissingDescription(start, length)
+                | HeaderProblem::ExtraLines(start, length) => (start, length),
+            };
+
+            assert_eq!(
+                (start, length),
+                (expected_start, expected_length),
+                "The problem in {header:?} is in the wrong place"
+            );
+        }
+    }
+}
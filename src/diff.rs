@@ -0,0 +1,303 @@
+//! Unified diffs, like the one `git commit --verbose` puts after the
+//! scissors line
+//!
+//! A [`Diff`] is a list of [`FileDiff`], one for each file changed, with the
+//! [`Hunk`]s of [`Line`]s added and removed in it. Renames, copies and mode
+//! changes are read from the extended headers git writes before each file.
+//!
+//! Reading a diff never fails. Anything that isn't part of a diff, like the
+//! comments git writes after the scissors line, is skipped.
+//!
+//! # Examples
+//!
+//! A lint that wants a `BREAKING CHANGE` trailer when the public API changes
+//!
+//! ```
+//! use mit_commit::{
+//!     CommitMessage,
+//!     lint::{Lint, Problem},
+//! };
+//!
+//! #[derive(Debug)]
+//! struct BreakingChange;
+//!
+//! impl Lint for BreakingChange {
+//!     fn get_code(&self) -> &str {
+//!         "breaking-change"
+//!     }
+//!
+//!     fn check(&self, commit: &CommitMessage<'_>) -> Vec<Problem> {
+//!         let touches_api = commit.get_scissors().is_some_and(|scissors| {
+//!             scissors
+//!                 .diff()
+//!                 .iter()
+//!                 .any(|file| file.get_path() == "src/lib.rs" && file.get_removed() > 0)
+//!         });
+//!         let trailers = commit.get_trailers();
+//!         let has_trailer = trailers
+//!             .iter()
+//!             .any(|trailer| trailer.get_key() == "BREAKING CHANGE");
+//!
+//!         if touches_api && !has_trailer {
+//!             vec![Problem::new(self.get_code(), "the public API changed")]
+//!         } else {
+//!             vec![]
+//!         }
+//!     }
+//! }
+//!
+//! let commit = CommitMessage::from(concat!(
+//!     "Rename the parser\n",
+//!     "\n",
+//!     "# ------------------------ >8 ------------------------\n",
+//!     "# Do not modify or remove the line above.\n",
+//!     "# Everything below it will be ignored.\n",
+//!     "diff --git a/src/lib.rs b/src/lib.rs\n",
+//!     "index 5f3c0a1..0e8d6c4 100644\n",
+//!     "--- a/src/lib.rs\n",
+//!     "+++ b/src/lib.rs\n",
+//!     "@@ -1 +1 @@\n",
+//!     "-pub fn parse() {}\n",
+//!     "+pub fn read() {}\n",
+//! ));
+//!
+//! assert_eq!(BreakingChange.check(&commit).len(), 1);
+//! ```
+
+use std::{borrow::Cow, slice::Iter, vec::IntoIter};
+
+pub use file_diff::FileDiff;
+pub use file_status::FileStatus;
+pub use hunk::Hunk;
+pub use line::Line;
+
+mod file_diff;
+mod file_status;
+mod hunk;
+mod line;
+
+/// The files changed in a unified diff
+///
+/// # Examples
+///
+/// ```
+/// use mit_commit::diff::{Diff, FileStatus, Line};
+///
+/// let diff = Diff::from(concat!(
+///     "diff --git a/old.rs b/new.rs\n",
+///     "similarity index 90%\n",
+///     "rename from old.rs\n",
+///     "rename to new.rs\n",
+///     "index 5f3c0a1..0e8d6c4 100644\n",
+///     "--- a/old.rs\n",
+///     "+++ b/new.rs\n",
+///     "@@ -1,2 +1,2 @@ fn main() {\n",
+///     " let a = 1;\n",
+///     "-let b = 2;\n",
+///     "+let b = 3;\n",
+///     "diff --git a/run.sh b/run.sh\n",
+///     "old mode 100644\n",
+///     "new mode 100755\n",
+/// ));
+/// let files = diff.get_files();
+///
+/// assert_eq!(files.len(), 2);
+/// assert_eq!(files[0].get_status(), FileStatus::Renamed);
+/// assert_eq!(files[0].get_old_path(), Some("old.rs"));
+/// assert_eq!(files[0].get_path(), "new.rs");
+/// assert_eq!(files[0].get_similarity(), Some(90));
+/// assert_eq!(
+///     files[0].get_hunks()[0].get_lines(),
+///     &[
+///         Line::Context("let a = 1;".into()),
+///         Line::Removed("let b = 2;".into()),
+///         Line::Added("let b = 3;".into()),
+///     ]
+/// );
+/// assert_eq!(files[1].get_old_mode(), Some("100644"));
+/// assert_eq!(files[1].get_new_mode(), Some("100755"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Diff<'a> {
+    files: Vec<FileDiff<'a>>,
+}
+
+impl<'a> Diff<'a> {
+    /// Give you a [`Diff`] that owns all of its text, so it can outlive the
+    /// text it was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`Diff`], with any borrowed text copied
+    #[must_use]
+    pub fn into_owned(self) -> Diff<'static> {
+        Diff {
+            files: self.files.into_iter().map(FileDiff::into_owned).collect(),
+        }
+    }
+
+    /// Get the files that were changed, in the order they are in the diff
+    #[must_use]
+    pub fn get_files(&self) -> &[FileDiff<'a>] {
+        &self.files
+    }
+
+    /// Iterate over the [`FileDiff`] in the [`Diff`]
+    pub fn iter(&self) -> Iter<'_, FileDiff<'a>> {
+        self.files.iter()
+    }
+
+    /// Get the number of lines added in every file
+    #[must_use]
+    pub fn get_added(&self) -> usize {
+        self.files.iter().map(FileDiff::get_added).sum()
+    }
+
+    /// Get the number of lines removed in every file
+    #[must_use]
+    pub fn get_removed(&self) -> usize {
+        self.files.iter().map(FileDiff::get_removed).sum()
+    }
+}
+
+impl<'a> From<&'a str> for Diff<'a> {
+    /// Read the diff in some text, skipping anything before or between the
+    /// files that isn't part of it
+    fn from(text: &'a str) -> Self {
+        let lines = text.lines().collect::<Vec<_>>();
+        let mut files = vec![];
+        let mut index = 0;
+
+        while index < lines.len() {
+            if lines[index].starts_with(file_diff::HEADER) {
+                let (file, next) = FileDiff::parse(&lines, index);
+                files.push(file);
+                index = next;
+            } else {
+                index += 1;
+            }
+        }
+
+        Self { files }
+    }
+}
+
+impl<'a> From<Vec<FileDiff<'a>>> for Diff<'a> {
+    fn from(files: Vec<FileDiff<'a>>) -> Self {
+        Self { files }
+    }
+}
+
+impl<'a> IntoIterator for Diff<'a> {
+    type Item = FileDiff<'a>;
+    type IntoIter = IntoIter<FileDiff<'a>>;
+
+    /// Iterate over the [`FileDiff`] in the [`Diff`]
+    fn into_iter(self) -> Self::IntoIter {
+        self.files.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Diff<'a> {
+    type Item = &'a FileDiff<'a>;
+    type IntoIter = Iter<'a, FileDiff<'a>>;
+
+    /// Iterate over the [`FileDiff`] in the [`Diff`]
+    fn into_iter(self) -> Self::IntoIter {
+        self.files.iter()
+    }
+}
+
+/// Take the quotes and escapes off a path git has quoted, like
+/// `"caf\303\251.txt"`
+///
+/// Paths that aren't quoted are given back as they are.
+pub(crate) fn unquote(path: &str) -> Cow<'_, str> {
+    let Some(quoted) = path
+        .strip_prefix('"')
+        .and_then(|path| path.strip_suffix('"'))
+    else {
+        return Cow::Borrowed(path);
+    };
+
+    let mut bytes = Vec::with_capacity(quoted.len());
+    let mut rest = quoted.as_bytes();
+
+    while let Some((&byte, after)) = rest.split_first() {
+        rest = after;
+
+        if byte != b'\\' {
+            bytes.push(byte);
+            continue;
+        }
+
+        let Some((&escaped, after)) = rest.split_first() else {
+            break;
+        };
+        rest = after;
+
+        bytes.push(match escaped {
+            b'a' => 0x07,
+            b'b' => 0x08,
+            b'f' => 0x0c,
+            b'n' => b'\n',
+            b'r' => b'\r',
+            b't' => b'\t',
+            b'v' => 0x0b,
+            // Bytes that aren't printable are written as three octal digits
+            b'0'..=b'3'
+                if rest.len() >= 2
+                    && rest[..2].iter().all(|digit| (b'0'..=b'7').contains(digit)) =>
+            {
+                let value = (escaped - b'0') * 64 + (rest[0] - b'0') * 8 + (rest[1] - b'0');
+                rest = &rest[2..];
+                value
+            }
+            _ => escaped,
+        });
+    }
+
+    Cow::Owned(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quoted_paths_are_unquoted() {
+        assert_eq!(
+            unquote("\"caf\\303\\251 \\\"menu\\\".txt\""),
+            "café \"menu\".txt",
+            "Octal bytes and escaped quotes should be undone"
+        );
+        assert!(
+            matches!(unquote("src/lib.rs"), Cow::Borrowed("src/lib.rs")),
+            "Paths without quotes should be borrowed"
+        );
+    }
+
+    #[test]
+    fn test_text_around_the_diff_is_skipped() {
+        let diff = Diff::from(concat!(
+            "# Changes not staged for commit:\n",
+            "diff --git a/a.txt b/a.txt\n",
+            "--- a/a.txt\n",
+            "+++ b/a.txt\n",
+            "@@ -1 +1 @@\n",
+            "-one\n",
+            "+two\n",
+            "# ------------------------ >8 ------------------------\n",
+            "diff --git a/b.txt b/b.txt\n",
+            "deleted file mode 100644\n",
+        ));
+
+        assert_eq!(
+            diff.iter().map(FileDiff::get_path).collect::<Vec<_>>(),
+            vec!["a.txt", "b.txt"],
+            "Both files should be read"
+        );
+        assert_eq!(diff.get_added(), 1, "One line was added");
+        assert_eq!(diff.get_removed(), 1, "One line was removed");
+    }
+}
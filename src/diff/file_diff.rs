@@ -0,0 +1,390 @@
+use std::borrow::Cow;
+
+use super::{FileStatus, Hunk, unquote};
+
+/// The line git starts the diff of each file with
+pub const HEADER: &str = "diff --git ";
+
+/// The path git uses for the missing side of an added or deleted file
+const DEV_NULL: &str = "/dev/null";
+
+/// The changes to one file in a [`crate::diff::Diff`]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FileDiff<'a> {
+    old_path: Option<Cow<'a, str>>,
+    new_path: Option<Cow<'a, str>>,
+    status: FileStatus,
+    old_mode: Option<Cow<'a, str>>,
+    new_mode: Option<Cow<'a, str>>,
+    similarity: Option<u8>,
+    binary: bool,
+    hunks: Vec<Hunk<'a>>,
+}
+
+impl<'a> FileDiff<'a> {
+    /// Give you a [`FileDiff`] that owns all of its text, so it can outlive
+    /// the diff it was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`FileDiff`], with any borrowed text copied
+    #[must_use]
+    pub fn into_owned(self) -> FileDiff<'static> {
+        let owned = |text: Option<Cow<'a, str>>| text.map(|text| Cow::Owned(text.into_owned()));
+
+        FileDiff {
+            old_path: owned(self.old_path),
+            new_path: owned(self.new_path),
+            status: self.status,
+            old_mode: owned(self.old_mode),
+            new_mode: owned(self.new_mode),
+            similarity: self.similarity,
+            binary: self.binary,
+            hunks: self.hunks.into_iter().map(Hunk::into_owned).collect(),
+        }
+    }
+
+    /// Get the path of the file, after the change if it still exists, or
+    /// before it if it was deleted
+    #[must_use]
+    pub fn get_path(&self) -> &str {
+        self.new_path
+            .as_deref()
+            .or(self.old_path.as_deref())
+            .unwrap_or_default()
+    }
+
+    /// Get the path of the file before the change, or [`None`] if it was
+    /// added
+    #[must_use]
+    pub fn get_old_path(&self) -> Option<&str> {
+        self.old_path.as_deref()
+    }
+
+    /// Get the path of the file after the change, or [`None`] if it was
+    /// deleted
+    #[must_use]
+    pub fn get_new_path(&self) -> Option<&str> {
+        self.new_path.as_deref()
+    }
+
+    /// Get what happened to the file
+    #[must_use]
+    pub const fn get_status(&self) -> FileStatus {
+        self.status
+    }
+
+    /// Get the mode of the file before the change, like `100644`, if the
+    /// diff says what it was
+    #[must_use]
+    pub fn get_old_mode(&self) -> Option<&str> {
+        self.old_mode.as_deref()
+    }
+
+    /// Get the mode of the file after the change, like `100755`, if the
+    /// diff says what it is
+    #[must_use]
+    pub fn get_new_mode(&self) -> Option<&str> {
+        self.new_mode.as_deref()
+    }
+
+    /// Get how similar a renamed or copied file is to the one it came from,
+    /// as a percentage
+    #[must_use]
+    pub const fn get_similarity(&self) -> Option<u8> {
+        self.similarity
+    }
+
+    /// Whether the file is binary, in which case it has no hunks
+    #[must_use]
+    pub const fn is_binary(&self) -> bool {
+        self.binary
+    }
+
+    /// Get the hunks of changed lines in the file
+    #[must_use]
+    pub fn get_hunks(&self) -> &[Hunk<'a>] {
+        &self.hunks
+    }
+
+    /// Get the number of lines added to the file
+    #[must_use]
+    pub fn get_added(&self) -> usize {
+        self.hunks.iter().map(Hunk::get_added).sum()
+    }
+
+    /// Get the number of lines removed from the file
+    #[must_use]
+    pub fn get_removed(&self) -> usize {
+        self.hunks.iter().map(Hunk::get_removed).sum()
+    }
+
+    /// Read the file that starts at `start`, which is a `diff --git` line
+    ///
+    /// # Returns
+    ///
+    /// The file and the index of the first line after it
+    pub(crate) fn parse(lines: &[&'a str], start: usize) -> (Self, usize) {
+        let (old_path, new_path) = lines[start]
+            .strip_prefix(HEADER)
+            .and_then(split_header)
+            .unzip();
+        let mut file = Self {
+            old_path,
+            new_path,
+            ..Self::default()
+        };
+        let mut index = start + 1;
+
+        while index < lines.len() {
+            let line = lines[index];
+
+            if line.starts_with(HEADER) {
+                break;
+            }
+
+            if let Some((hunk, next)) = Hunk::parse(lines, index) {
+                file.hunks.push(hunk);
+                index = next;
+                continue;
+            }
+
+            // Everything else is an extended header, and only comes before
+            // the hunks
+            if file.hunks.is_empty() {
+                file.read_extended_header(line);
+            } else {
+                break;
+            }
+
+            index += 1;
+        }
+
+        (file, index)
+    }
+
+    fn read_extended_header(&mut self, line: &'a str) {
+        let Some((key, value)) = split_extended_header(line) else {
+            if line.starts_with("Binary files ") || line == "GIT binary patch" {
+                self.binary = true;
+            }
+            return;
+        };
+
+        match key {
+            "old mode" => self.old_mode = Some(Cow::Borrowed(value)),
+            "new mode" => self.new_mode = Some(Cow::Borrowed(value)),
+            "deleted file mode" => {
+                self.status = FileStatus::Deleted;
+                self.old_mode = Some(Cow::Borrowed(value));
+                self.new_path = None;
+            }
+            "new file mode" => {
+                self.status = FileStatus::Added;
+                self.new_mode = Some(Cow::Borrowed(value));
+                self.old_path = None;
+            }
+            "similarity index" => {
+                self.similarity = value.trim_end_matches('%').parse().ok();
+            }
+            "rename from" => {
+                self.status = FileStatus::Renamed;
+                self.old_path = Some(unquote(value));
+            }
+            "rename to" => {
+                self.status = FileStatus::Renamed;
+                self.new_path = Some(unquote(value));
+            }
+            "copy from" => {
+                self.status = FileStatus::Copied;
+                self.old_path = Some(unquote(value));
+            }
+            "copy to" => {
+                self.status = FileStatus::Copied;
+                self.new_path = Some(unquote(value));
+            }
+            "---" => self.old_path = diff_path(value),
+            "+++" => self.new_path = diff_path(value),
+            "index" => {
+                // Unchanged modes are put on the end, like `index 5f3c0a1..0e8d6c4 100644`
+                if let Some((_, mode)) = value.split_once(' ') {
+                    self.old_mode.get_or_insert(Cow::Borrowed(mode));
+                    self.new_mode.get_or_insert(Cow::Borrowed(mode));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Split an extended header line into what it is and its value
+fn split_extended_header(line: &str) -> Option<(&str, &str)> {
+    [
+        "old mode",
+        "new mode",
+        "deleted file mode",
+        "new file mode",
+        "similarity index",
+        "dissimilarity index",
+        "rename from",
+        "rename to",
+        "copy from",
+        "copy to",
+        "index",
+        "---",
+        "+++",
+    ]
+    .into_iter()
+    .find_map(|key| {
+        line.strip_prefix(key)
+            .and_then(|value| value.strip_prefix(' '))
+            .map(|value| (key, value))
+    })
+}
+
+/// The path on a `---` or `+++` line, without the `a/` or `b/` git puts on it
+fn diff_path(path: &str) -> Option<Cow<'_, str>> {
+    // Git puts a tab after paths with spaces in them
+    let path = path.trim_end_matches('\t');
+
+    (path != DEV_NULL).then(|| strip_prefix(unquote(path)))
+}
+
+/// Take the `a/` or `b/` off a path
+fn strip_prefix(path: Cow<'_, str>) -> Cow<'_, str> {
+    match path {
+        Cow::Borrowed(path) => Cow::Borrowed(
+            path.strip_prefix("a/")
+                .or_else(|| path.strip_prefix("b/"))
+                .unwrap_or(path),
+        ),
+        Cow::Owned(path) => Cow::Owned(
+            path.strip_prefix("a/")
+                .or_else(|| path.strip_prefix("b/"))
+                .unwrap_or(&path)
+                .to_string(),
+        ),
+    }
+}
+
+/// Split the `a/old b/new` after `diff --git` into the two paths
+///
+/// Paths with spaces in them aren't quoted here, so if neither side is
+/// quoted, it is split where both halves are the same, which is always the
+/// case unless the file was renamed. Renamed files have `rename from` and
+/// `rename to` lines later that say what the paths really are.
+fn split_header(paths: &str) -> Option<(Cow<'_, str>, Cow<'_, str>)> {
+    if paths.starts_with('"') {
+        let end = closing_quote(paths)?;
+        let (old, new) = paths.split_at(end + 1);
+
+        return Some((
+            strip_prefix(unquote(old)),
+            strip_prefix(unquote(new.trim_start())),
+        ));
+    }
+
+    if paths.ends_with('"') {
+        let (old, _) = paths.split_once(" \"")?;
+
+        return Some((
+            strip_prefix(Cow::Borrowed(old)),
+            strip_prefix(unquote(&paths[old.len() + 1..])),
+        ));
+    }
+
+    let middle = paths.len() / 2;
+    if paths.is_char_boundary(middle) && paths.as_bytes().get(middle) == Some(&b' ') {
+        let (old, new) = (&paths[..middle], &paths[middle + 1..]);
+        let (old, new) = (
+            strip_prefix(Cow::Borrowed(old)),
+            strip_prefix(Cow::Borrowed(new)),
+        );
+
+        if old == new {
+            return Some((old, new));
+        }
+    }
+
+    let (old, new) = paths.split_once(" b/")?;
+
+    Some((strip_prefix(Cow::Borrowed(old)), Cow::Borrowed(new)))
+}
+
+/// Find the quote that ends a quoted path that starts at the beginning of
+/// the text
+fn closing_quote(text: &str) -> Option<usize> {
+    let mut escaped = false;
+
+    text.char_indices().skip(1).find_map(|(index, character)| {
+        match (escaped, character) {
+            (false, '\\') => escaped = true,
+            (false, '"') => return Some(index),
+            _ => escaped = false,
+        }
+        None
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_headers_with_spaces_are_split_in_the_middle() {
+        assert_eq!(
+            split_header("a/my file b/my file"),
+            Some((Cow::Borrowed("my file"), Cow::Borrowed("my file"))),
+            "Both sides are the same path"
+        );
+        assert_eq!(
+            split_header("a/old name b/new name"),
+            Some((Cow::Borrowed("old name"), Cow::Borrowed("new name"))),
+            "Renames are split at the b/ prefix"
+        );
+        assert_eq!(
+            split_header("\"a/caf\\303\\251\" \"b/caf\\303\\251\""),
+            Some((Cow::Borrowed("café"), Cow::Borrowed("café"))),
+            "Quoted paths are unquoted"
+        );
+    }
+
+    #[test]
+    fn test_added_files_have_no_old_path() {
+        let lines = [
+            "diff --git a/README.md b/README.md",
+            "new file mode 100644",
+            "index 0000000..5f3c0a1",
+            "--- /dev/null",
+            "+++ b/README.md",
+            "@@ -0,0 +1 @@",
+            "+# mit-commit",
+        ];
+        let (file, next) = FileDiff::parse(&lines, 0);
+
+        assert_eq!(file.get_status(), FileStatus::Added, "The file is new");
+        assert_eq!(file.get_old_path(), None, "There was no file before");
+        assert_eq!(file.get_new_path(), Some("README.md"), "The new path");
+        assert_eq!(file.get_new_mode(), Some("100644"), "The new mode");
+        assert_eq!(file.get_added(), 1, "One line was added");
+        assert_eq!(next, lines.len(), "Every line is part of the file");
+    }
+
+    #[test]
+    fn test_binary_files_have_no_hunks() {
+        let lines = [
+            "diff --git a/logo.png b/logo.png",
+            "index 5f3c0a1..0e8d6c4 100644",
+            "Binary files a/logo.png and b/logo.png differ",
+        ];
+        let (file, _) = FileDiff::parse(&lines, 0);
+
+        assert!(file.is_binary(), "The file is binary");
+        assert!(file.get_hunks().is_empty(), "There are no hunks");
+        assert_eq!(
+            file.get_old_mode(),
+            Some("100644"),
+            "The mode from the index line"
+        );
+    }
+}
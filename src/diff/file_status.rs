@@ -0,0 +1,15 @@
+/// What happened to a file in a [`crate::diff::FileDiff`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FileStatus {
+    /// The file is new
+    Added,
+    /// The file was deleted
+    Deleted,
+    /// The file was changed where it was, including changes to only its mode
+    #[default]
+    Modified,
+    /// The file was moved, and may have been changed too
+    Renamed,
+    /// The file was copied from another, and may have been changed too
+    Copied,
+}
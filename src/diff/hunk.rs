@@ -0,0 +1,223 @@
+use std::borrow::Cow;
+
+use super::Line;
+
+/// A run of changed lines in a [`crate::diff::FileDiff`], starting with a
+/// line like `@@ -1,3 +1,4 @@ fn main() {`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hunk<'a> {
+    old_start: usize,
+    old_lines: usize,
+    new_start: usize,
+    new_lines: usize,
+    heading: Option<Cow<'a, str>>,
+    lines: Vec<Line<'a>>,
+}
+
+impl<'a> Hunk<'a> {
+    /// Give you a [`Hunk`] that owns all of its text, so it can outlive the
+    /// diff it was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`Hunk`], with any borrowed text copied
+    #[must_use]
+    pub fn into_owned(self) -> Hunk<'static> {
+        Hunk {
+            old_start: self.old_start,
+            old_lines: self.old_lines,
+            new_start: self.new_start,
+            new_lines: self.new_lines,
+            heading: self.heading.map(|heading| Cow::Owned(heading.into_owned())),
+            lines: self.lines.into_iter().map(Line::into_owned).collect(),
+        }
+    }
+
+    /// Get the line the hunk starts on in the file before the change
+    #[must_use]
+    pub const fn get_old_start(&self) -> usize {
+        self.old_start
+    }
+
+    /// Get how many lines of the file before the change are in the hunk
+    #[must_use]
+    pub const fn get_old_lines(&self) -> usize {
+        self.old_lines
+    }
+
+    /// Get the line the hunk starts on in the file after the change
+    #[must_use]
+    pub const fn get_new_start(&self) -> usize {
+        self.new_start
+    }
+
+    /// Get how many lines of the file after the change are in the hunk
+    #[must_use]
+    pub const fn get_new_lines(&self) -> usize {
+        self.new_lines
+    }
+
+    /// Get the text git puts after the line numbers, usually the function
+    /// the hunk is in
+    #[must_use]
+    pub fn get_heading(&self) -> Option<&str> {
+        self.heading.as_deref()
+    }
+
+    /// Get the lines in the hunk, in order
+    #[must_use]
+    pub fn get_lines(&self) -> &[Line<'a>] {
+        &self.lines
+    }
+
+    /// Get the number of lines added in the hunk
+    #[must_use]
+    pub fn get_added(&self) -> usize {
+        self.lines
+            .iter()
+            .filter(|line| matches!(line, Line::Added(_)))
+            .count()
+    }
+
+    /// Get the number of lines removed in the hunk
+    #[must_use]
+    pub fn get_removed(&self) -> usize {
+        self.lines
+            .iter()
+            .filter(|line| matches!(line, Line::Removed(_)))
+            .count()
+    }
+
+    /// Read the hunk that starts at `start`, if that line is a hunk header
+    ///
+    /// # Returns
+    ///
+    /// The hunk and the index of the first line after it
+    pub(crate) fn parse(lines: &[&'a str], start: usize) -> Option<(Self, usize)> {
+        let (old_start, old_lines, new_start, new_lines, heading) = parse_header(lines[start])?;
+        let mut old_remaining = old_lines;
+        let mut new_remaining = new_lines;
+        let mut parsed = vec![];
+        let mut index = start + 1;
+
+        while index < lines.len() && (old_remaining > 0 || new_remaining > 0) {
+            let line = lines[index];
+
+            match line.chars().next() {
+                // Some editors strip the trailing space from empty context
+                None => {
+                    parsed.push(Line::Context(Cow::Borrowed("")));
+                    old_remaining = old_remaining.saturating_sub(1);
+                    new_remaining = new_remaining.saturating_sub(1);
+                }
+                Some(' ') => {
+                    parsed.push(Line::Context(Cow::Borrowed(&line[1..])));
+                    old_remaining = old_remaining.saturating_sub(1);
+                    new_remaining = new_remaining.saturating_sub(1);
+                }
+                Some('-') => {
+                    parsed.push(Line::Removed(Cow::Borrowed(&line[1..])));
+                    old_remaining = old_remaining.saturating_sub(1);
+                }
+                Some('+') => {
+                    parsed.push(Line::Added(Cow::Borrowed(&line[1..])));
+                    new_remaining = new_remaining.saturating_sub(1);
+                }
+                Some('\\') => {}
+                Some(_) => break,
+            }
+
+            index += 1;
+        }
+
+        // "\ No newline at end of file" can come after the last line
+        while lines.get(index).is_some_and(|line| line.starts_with('\\')) {
+            index += 1;
+        }
+
+        Some((
+            Self {
+                old_start,
+                old_lines,
+                new_start,
+                new_lines,
+                heading,
+                lines: parsed,
+            },
+            index,
+        ))
+    }
+}
+
+type Header<'a> = (usize, usize, usize, usize, Option<Cow<'a, str>>);
+
+/// Read a line like `@@ -1,3 +1,4 @@ fn main() {`
+fn parse_header(line: &str) -> Option<Header<'_>> {
+    let rest = line.strip_prefix("@@ -")?;
+    let (ranges, heading) = rest.split_once(" @@")?;
+    let (old, new) = ranges.split_once(" +")?;
+    let (old_start, old_lines) = parse_range(old)?;
+    let (new_start, new_lines) = parse_range(new)?;
+    let heading = heading.trim_start();
+
+    Some((
+        old_start,
+        old_lines,
+        new_start,
+        new_lines,
+        (!heading.is_empty()).then_some(Cow::Borrowed(heading)),
+    ))
+}
+
+/// Read a range like `1,3`, where the number of lines is 1 if it is left out
+fn parse_range(range: &str) -> Option<(usize, usize)> {
+    match range.split_once(',') {
+        Some((start, lines)) => Some((start.parse().ok()?, lines.parse().ok()?)),
+        None => Some((range.parse().ok()?, 1)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hunk_headers_are_read() {
+        assert_eq!(
+            parse_header("@@ -1,3 +1,4 @@ fn main() {"),
+            Some((1, 3, 1, 4, Some(Cow::Borrowed("fn main() {")))),
+            "Both ranges and the heading should be read"
+        );
+        assert_eq!(
+            parse_header("@@ -0,0 +1 @@"),
+            Some((0, 0, 1, 1, None)),
+            "A range without a count is one line"
+        );
+        assert_eq!(parse_header("@@ nonsense @@"), None, "Not a hunk header");
+    }
+
+    #[test]
+    fn test_hunks_stop_when_the_ranges_are_used_up() {
+        let lines = [
+            "@@ -1,2 +1,2 @@",
+            " same",
+            "-old",
+            "\\ No newline at end of file",
+            "+new",
+            "\\ No newline at end of file",
+            "-- ",
+        ];
+        let (hunk, next) = Hunk::parse(&lines, 0).expect("the header is valid");
+
+        assert_eq!(
+            hunk.get_lines(),
+            &[
+                Line::Context("same".into()),
+                Line::Removed("old".into()),
+                Line::Added("new".into()),
+            ],
+            "The lines should be read without their markers"
+        );
+        assert_eq!(next, 6, "The signature after the hunk isn't part of it");
+    }
+}
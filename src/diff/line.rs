@@ -0,0 +1,37 @@
+use std::borrow::Cow;
+
+/// A line in a [`crate::diff::Hunk`], without the ` `, `+` or `-` before it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Line<'a> {
+    /// A line that is the same before and after, shown around the change
+    Context(Cow<'a, str>),
+    /// A line that was added
+    Added(Cow<'a, str>),
+    /// A line that was removed
+    Removed(Cow<'a, str>),
+}
+
+impl Line<'_> {
+    /// Give you a [`Line`] that owns its text, so it can outlive the diff it
+    /// was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`Line`], with any borrowed text copied
+    #[must_use]
+    pub fn into_owned(self) -> Line<'static> {
+        match self {
+            Self::Context(text) => Line::Context(Cow::Owned(text.into_owned())),
+            Self::Added(text) => Line::Added(Cow::Owned(text.into_owned())),
+            Self::Removed(text) => Line::Removed(Cow::Owned(text.into_owned())),
+        }
+    }
+
+    /// Get the text of the line
+    #[must_use]
+    pub fn get_text(&self) -> &str {
+        match self {
+            Self::Context(text) | Self::Added(text) | Self::Removed(text) => text,
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use std::ops::Range;
+
+use crate::Span;
+
+/// An edit to the text of a [`crate::CommitMessage`] that fixes a problem
+///
+/// The span is where in the message the edit goes, and the replacement is
+/// the text to put there. An empty span inserts the replacement, and an
+/// empty replacement deletes the text in the span.
+///
+/// # Examples
+///
+/// ```
+/// use mit_commit::{CommitMessage, Fix, Span};
+///
+/// let commit = CommitMessage::from("Subject.\n\nBody\n");
+/// let fix = Fix::new(Span::new(7, 1, 0, 7), String::new());
+///
+/// assert_eq!(fix.get_span(), Span::new(7, 1, 0, 7));
+/// assert_eq!(fix.get_replacement(), "");
+/// assert_eq!(
+///     String::from(commit.apply_fixes(&[fix]).unwrap()),
+///     "Subject\n\nBody\n"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fix {
+    span: Span,
+    replacement: String,
+}
+
+impl Fix {
+    /// Create a new [`Fix`]
+    ///
+    /// # Arguments
+    ///
+    /// * `span` - The text to replace
+    /// * `replacement` - What to replace it with
+    #[must_use]
+    pub const fn new(span: Span, replacement: String) -> Self {
+        Self { span, replacement }
+    }
+
+    /// Get where in the message the edit goes
+    #[must_use]
+    pub const fn get_span(&self) -> Span {
+        self.span
+    }
+
+    /// Get the text that replaces the span
+    #[must_use]
+    pub fn get_replacement(&self) -> &str {
+        &self.replacement
+    }
+
+    /// Does this fix edit any of the text in the range
+    ///
+    /// Insertions only overlap a range if they are inside it, not at either
+    /// end.
+    pub(crate) const fn overlaps(&self, range: &Range<usize>) -> bool {
+        self.span.get_offset() < range.end && range.start < self.span.get_end()
+    }
+
+    /// Can this fix and another both be applied
+    ///
+    /// They can't if they overlap, or if they insert different text in the
+    /// same place, as there's no telling which should go first.
+    pub(crate) fn conflicts_with(&self, other: &Self) -> bool {
+        let other_range = other.span.get_offset()..other.span.get_end();
+
+        self != other
+            && (self.overlaps(&other_range)
+                || (self.span.get_length() == 0
+                    && other.span.get_length() == 0
+                    && self.span.get_offset() == other.span.get_offset()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fix(offset: usize, length: usize, replacement: &str) -> Fix {
+        Fix::new(Span::new(offset, length, 0, offset), replacement.into())
+    }
+
+    #[test]
+    fn test_overlapping_fixes_conflict() {
+        assert!(
+            fix(0, 5, "a").conflicts_with(&fix(4, 2, "b")),
+            "Fixes editing the same text conflict"
+        );
+        assert!(
+            fix(0, 5, "a").conflicts_with(&fix(2, 0, "b")),
+            "Inserting inside another fix conflicts"
+        );
+        assert!(
+            fix(3, 0, "a").conflicts_with(&fix(3, 0, "b")),
+            "Different insertions in the same place conflict"
+        );
+    }
+
+    #[test]
+    fn test_adjacent_fixes_do_not_conflict() {
+        assert!(
+            !fix(0, 5, "a").conflicts_with(&fix(5, 2, "b")),
+            "Fixes that only touch don't conflict"
+        );
+        assert!(
+            !fix(0, 5, "a").conflicts_with(&fix(5, 0, "b")),
+            "Inserting at the end of another fix doesn't conflict"
+        );
+        assert!(
+            !fix(0, 5, "a").conflicts_with(&fix(0, 5, "a")),
+            "The same fix twice doesn't conflict"
+        );
+    }
+}
@@ -1,7 +1,12 @@
-use crate::{Body, Comment};
+use crate::{Body, Comment, Span};
 
 /// A `Fragment` from the [`CommitMessage`], either a comment or body
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum Fragment<'a> {
     /// A fragment that is going to appear in the git log
     Body(Body<'a>),
@@ -9,6 +14,58 @@ pub enum Fragment<'a> {
     Comment(Comment<'a>),
 }
 
+impl Fragment<'_> {
+    /// Split a grouped [`Fragment`] back into the lines it was grouped from
+    pub(crate) fn lines(&self, line_spans: &[Span]) -> Vec<Self> {
+        match self {
+            Self::Body(body) => body.lines(line_spans).into_iter().map(Self::from).collect(),
+            Self::Comment(comment) => comment
+                .lines(line_spans)
+                .into_iter()
+                .map(Self::from)
+                .collect(),
+        }
+    }
+}
+
+impl Fragment<'_> {
+    /// Give you a [`Fragment`] that owns its text, so it can outlive the message it
+    /// was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`Fragment`], with any borrowed text copied
+    #[must_use]
+    pub fn into_owned(self) -> Fragment<'static> {
+        match self {
+            Self::Body(body) => Fragment::Body(body.into_owned()),
+            Self::Comment(comment) => Fragment::Comment(comment.into_owned()),
+        }
+    }
+
+    /// Where this [`Fragment`] came from in the parsed [`crate::CommitMessage`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{CommitMessage, Span};
+    ///
+    /// let message = CommitMessage::from("Subject\n\n# Comment");
+    ///
+    /// assert_eq!(
+    ///     message.get_ast().last().and_then(|fragment| fragment.get_span()),
+    ///     Some(Span::new(9, 9, 2, 0))
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn get_span(&self) -> Option<Span> {
+        match self {
+            Self::Body(body) => body.get_span(),
+            Self::Comment(comment) => comment.get_span(),
+        }
+    }
+}
+
 impl<'a> From<Body<'a>> for Fragment<'a> {
     /// Create a Fragment from a Body
     ///
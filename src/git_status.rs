@@ -0,0 +1,440 @@
+//! The status git writes in the comments of a commit message
+//!
+//! When you commit, git puts the output of `git status` in the comments of
+//! the template, like
+//!
+//! ```text
+//! # On branch main
+//! # Your branch is up to date with 'origin/main'.
+//! #
+//! # Changes to be committed:
+//! #       new file:   src/lib.rs
+//! #
+//! # Untracked files:
+//! #       notes.txt
+//! #
+//! ```
+//!
+//! [`GitStatus`] reads that back into the branch, how it compares to its
+//! upstream, the lists of files, and whether a rebase, merge, cherry-pick,
+//! revert or bisect is in progress.
+//!
+//! Git translates the template, so it is read in whichever of git's
+//! languages it is written in. See [`crate::Comments::get_locale`] for the
+//! languages that are recognised.
+//!
+//! # Examples
+//!
+//! ```
+//! use mit_commit::{
+//!     CommitMessage,
+//!     git_status::{Change, Tracking},
+//! };
+//!
+//! let commit = CommitMessage::from(concat!(
+//!     "Add the library\n",
+//!     "\n",
+//!     "# Please enter the commit message for your changes. Lines starting\n",
+//!     "# with '#' will be ignored, and an empty message aborts the commit.\n",
+//!     "#\n",
+//!     "# On branch main\n",
+//!     "# Your branch is ahead of 'origin/main' by 2 commits.\n",
+//!     "#\n",
+//!     "# Changes to be committed:\n",
+//!     "#\tnew file:   src/lib.rs\n",
+//!     "#\n",
+//!     "# Untracked files:\n",
+//!     "#\tnotes.txt\n",
+//!     "#\n",
+//! ));
+//! let comments = commit.get_comments();
+//! let status = comments.git_status();
+//!
+//! assert_eq!(status.get_branch(), Some("main"));
+//! assert_eq!(
+//!     status.get_upstream().map(|upstream| upstream.get_tracking()),
+//!     Some(Tracking::Ahead(2))
+//! );
+//! assert_eq!(status.get_staged()[0].get_change(), Change::Added);
+//! assert_eq!(status.get_staged()[0].get_path(), "src/lib.rs");
+//! assert_eq!(status.get_untracked(), &["notes.txt"]);
+//! ```
+
+use std::borrow::Cow;
+
+pub use entry::{Change, Entry};
+pub use head::Head;
+pub use in_progress::InProgress;
+pub use upstream::{Tracking, Upstream};
+
+use crate::diff::unquote;
+use template::{ENGLISH, Key, Template};
+
+mod entry;
+mod head;
+mod in_progress;
+mod template;
+mod upstream;
+
+/// The status git writes in the comments of a commit message
+///
+/// Anything git didn't say is left empty, so a message without the status
+/// in its comments gives an empty [`GitStatus`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GitStatus<'a> {
+    head: Option<Head<'a>>,
+    upstream: Option<Upstream<'a>>,
+    in_progress: Option<InProgress<'a>>,
+    staged: Vec<Entry<'a>>,
+    unstaged: Vec<Entry<'a>>,
+    unmerged: Vec<Entry<'a>>,
+    untracked: Vec<Cow<'a, str>>,
+}
+
+impl<'a> GitStatus<'a> {
+    /// Give you a [`GitStatus`] that owns all of its text, so it can outlive
+    /// the message it was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`GitStatus`], with any borrowed text copied
+    #[must_use]
+    pub fn into_owned(self) -> GitStatus<'static> {
+        GitStatus {
+            head: self.head.map(Head::into_owned),
+            upstream: self.upstream.map(Upstream::into_owned),
+            in_progress: self.in_progress.map(InProgress::into_owned),
+            staged: self.staged.into_iter().map(Entry::into_owned).collect(),
+            unstaged: self.unstaged.into_iter().map(Entry::into_owned).collect(),
+            unmerged: self.unmerged.into_iter().map(Entry::into_owned).collect(),
+            untracked: self
+                .untracked
+                .into_iter()
+                .map(|path| Cow::Owned(path.into_owned()))
+                .collect(),
+        }
+    }
+
+    /// Get what git says `HEAD` is
+    #[must_use]
+    pub const fn get_head(&self) -> Option<&Head<'a>> {
+        self.head.as_ref()
+    }
+
+    /// Get the branch being committed to, if there is one
+    #[must_use]
+    pub fn get_branch(&self) -> Option<&str> {
+        match &self.head {
+            Some(Head::Branch(branch)) => Some(branch),
+            _ => None,
+        }
+    }
+
+    /// Get the branch the current branch tracks, and how they compare
+    #[must_use]
+    pub const fn get_upstream(&self) -> Option<&Upstream<'a>> {
+        self.upstream.as_ref()
+    }
+
+    /// Get the rebase, merge, cherry-pick, revert or bisect that is in
+    /// progress
+    #[must_use]
+    pub const fn get_in_progress(&self) -> Option<&InProgress<'a>> {
+        self.in_progress.as_ref()
+    }
+
+    /// Get the files under `Changes to be committed:`
+    #[must_use]
+    pub fn get_staged(&self) -> &[Entry<'a>] {
+        &self.staged
+    }
+
+    /// Get the files under `Changes not staged for commit:`
+    #[must_use]
+    pub fn get_unstaged(&self) -> &[Entry<'a>] {
+        &self.unstaged
+    }
+
+    /// Get the files under `Unmerged paths:`
+    #[must_use]
+    pub fn get_unmerged(&self) -> &[Entry<'a>] {
+        &self.unmerged
+    }
+
+    /// Get the files under `Untracked files:`
+    #[must_use]
+    pub fn get_untracked(&self) -> &[Cow<'a, str>] {
+        &self.untracked
+    }
+
+    /// Read the status from the lines of the comments, with their comment
+    /// markers still on
+    pub(crate) fn parse(lines: &[&'a str]) -> Self {
+        let lines = comment_lines(lines);
+
+        Self::parse_with(&lines, Template::detect(&lines).unwrap_or(&ENGLISH))
+    }
+
+    fn parse_with(lines: &[CommentLine<'a>], template: &Template) -> Self {
+        let mut status = Self::default();
+        let mut section = None;
+        let mut diverged_from = None;
+
+        for line in lines {
+            let text = match *line {
+                CommentLine::Item(item) => {
+                    status.read_item(section, item, template);
+                    continue;
+                }
+                CommentLine::Empty => {
+                    section = None;
+                    continue;
+                }
+                CommentLine::Text(text) => text,
+            };
+
+            // Some messages aren't translated, so are in English whatever
+            // the language
+            let Some((key, captures)) = template.message(text).or_else(|| ENGLISH.message(text))
+            else {
+                continue;
+            };
+            let capture = |index: usize| captures.get(index).map(|text| Cow::Borrowed(*text));
+            let count = |index: usize| {
+                captures
+                    .get(index)
+                    .and_then(|count| count.parse().ok())
+                    .unwrap_or_default()
+            };
+
+            match key {
+                Key::OnBranch => status.head = capture(0).map(Head::Branch),
+                Key::DetachedAt => status.head = capture(0).map(Head::DetachedAt),
+                Key::DetachedFrom => status.head = capture(0).map(Head::DetachedFrom),
+                Key::NotOnBranch => status.head = Some(Head::Detached),
+                Key::UpToDate => status.set_upstream(capture(0), Tracking::UpToDate),
+                Key::Ahead => status.set_upstream(capture(0), Tracking::Ahead(count(1))),
+                Key::Behind => status.set_upstream(capture(0), Tracking::Behind(count(1))),
+                Key::Gone => status.set_upstream(capture(0), Tracking::Gone),
+                Key::Diverged => {
+                    // The counts are on the next line
+                    diverged_from = capture(0);
+                    status.set_upstream(
+                        diverged_from.clone(),
+                        Tracking::Diverged {
+                            ahead: 0,
+                            behind: 0,
+                        },
+                    );
+                }
+                Key::DivergedCounts => status.set_upstream(
+                    diverged_from.clone(),
+                    Tracking::Diverged {
+                        ahead: count(0),
+                        behind: count(1),
+                    },
+                ),
+                Key::Staged | Key::Unstaged | Key::Unmerged | Key::Untracked => {
+                    section = Some(key);
+                }
+                Key::Rebase => {
+                    status.in_progress = Some(InProgress::Rebase {
+                        branch: if captures.len() > 1 { capture(0) } else { None },
+                        onto: captures.len().checked_sub(1).and_then(capture),
+                    });
+                }
+                Key::Merge => {
+                    status.in_progress.get_or_insert(InProgress::Merge);
+                }
+                Key::CherryPick => {
+                    status.in_progress = Some(InProgress::CherryPick { commit: capture(0) });
+                }
+                Key::Revert => {
+                    status.in_progress = Some(InProgress::Revert { commit: capture(0) });
+                }
+                Key::Bisect => {
+                    status.in_progress = Some(InProgress::Bisect { branch: capture(0) });
+                }
+                Key::Instructions => {}
+            }
+        }
+
+        status
+    }
+
+    fn set_upstream(&mut self, name: Option<Cow<'a, str>>, tracking: Tracking) {
+        if let Some(name) = name {
+            self.upstream = Some(Upstream::new(name, tracking));
+        }
+    }
+
+    /// Add a file from one of the lists
+    fn read_item(&mut self, section: Option<Key>, item: &'a str, template: &Template) {
+        let list = match section {
+            Some(Key::Untracked) => {
+                self.untracked.push(unquote(item));
+                return;
+            }
+            Some(Key::Staged) => &mut self.staged,
+            Some(Key::Unstaged) => &mut self.unstaged,
+            Some(Key::Unmerged) => &mut self.unmerged,
+            _ => return,
+        };
+        let Some((change, path)) = template.label(item).or_else(|| ENGLISH.label(item)) else {
+            return;
+        };
+
+        let entry = match path.split_once(" -> ") {
+            Some((old_path, path)) if matches!(change, Change::Renamed | Change::Copied) => {
+                Entry::new(change, unquote(path), Some(unquote(old_path)))
+            }
+            _ => Entry::new(change, unquote(path), None),
+        };
+
+        list.push(entry);
+    }
+}
+
+/// The language the status in the comments is written in, like `de` or
+/// `zh_CN`, from the lines of the comments with their comment markers still on
+pub(crate) fn locale(lines: &[&str]) -> Option<&'static str> {
+    Template::detect(&comment_lines(lines)).map(|template| template.locale)
+}
+
+/// A line of the comments, with the comment marker taken off
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CommentLine<'a> {
+    /// A message, like `On branch main`
+    Text(&'a str),
+    /// A file in a list, which git indents with a tab
+    Item(&'a str),
+    /// An empty line, which ends a list
+    Empty,
+}
+
+/// Take the comment marker off the lines, leaving out hints, which are
+/// indented, and lines that aren't comments
+fn comment_lines<'a>(lines: &[&'a str]) -> Vec<CommentLine<'a>> {
+    let marker = comment_marker(lines);
+
+    lines
+        .iter()
+        .filter_map(|line| {
+            let text = line.strip_prefix(marker)?;
+
+            if let Some(item) = text.strip_prefix('\t') {
+                return Some(CommentLine::Item(item));
+            }
+
+            let text = text.strip_prefix(' ').unwrap_or(text);
+
+            if text.trim().is_empty() {
+                Some(CommentLine::Empty)
+            } else if text.starts_with(char::is_whitespace) {
+                None
+            } else {
+                Some(CommentLine::Text(text))
+            }
+        })
+        .collect()
+}
+
+/// Work out the comment marker from the lines of the comments
+///
+/// Every line starts with the marker, and git always puts a space or tab
+/// after it, so it is what all of the lines have in common, up to the first
+/// space.
+fn comment_marker<'a>(lines: &[&'a str]) -> &'a str {
+    let Some(first) = lines.first() else {
+        return "";
+    };
+    let mut marker = first.split(char::is_whitespace).next().unwrap_or_default();
+
+    for line in lines {
+        let common = marker
+            .char_indices()
+            .zip(line.chars())
+            .take_while(|((_, ours), theirs)| ours == theirs)
+            .last()
+            .map_or(0, |((index, character), _)| index + character.len_utf8());
+
+        marker = &marker[..common];
+    }
+
+    marker
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_the_marker_is_what_the_lines_have_in_common() {
+        assert_eq!(
+            comment_marker(&["# On branch main", "#", "#\tnew file:   a"]),
+            "#",
+            "A single character marker"
+        );
+        assert_eq!(
+            comment_marker(&["// On branch main", "//", "//\tnew file:   a"]),
+            "//",
+            "A marker that is a string"
+        );
+        assert_eq!(comment_marker(&[]), "", "There are no comments");
+    }
+
+    #[test]
+    fn test_renames_and_quoted_paths_are_read() {
+        let status = GitStatus::parse(&[
+            "# Changes to be committed:",
+            "#\trenamed:    old.rs -> \"caf\\303\\251.rs\"",
+            "#\tmodified:   a -> b.rs",
+        ]);
+
+        assert_eq!(
+            status.get_staged(),
+            &[
+                Entry::new(Change::Renamed, "café.rs".into(), Some("old.rs".into())),
+                Entry::new(Change::Modified, "a -> b.rs".into(), None),
+            ],
+            "Only renames have an old path"
+        );
+    }
+
+    #[test]
+    fn test_diverged_branches_have_both_counts() {
+        let status = GitStatus::parse(&[
+            "# On branch main",
+            "# Your branch and 'origin/main' have diverged,",
+            "# and have 1 and 2 different commits each, respectively.",
+        ]);
+
+        assert_eq!(
+            status.get_upstream(),
+            Some(&Upstream::new(
+                "origin/main".into(),
+                Tracking::Diverged {
+                    ahead: 1,
+                    behind: 2
+                }
+            )),
+            "The counts are on the line after the upstream"
+        );
+    }
+
+    #[test]
+    fn test_files_outside_a_list_are_ignored() {
+        let status = GitStatus::parse(&[
+            "# Changes to be committed:",
+            "#\tnew file:   a.rs",
+            "#",
+            "#\tnew file:   b.rs",
+        ]);
+
+        assert_eq!(
+            status.get_staged(),
+            &[Entry::new(Change::Added, "a.rs".into(), None)],
+            "The list ends at the empty line"
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use std::borrow::Cow;
+
+/// How a file in a list in git's status template changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Change {
+    /// `new file:`
+    Added,
+    /// `copied:`
+    Copied,
+    /// `deleted:`
+    Deleted,
+    /// `modified:`
+    Modified,
+    /// `renamed:`
+    Renamed,
+    /// `typechange:`, like a file that became a symlink
+    TypeChanged,
+    /// `both deleted:`, a conflict
+    BothDeleted,
+    /// `added by us:`, a conflict
+    AddedByUs,
+    /// `deleted by them:`, a conflict
+    DeletedByThem,
+    /// `added by them:`, a conflict
+    AddedByThem,
+    /// `deleted by us:`, a conflict
+    DeletedByUs,
+    /// `both added:`, a conflict
+    BothAdded,
+    /// `both modified:`, a conflict
+    BothModified,
+}
+
+/// A file in a list in git's status template, like
+/// `renamed:    old.rs -> new.rs`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry<'a> {
+    change: Change,
+    path: Cow<'a, str>,
+    old_path: Option<Cow<'a, str>>,
+}
+
+impl<'a> Entry<'a> {
+    /// Create a new [`Entry`]
+    ///
+    /// # Arguments
+    ///
+    /// * `change` - How the file changed
+    /// * `path` - The path of the file
+    /// * `old_path` - Where the file was before, if it was renamed or copied
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::git_status::{Change, Entry};
+    ///
+    /// let entry = Entry::new(Change::Renamed, "new.rs".into(), Some("old.rs".into()));
+    ///
+    /// assert_eq!(entry.get_path(), "new.rs");
+    /// assert_eq!(entry.get_old_path(), Some("old.rs"));
+    /// ```
+    #[must_use]
+    pub const fn new(change: Change, path: Cow<'a, str>, old_path: Option<Cow<'a, str>>) -> Self {
+        Self {
+            change,
+            path,
+            old_path,
+        }
+    }
+
+    /// Give you an [`Entry`] that owns its text, so it can outlive the
+    /// message it was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`Entry`], with any borrowed text copied
+    #[must_use]
+    pub fn into_owned(self) -> Entry<'static> {
+        Entry {
+            change: self.change,
+            path: Cow::Owned(self.path.into_owned()),
+            old_path: self.old_path.map(|path| Cow::Owned(path.into_owned())),
+        }
+    }
+
+    /// Get how the file changed
+    #[must_use]
+    pub const fn get_change(&self) -> Change {
+        self.change
+    }
+
+    /// Get the path of the file
+    #[must_use]
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+
+    /// Get where the file was before, if it was renamed or copied
+    #[must_use]
+    pub fn get_old_path(&self) -> Option<&str> {
+        self.old_path.as_deref()
+    }
+}
@@ -0,0 +1,32 @@
+use std::borrow::Cow;
+
+/// What git says `HEAD` is in the status template
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Head<'a> {
+    /// `On branch main`
+    Branch(Cow<'a, str>),
+    /// `HEAD detached at 5f3c0a1`
+    DetachedAt(Cow<'a, str>),
+    /// `HEAD detached from 5f3c0a1`, where there have been commits since
+    DetachedFrom(Cow<'a, str>),
+    /// `Not currently on any branch.`
+    Detached,
+}
+
+impl Head<'_> {
+    /// Give you a [`Head`] that owns its text, so it can outlive the message
+    /// it was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`Head`], with any borrowed text copied
+    #[must_use]
+    pub fn into_owned(self) -> Head<'static> {
+        match self {
+            Self::Branch(branch) => Head::Branch(Cow::Owned(branch.into_owned())),
+            Self::DetachedAt(commit) => Head::DetachedAt(Cow::Owned(commit.into_owned())),
+            Self::DetachedFrom(commit) => Head::DetachedFrom(Cow::Owned(commit.into_owned())),
+            Self::Detached => Head::Detached,
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use std::borrow::Cow;
+
+/// Something git is in the middle of, from the status template
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InProgress<'a> {
+    /// A merge, which may have conflicts
+    Merge,
+    /// A rebase
+    Rebase {
+        /// The branch being rebased, if git says
+        branch: Option<Cow<'a, str>>,
+        /// What the branch is being rebased onto, if git says
+        onto: Option<Cow<'a, str>>,
+    },
+    /// A cherry-pick
+    CherryPick {
+        /// The commit being picked, if git says
+        commit: Option<Cow<'a, str>>,
+    },
+    /// A revert
+    Revert {
+        /// The commit being reverted, if git says
+        commit: Option<Cow<'a, str>>,
+    },
+    /// A bisect
+    Bisect {
+        /// The branch the bisect started from, if git says
+        branch: Option<Cow<'a, str>>,
+    },
+}
+
+impl InProgress<'_> {
+    /// Give you an [`InProgress`] that owns its text, so it can outlive the
+    /// message it was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`InProgress`], with any borrowed text copied
+    #[must_use]
+    pub fn into_owned(self) -> InProgress<'static> {
+        let owned = |text: Option<Cow<'_, str>>| text.map(|text| Cow::Owned(text.into_owned()));
+
+        match self {
+            Self::Merge => InProgress::Merge,
+            Self::Rebase { branch, onto } => InProgress::Rebase {
+                branch: owned(branch),
+                onto: owned(onto),
+            },
+            Self::CherryPick { commit } => InProgress::CherryPick {
+                commit: owned(commit),
+            },
+            Self::Revert { commit } => InProgress::Revert {
+                commit: owned(commit),
+            },
+            Self::Bisect { branch } => InProgress::Bisect {
+                branch: owned(branch),
+            },
+        }
+    }
+}
@@ -0,0 +1,370 @@
+pub use de::GERMAN;
+pub use en::ENGLISH;
+pub use es::SPANISH;
+pub use fr::FRENCH;
+pub use pt_pt::PORTUGUESE;
+pub use zh_cn::SIMPLIFIED_CHINESE;
+
+use super::{Change, CommentLine};
+
+mod de;
+mod en;
+mod es;
+mod fr;
+mod pt_pt;
+mod zh_cn;
+
+/// Every template that can be recognised, in the order they are tried
+///
+/// Git has no translations for some languages, like Japanese or Brazilian
+/// Portuguese, and writes the template in English for them.
+pub const TEMPLATES: [Template; 6] = [
+    ENGLISH,
+    GERMAN,
+    FRENCH,
+    SPANISH,
+    SIMPLIFIED_CHINESE,
+    PORTUGUESE,
+];
+
+/// What a line in git's status template means
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    /// `On branch %s`
+    OnBranch,
+    /// `HEAD detached at %s`
+    DetachedAt,
+    /// `HEAD detached from %s`
+    DetachedFrom,
+    /// `Not currently on any branch.`
+    NotOnBranch,
+    /// `Your branch is up to date with '%s'.`
+    UpToDate,
+    /// `Your branch is ahead of '%s' by %d commits.`
+    Ahead,
+    /// `Your branch is behind '%s' by %d commits, and can be fast-forwarded.`
+    Behind,
+    /// `Your branch and '%s' have diverged,`
+    Diverged,
+    /// `and have %d and %d different commits each, respectively.`
+    DivergedCounts,
+    /// `Your branch is based on '%s', but the upstream is gone.`
+    Gone,
+    /// `Changes to be committed:`
+    Staged,
+    /// `Changes not staged for commit:`
+    Unstaged,
+    /// `Unmerged paths:`
+    Unmerged,
+    /// `Untracked files:`
+    Untracked,
+    /// `You are currently rebasing branch '%s' on '%s'.`, with the branch
+    /// and where it is going onto when git says
+    Rebase,
+    /// `You have unmerged paths.`
+    Merge,
+    /// `You are currently cherry-picking commit %s.`, with the commit when
+    /// git says
+    CherryPick,
+    /// `You are currently reverting commit %s.`, with the commit when git
+    /// says
+    Revert,
+    /// `You are currently bisecting, started from branch '%s'.`, with the
+    /// branch when git says
+    Bisect,
+    /// `Please enter the commit message for your changes. Lines starting`,
+    /// which says nothing about the status, but does say what language
+    /// the template is in
+    Instructions,
+}
+
+/// The text git writes in the status template, in one language
+///
+/// The messages are patterns, where `%s`, `%d` and `%c` stand for the
+/// text, numbers and comment character git puts in, as they are in git's
+/// translations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Template {
+    pub locale: &'static str,
+    pub messages: &'static [(&'static str, Key)],
+    pub labels: &'static [(&'static str, Change)],
+}
+
+impl Template {
+    /// Find the template the comments are written in
+    ///
+    /// This is the one that recognises the most lines, with templates that
+    /// come first winning a tie.
+    pub fn detect(lines: &[CommentLine<'_>]) -> Option<&'static Self> {
+        TEMPLATES
+            .iter()
+            .map(|template| (template, template.recognised(lines)))
+            .filter(|(_, recognised)| *recognised > 0)
+            .fold(
+                None,
+                |best: Option<(&Self, usize)>, (template, recognised)| match best {
+                    Some((_, most)) if most >= recognised => best,
+                    _ => Some((template, recognised)),
+                },
+            )
+            .map(|(template, _)| template)
+    }
+
+    /// How many of the lines are messages or labels in this template
+    fn recognised(&self, lines: &[CommentLine<'_>]) -> usize {
+        lines
+            .iter()
+            .filter(|line| match line {
+                CommentLine::Text(text) => self.message(text).is_some(),
+                CommentLine::Item(item) => self.label(item).is_some(),
+                CommentLine::Empty => false,
+            })
+            .count()
+    }
+
+    /// Find the message a line is, and the text git put in it
+    pub fn message<'a>(&self, line: &'a str) -> Option<(Key, Vec<&'a str>)> {
+        self.messages
+            .iter()
+            .find_map(|(pattern, key)| captures(pattern, line).map(|captures| (*key, captures)))
+    }
+
+    /// Find the label at the start of a file in a list, and the rest of the
+    /// line after it
+    pub fn label<'a>(&self, line: &'a str) -> Option<(Change, &'a str)> {
+        self.labels.iter().find_map(|(label, change)| {
+            line.strip_prefix(label)
+                .map(|path| (*change, path.trim_start()))
+        })
+    }
+}
+
+/// A part of a pattern
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token<'a> {
+    Text(&'a str),
+    /// A placeholder, with where its value goes in the arguments
+    Placeholder(usize, Conversion),
+}
+
+/// What a placeholder stands for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Conversion {
+    /// `%s`
+    Text,
+    /// `%d`
+    Number,
+    /// `%c`
+    Character,
+}
+
+/// Split a pattern into the text and the placeholders in it
+///
+/// Placeholders can say which argument they are, like `%2$s`, so that
+/// translations can put them in a different order.
+fn tokenize(pattern: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = pattern;
+    let mut next_argument = 0;
+
+    while let Some(start) = rest.find('%') {
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+
+        let after = &rest[start + 1..];
+
+        if let Some(after) = after.strip_prefix('%') {
+            tokens.push(Token::Text("%"));
+            rest = after;
+            continue;
+        }
+
+        let digits = after.len() - after.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        let (argument, after) = match (
+            after[..digits].parse::<usize>(),
+            after[digits..].strip_prefix('$'),
+        ) {
+            (Ok(position), Some(after)) if position > 0 => (position - 1, after),
+            _ => {
+                next_argument += 1;
+                (next_argument - 1, after)
+            }
+        };
+
+        match after.chars().next() {
+            Some('s') => tokens.push(Token::Placeholder(argument, Conversion::Text)),
+            Some('d') => tokens.push(Token::Placeholder(argument, Conversion::Number)),
+            Some('c') => tokens.push(Token::Placeholder(argument, Conversion::Character)),
+            Some(_) | None => {
+                // Not a placeholder, so it is matched as it is
+                tokens.push(Token::Text("%"));
+                rest = &rest[start + 1..];
+                continue;
+            }
+        }
+
+        rest = &after[1..];
+    }
+
+    if !rest.is_empty() {
+        tokens.push(Token::Text(rest));
+    }
+
+    tokens
+}
+
+/// Match a line against a pattern, giving back the text in each placeholder
+/// in argument order
+fn captures<'a>(pattern: &str, line: &'a str) -> Option<Vec<&'a str>> {
+    let tokens = tokenize(pattern);
+    let mut captured = vec![];
+    let mut position = 0;
+
+    for (index, token) in tokens.iter().enumerate() {
+        let rest = &line[position..];
+
+        let (argument, value) = match *token {
+            Token::Text(text) => {
+                rest.strip_prefix(text)?;
+                position += text.len();
+                continue;
+            }
+            Token::Placeholder(argument, Conversion::Number) => {
+                let digits =
+                    rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+                (argument, &rest[..digits])
+            }
+            Token::Placeholder(argument, Conversion::Character) => (
+                argument,
+                &rest[..rest.chars().next().map_or(0, char::len_utf8)],
+            ),
+            Token::Placeholder(argument, Conversion::Text) => match tokens.get(index + 1) {
+                Some(Token::Text(text)) => (argument, &rest[..rest.find(text)?]),
+                _ => (argument, rest),
+            },
+        };
+
+        if value.is_empty() {
+            return None;
+        }
+
+        position += value.len();
+        captured.push((argument, value));
+    }
+
+    if position != line.len() {
+        return None;
+    }
+
+    captured.sort_by_key(|(argument, _)| *argument);
+
+    Some(captured.into_iter().map(|(_, value)| value).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_placeholders_capture_text_and_numbers() {
+        assert_eq!(
+            captures(
+                "Your branch is ahead of '%s' by %d commits.",
+                "Your branch is ahead of 'origin/main' by 12 commits."
+            ),
+            Some(vec!["origin/main", "12"]),
+            "The upstream and the count should be captured"
+        );
+        assert_eq!(
+            captures(
+                "Your branch is ahead of '%s' by %d commits.",
+                "Your branch is ahead of 'origin/main' by some commits."
+            ),
+            None,
+            "A number placeholder needs digits"
+        );
+        assert_eq!(
+            captures("On branch %s", "On branch"),
+            None,
+            "Placeholders can't be empty"
+        );
+    }
+
+    #[test]
+    fn test_placeholders_can_be_reordered() {
+        assert_eq!(
+            captures("'%2$s' <- '%1$s'", "'onto' <- 'branch'"),
+            Some(vec!["branch", "onto"]),
+            "The captures should be in argument order"
+        );
+        assert_eq!(
+            captures("100%% sure: %s", "100% sure: yes"),
+            Some(vec!["yes"]),
+            "A doubled percent is a percent"
+        );
+    }
+
+    #[test]
+    fn test_labels_are_stripped_from_files() {
+        assert_eq!(
+            ENGLISH.label("both modified:   src/lib.rs"),
+            Some((Change::BothModified, "src/lib.rs")),
+            "The label and padding should be taken off"
+        );
+        assert_eq!(ENGLISH.label("src/lib.rs"), None, "There is no label");
+    }
+
+    #[test]
+    fn test_the_comment_character_is_one_character() {
+        assert_eq!(
+            captures(
+                "Lines starting with '%c' will be ignored",
+                "Lines starting with ';' will be ignored"
+            ),
+            Some(vec![";"]),
+            "The comment character should be captured"
+        );
+        assert_eq!(
+            captures(
+                "Lines starting with '%c' will be ignored",
+                "Lines starting with '//' will be ignored"
+            ),
+            None,
+            "Only one character can be captured"
+        );
+    }
+
+    #[test]
+    fn test_every_template_has_every_label() {
+        for template in TEMPLATES {
+            assert_eq!(
+                template.labels.len(),
+                ENGLISH.labels.len(),
+                "{} should have a label for every change",
+                template.locale
+            );
+        }
+    }
+
+    #[test]
+    fn test_the_template_that_recognises_the_most_lines_is_detected() {
+        let lines = [
+            CommentLine::Text("Auf Branch main"),
+            CommentLine::Empty,
+            CommentLine::Text("Zum Commit vorgemerkte Änderungen:"),
+            CommentLine::Item("neue Datei:     src/lib.rs"),
+        ];
+
+        assert_eq!(
+            Template::detect(&lines).map(|template| template.locale),
+            Some("de"),
+            "Every line is in German"
+        );
+        assert_eq!(
+            Template::detect(&[CommentLine::Text("A note of my own")]),
+            None,
+            "Nothing is from a template"
+        );
+    }
+}
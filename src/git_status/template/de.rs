@@ -0,0 +1,118 @@
+use super::{Key, Template};
+use crate::git_status::Change;
+
+/// The template from git's German translation
+pub const GERMAN: Template = Template {
+    locale: "de",
+    messages: &[
+        ("Auf Branch %s", Key::OnBranch),
+        ("HEAD losgelöst bei %s", Key::DetachedAt),
+        ("HEAD losgelöst von %s", Key::DetachedFrom),
+        ("Im Moment auf keinem Branch.", Key::NotOnBranch),
+        (
+            "Ihr Branch ist auf demselben Stand wie '%s'.",
+            Key::UpToDate,
+        ),
+        (
+            "Ihr Branch basiert auf '%s', aber der Upstream-Branch wurde entfernt.",
+            Key::Gone,
+        ),
+        ("Zum Commit vorgemerkte Änderungen:", Key::Staged),
+        (
+            "Änderungen, die nicht zum Commit vorgemerkt sind:",
+            Key::Unstaged,
+        ),
+        // Some versions of the translation start these in lower case
+        ("Zum Commit vorgemerkte änderungen:", Key::Staged),
+        (
+            "änderungen, die nicht zum Commit vorgemerkt sind:",
+            Key::Unstaged,
+        ),
+        ("Nicht zusammengeführte Pfade:", Key::Unmerged),
+        ("Unversionierte Dateien:", Key::Untracked),
+        (
+            "Sie sind gerade beim Rebase von Branch '%s' auf '%s'.",
+            Key::Rebase,
+        ),
+        (
+            "Sie editieren gerade einen Commit während eines Rebase von Branch '%s' auf '%s'.",
+            Key::Rebase,
+        ),
+        (
+            "Sie teilen gerade einen Commit auf, während ein Rebase von Branch '%s' auf '%s' im Gange ist.",
+            Key::Rebase,
+        ),
+        ("Sie sind gerade beim Rebase.", Key::Rebase),
+        (
+            "Sie editieren gerade einen Commit während eines Rebase.",
+            Key::Rebase,
+        ),
+        (
+            "Sie teilen gerade einen Commit während eines Rebase auf.",
+            Key::Rebase,
+        ),
+        ("Sie haben nicht zusammengeführte Pfade.", Key::Merge),
+        (
+            "Alle Konflikte sind behoben, aber Sie sind immer noch beim Merge.",
+            Key::Merge,
+        ),
+        (
+            "Es sieht so aus, als committen Sie einen Merge.",
+            Key::Merge,
+        ),
+        (
+            "Sie führen gerade \"cherry-pick\" von Commit %s aus.",
+            Key::CherryPick,
+        ),
+        ("Cherry-pick zurzeit im Gange.", Key::CherryPick),
+        (
+            "Es sieht so aus, als committen Sie einen \"cherry-pick\".",
+            Key::CherryPick,
+        ),
+        ("Sie sind gerade beim Revert von Commit '%s'.", Key::Revert),
+        ("Revert zurzeit im Gange.", Key::Revert),
+        (
+            "Sie sind gerade bei einer binären Suche, gestartet von Branch '%s'.",
+            Key::Bisect,
+        ),
+        ("Sie sind gerade bei einer binären Suche.", Key::Bisect),
+        (
+            "Bitte geben Sie eine Commit-Beschreibung für Ihre Änderungen ein. Zeilen,",
+            Key::Instructions,
+        ),
+        (
+            "Bitte geben Sie eine Commit-Beschreibung für Ihre änderungen ein. Zeilen,",
+            Key::Instructions,
+        ),
+        ("Ihr Branch ist %2$d Commit vor '%1$s'.", Key::Ahead),
+        ("Ihr Branch ist %2$d Commits vor '%1$s'.", Key::Ahead),
+        (
+            "Ihr Branch ist %2$d Commit hinter '%1$s', und kann vorgespult werden.",
+            Key::Behind,
+        ),
+        (
+            "Ihr Branch ist %2$d Commits hinter '%1$s', und kann vorgespult werden.",
+            Key::Behind,
+        ),
+        ("Ihr Branch und '%s' sind divergiert,", Key::Diverged),
+        (
+            "und haben jeweils %d und %d unterschiedliche Commits.",
+            Key::DivergedCounts,
+        ),
+    ],
+    labels: &[
+        ("neue Datei:", Change::Added),
+        ("kopiert:", Change::Copied),
+        ("gelöscht:", Change::Deleted),
+        ("geändert:", Change::Modified),
+        ("umbenannt:", Change::Renamed),
+        ("Typänderung:", Change::TypeChanged),
+        ("beide gelöscht:", Change::BothDeleted),
+        ("von uns hinzugefügt:", Change::AddedByUs),
+        ("von denen gelöscht:", Change::DeletedByThem),
+        ("von denen hinzugefügt:", Change::AddedByThem),
+        ("von uns gelöscht:", Change::DeletedByUs),
+        ("von beiden hinzugefügt:", Change::BothAdded),
+        ("von beiden geändert:", Change::BothModified),
+    ],
+};
@@ -0,0 +1,103 @@
+use super::{Key, Template};
+use crate::git_status::Change;
+
+/// The template git writes when it isn't translated
+pub const ENGLISH: Template = Template {
+    locale: "en",
+    messages: &[
+        ("On branch %s", Key::OnBranch),
+        ("HEAD detached at %s", Key::DetachedAt),
+        ("HEAD detached from %s", Key::DetachedFrom),
+        ("Not currently on any branch.", Key::NotOnBranch),
+        ("Your branch is up to date with '%s'.", Key::UpToDate),
+        ("Your branch is up-to-date with '%s'.", Key::UpToDate),
+        ("Your branch is ahead of '%s' by %d commit.", Key::Ahead),
+        ("Your branch is ahead of '%s' by %d commits.", Key::Ahead),
+        (
+            "Your branch is behind '%s' by %d commit, and can be fast-forwarded.",
+            Key::Behind,
+        ),
+        (
+            "Your branch is behind '%s' by %d commits, and can be fast-forwarded.",
+            Key::Behind,
+        ),
+        ("Your branch and '%s' have diverged,", Key::Diverged),
+        (
+            "and have %d and %d different commit each, respectively.",
+            Key::DivergedCounts,
+        ),
+        (
+            "and have %d and %d different commits each, respectively.",
+            Key::DivergedCounts,
+        ),
+        (
+            "Your branch is based on '%s', but the upstream is gone.",
+            Key::Gone,
+        ),
+        ("Changes to be committed:", Key::Staged),
+        ("Changes not staged for commit:", Key::Unstaged),
+        ("Unmerged paths:", Key::Unmerged),
+        ("Untracked files:", Key::Untracked),
+        (
+            "You are currently rebasing branch '%s' on '%s'.",
+            Key::Rebase,
+        ),
+        (
+            "You are currently editing a commit while rebasing branch '%s' on '%s'.",
+            Key::Rebase,
+        ),
+        (
+            "You are currently splitting a commit while rebasing branch '%s' on '%s'.",
+            Key::Rebase,
+        ),
+        ("You are currently rebasing.", Key::Rebase),
+        (
+            "You are currently editing a commit during a rebase.",
+            Key::Rebase,
+        ),
+        (
+            "You are currently splitting a commit during a rebase.",
+            Key::Rebase,
+        ),
+        ("interactive rebase in progress; onto %s", Key::Rebase),
+        ("rebase in progress; onto %s", Key::Rebase),
+        ("You have unmerged paths.", Key::Merge),
+        ("All conflicts fixed but you are still merging.", Key::Merge),
+        ("It looks like you may be committing a merge.", Key::Merge),
+        (
+            "You are currently cherry-picking commit %s.",
+            Key::CherryPick,
+        ),
+        ("Cherry-pick currently in progress.", Key::CherryPick),
+        (
+            "It looks like you may be committing a cherry-pick.",
+            Key::CherryPick,
+        ),
+        ("You are currently reverting commit %s.", Key::Revert),
+        ("Revert currently in progress.", Key::Revert),
+        (
+            "You are currently bisecting, started from branch '%s'.",
+            Key::Bisect,
+        ),
+        ("You are currently bisecting.", Key::Bisect),
+        (
+            "Please enter the commit message for your changes. Lines starting",
+            Key::Instructions,
+        ),
+    ],
+    labels: &[
+        ("new file:", Change::Added),
+        ("copied:", Change::Copied),
+        ("deleted:", Change::Deleted),
+        ("modified:", Change::Modified),
+        ("renamed:", Change::Renamed),
+        ("typechange:", Change::TypeChanged),
+        ("both deleted:", Change::BothDeleted),
+        ("added by us:", Change::AddedByUs),
+        ("deleted by them:", Change::DeletedByThem),
+        ("added by them:", Change::AddedByThem),
+        ("deleted by us:", Change::DeletedByUs),
+        ("both added:", Change::BothAdded),
+        ("both modified:", Change::BothModified),
+    ],
+};
@@ -0,0 +1,93 @@
+use super::{Key, Template};
+use crate::git_status::Change;
+
+/// The template from git's Spanish translation
+pub const SPANISH: Template = Template {
+    locale: "es",
+    messages: &[
+        ("En la rama %s", Key::OnBranch),
+        ("HEAD desacoplada en %s", Key::DetachedAt),
+        ("HEAD desacoplada de %s", Key::DetachedFrom),
+        ("Actualmente no estás en ninguna rama.", Key::NotOnBranch),
+        ("Tu rama está actualizada con '%s'.", Key::UpToDate),
+        (
+            "Tu rama está basada en '%s', pero upstream ha desaparecido.",
+            Key::Gone,
+        ),
+        ("Cambios a ser confirmados:", Key::Staged),
+        ("Cambios no rastreados para el commit:", Key::Unstaged),
+        ("Rutas no fusionadas:", Key::Unmerged),
+        ("Archivos sin seguimiento:", Key::Untracked),
+        (
+            "Estás aplicando un rebase de la rama '%s' sobre '%s.",
+            Key::Rebase,
+        ),
+        (
+            "Estás editando un commit mientras se aplica un rebase de la rama '%s' sobre '%s'.",
+            Key::Rebase,
+        ),
+        (
+            "Estás dividiendo un commit mientras aplicas un rebase de la rama '%s' en '%s'.",
+            Key::Rebase,
+        ),
+        ("Estás aplicando un rebase.", Key::Rebase),
+        ("Estás editando un commit durante un rebase.", Key::Rebase),
+        ("Estás dividiendo un commit durante un rebase.", Key::Rebase),
+        ("Tienes rutas no fusionadas.", Key::Merge),
+        (
+            "Todos los conflictos resueltos pero sigues fusionando.",
+            Key::Merge,
+        ),
+        ("Parece que estás realizando una fusión.", Key::Merge),
+        (
+            "Estás realizando un cherry-picking en el commit %s.",
+            Key::CherryPick,
+        ),
+        ("Cherry-pick en progreso actualmente.", Key::CherryPick),
+        (
+            "Parece que puedes estar cometiendo una selección de cerezas.",
+            Key::CherryPick,
+        ),
+        ("Estás revirtiendo el commit %s.", Key::Revert),
+        ("Reversión en progreso actualmente.", Key::Revert),
+        (
+            "Estás aplicando un bisect, comenzando en la rama '%s'.",
+            Key::Bisect,
+        ),
+        ("Estás aplicando un bisect.", Key::Bisect),
+        (
+            "Por favor ingresa el mensaje del commit para tus cambios. Las",
+            Key::Instructions,
+        ),
+        ("Tu rama está adelantada a '%s' por %d commit.", Key::Ahead),
+        ("Tu rama está adelantada a '%s' por %d commits.", Key::Ahead),
+        (
+            "Tu rama está detrás de '%s' por %d commit, y puede ser avanzada rápido.",
+            Key::Behind,
+        ),
+        (
+            "Tu rama está detrás de '%s' por %d commits, y puede ser avanzada rápido.",
+            Key::Behind,
+        ),
+        ("Tu rama y '%s' han divergido,", Key::Diverged),
+        (
+            "y tienen %d y %d commits diferentes cada una respectivamente.",
+            Key::DivergedCounts,
+        ),
+    ],
+    labels: &[
+        ("nuevos archivos:", Change::Added),
+        ("copiados:", Change::Copied),
+        ("borrados:", Change::Deleted),
+        ("modificados:", Change::Modified),
+        ("renombrados:", Change::Renamed),
+        ("cambios de tipo:", Change::TypeChanged),
+        ("borrados por ambos:", Change::BothDeleted),
+        ("agregados por nosotros:", Change::AddedByUs),
+        ("borrados por ellos:", Change::DeletedByThem),
+        ("agregados por ellos:", Change::AddedByThem),
+        ("borrados por nosotros:", Change::DeletedByUs),
+        ("agregados por ambos:", Change::BothAdded),
+        ("modificados por ambos:", Change::BothModified),
+    ],
+};
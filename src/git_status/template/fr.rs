@@ -0,0 +1,109 @@
+use super::{Key, Template};
+use crate::git_status::Change;
+
+/// The template from git's French translation
+pub const FRENCH: Template = Template {
+    locale: "fr",
+    messages: &[
+        ("Sur la branche %s", Key::OnBranch),
+        ("HEAD détachée sur %s", Key::DetachedAt),
+        ("HEAD détachée depuis %s", Key::DetachedFrom),
+        ("Actuellement sur aucune branche.", Key::NotOnBranch),
+        ("Votre branche est à jour avec '%s'.", Key::UpToDate),
+        (
+            "Votre branche est basée sur '%s', mais la branche amont a disparu.",
+            Key::Gone,
+        ),
+        ("Modifications qui seront validées :", Key::Staged),
+        ("Modifications qui ne seront pas validées :", Key::Unstaged),
+        ("Chemins non fusionnés :", Key::Unmerged),
+        ("Fichiers non suivis:", Key::Untracked),
+        (
+            "Vous êtes en train de rebaser la branche '%s' sur '%s'.",
+            Key::Rebase,
+        ),
+        (
+            "Vous êtes actuellement en train d'éditer un commit pendant un rebasage de la branche '%s' sur '%s'.",
+            Key::Rebase,
+        ),
+        (
+            "Vous êtes actuellement en train de fractionner un commit pendant un rebasage de la branche '%s' sur '%s'.",
+            Key::Rebase,
+        ),
+        ("Vous êtes en train de rebaser.", Key::Rebase),
+        (
+            "Vous êtes actuellement en train d'éditer un commit pendant un rebasage.",
+            Key::Rebase,
+        ),
+        (
+            "Vous êtes actuellement en train de fractionner un commit pendant un rebasage.",
+            Key::Rebase,
+        ),
+        ("Vous avez des chemins non fusionnés.", Key::Merge),
+        (
+            "Tous les conflits sont réglés mais la fusion n'est pas terminée.",
+            Key::Merge,
+        ),
+        ("Il semble que vous validiez une fusion.", Key::Merge),
+        (
+            "Vous êtes actuellement en train de picorer le commit %s.",
+            Key::CherryPick,
+        ),
+        ("Picorage en cours.", Key::CherryPick),
+        ("Il semble que vous validiez un picorage.", Key::CherryPick),
+        (
+            "Vous êtes actuellement en train de rétablir le commit %s.",
+            Key::Revert,
+        ),
+        ("Rétablissement en cours.", Key::Revert),
+        (
+            "Vous êtes en cours de bissection, depuis la branche '%s'.",
+            Key::Bisect,
+        ),
+        ("Vous êtes en cours de bissection.", Key::Bisect),
+        (
+            "Veuillez saisir le message de validation pour vos modifications. Les lignes",
+            Key::Instructions,
+        ),
+        (
+            "Votre branche est en avance sur '%s' de %d commit.",
+            Key::Ahead,
+        ),
+        (
+            "Votre branche est en avance sur '%s' de %d commits.",
+            Key::Ahead,
+        ),
+        (
+            "Votre branche est en retard sur '%s' de %d commit, et peut être mise à jour en avance rapide.",
+            Key::Behind,
+        ),
+        (
+            "Votre branche est en retard sur '%s' de %d commits, et peut être mise à jour en avance rapide.",
+            Key::Behind,
+        ),
+        ("Votre branche et '%s' ont divergé,", Key::Diverged),
+        (
+            "et ont %d et %d commit différent chacune respectivement.",
+            Key::DivergedCounts,
+        ),
+        (
+            "et ont %d et %d commits différents chacune respectivement.",
+            Key::DivergedCounts,
+        ),
+    ],
+    labels: &[
+        ("nouveau fichier\u{a0}:", Change::Added),
+        ("copié\u{a0}:", Change::Copied),
+        ("supprimé\u{a0}:", Change::Deleted),
+        ("modifié\u{a0}:", Change::Modified),
+        ("renommé\u{a0}:", Change::Renamed),
+        ("modif. type\u{a0}:", Change::TypeChanged),
+        ("supprimé des deux côtés :", Change::BothDeleted),
+        ("ajouté par nous :", Change::AddedByUs),
+        ("supprimé par eux :", Change::DeletedByThem),
+        ("ajouté par eux :", Change::AddedByThem),
+        ("supprimé par nous :", Change::DeletedByUs),
+        ("ajouté de deux côtés :", Change::BothAdded),
+        ("modifié des deux côtés :", Change::BothModified),
+    ],
+};
@@ -0,0 +1,94 @@
+use super::{Key, Template};
+use crate::git_status::Change;
+
+/// The template from git's European Portuguese translation
+pub const PORTUGUESE: Template = Template {
+    locale: "pt_PT",
+    messages: &[
+        ("Em ramo %s", Key::OnBranch),
+        ("HEAD desanexada em %s", Key::DetachedAt),
+        ("HEAD desanexada de %s", Key::DetachedFrom),
+        ("Atualmente em ramo nenhum.", Key::NotOnBranch),
+        ("Teu ramo está atualizado com '%s'.", Key::UpToDate),
+        (
+            "Teu ramo é baseado em '%s', mas o upstream desapareceu.",
+            Key::Gone,
+        ),
+        ("Alterações para serem memorizadas:", Key::Staged),
+        ("Alterações por encenar para memória:", Key::Unstaged),
+        ("Caminhos por juntar:", Key::Unmerged),
+        ("Ficheiros desmonitorizados:", Key::Untracked),
+        ("Estás rebaseando ramo '%s' sobre '%s'.", Key::Rebase),
+        (
+            "Estás a editar uma memória enquanto rebaseas o ramo '%s' sobre '%s'.",
+            Key::Rebase,
+        ),
+        (
+            "Estás dividindo uma memória enquanto rebaseas ramo '%s' sobre '%s'.",
+            Key::Rebase,
+        ),
+        ("Estás rebaseando neste momento.", Key::Rebase),
+        (
+            "Está a editar uma memória durante um rebaseamento.",
+            Key::Rebase,
+        ),
+        (
+            "Está dividindo uma memória durante um rebaseamento.",
+            Key::Rebase,
+        ),
+        ("Tens caminhos por juntar.", Key::Merge),
+        (
+            "Todos os conflitos foram corrigidos mas ainda estás juntando.",
+            Key::Merge,
+        ),
+        ("Parece que estás a memorizar uma junção.", Key::Merge),
+        ("Estás apanhando memória %s.", Key::CherryPick),
+        ("Cherry-pick atualmente em curso", Key::CherryPick),
+        ("Parece que estás memorizando uma apanha.", Key::CherryPick),
+        ("Estás atualmente revertendo memória %s.", Key::Revert),
+        ("Reversão atualmente em curso.", Key::Revert),
+        ("Estás bissetando, iniciado no ramo '%s'.", Key::Bisect),
+        ("Estás bissetando neste momento.", Key::Bisect),
+        (
+            "Por favor, introduz a mensagem de memória das tuas alterações.",
+            Key::Instructions,
+        ),
+        ("Teu ramo está à frente de '%s' por %d memória.", Key::Ahead),
+        (
+            "Teu ramo está à frente de '%s' por %d memórias.",
+            Key::Ahead,
+        ),
+        (
+            "Teu ramo está atrás de '%s' por %d memória, e pode ser avançado.",
+            Key::Behind,
+        ),
+        (
+            "Teu ramo está atrás de '%s' por %d memórias, e pode ser avançado.",
+            Key::Behind,
+        ),
+        ("Teu ramo e '%s' divergiram,", Key::Diverged),
+        (
+            "tendo cada um %d e %d memória diferente, respetivamente.",
+            Key::DivergedCounts,
+        ),
+        (
+            "tendo cada um %d e %d memórias diferentes, respetivamente.",
+            Key::DivergedCounts,
+        ),
+    ],
+    labels: &[
+        ("novo ficheiro:", Change::Added),
+        ("copiado:", Change::Copied),
+        ("eliminado:", Change::Deleted),
+        ("modificado:", Change::Modified),
+        ("renomeado:", Change::Renamed),
+        ("tipo alterado:", Change::TypeChanged),
+        ("eliminado por ambos:", Change::BothDeleted),
+        ("adicionado por nós:", Change::AddedByUs),
+        ("eliminado por eles:", Change::DeletedByThem),
+        ("adicionado por eles:", Change::AddedByThem),
+        ("eliminado por nós:", Change::DeletedByUs),
+        ("adicionado por ambos:", Change::BothAdded),
+        ("modificado por ambos:", Change::BothModified),
+    ],
+};
@@ -0,0 +1,70 @@
+use super::{Key, Template};
+use crate::git_status::Change;
+
+/// The template from git's Simplified Chinese translation
+pub const SIMPLIFIED_CHINESE: Template = Template {
+    locale: "zh_CN",
+    messages: &[
+        ("位于分支 %s", Key::OnBranch),
+        ("头指针分离于 %s", Key::DetachedAt),
+        ("头指针分离自 %s", Key::DetachedFrom),
+        ("当前不在任何分支上。", Key::NotOnBranch),
+        ("您的分支与上游分支 '%s' 一致。", Key::UpToDate),
+        ("您的分支基于 '%s'，但此上游分支已经不存在。", Key::Gone),
+        ("要提交的变更：", Key::Staged),
+        ("尚未暂存以备提交的变更：", Key::Unstaged),
+        ("未合并的路径：", Key::Unmerged),
+        ("未跟踪的文件:", Key::Untracked),
+        ("您在执行将分支 '%s' 变基到 '%s' 的操作。", Key::Rebase),
+        (
+            "您在执行将分支 '%s' 变基到 '%s' 的操作时编辑提交。",
+            Key::Rebase,
+        ),
+        (
+            "您在执行将分支 '%s' 变基到 '%s' 的操作时拆分提交。",
+            Key::Rebase,
+        ),
+        ("您在执行变基操作。", Key::Rebase),
+        ("您在执行变基操作时编辑提交。", Key::Rebase),
+        ("您在执行变基操作时拆分提交。", Key::Rebase),
+        ("您有尚未合并的路径。", Key::Merge),
+        ("所有冲突已解决但您仍处于合并中。", Key::Merge),
+        ("似乎您正在做一个合并提交。如果不对，请运行", Key::Merge),
+        ("您在执行拣选提交 %s 的操作。", Key::CherryPick),
+        ("拣选操作正在进行中。", Key::CherryPick),
+        (
+            "似乎您正在做一个拣选提交。如果不对，请运行",
+            Key::CherryPick,
+        ),
+        ("您在执行反转提交 %s 的操作。", Key::Revert),
+        ("还原操作正在行中。", Key::Revert),
+        ("您在执行从分支 '%s' 开始的二分查找操作。", Key::Bisect),
+        ("您在执行二分查找操作。", Key::Bisect),
+        (
+            "请为您的变更输入提交说明。以 '%c' 开始的行将被忽略，而一个空的提交",
+            Key::Instructions,
+        ),
+        ("您的分支领先 '%s' 共 %d 个提交。", Key::Ahead),
+        (
+            "您的分支落后 '%s' 共 %d 个提交，并且可以快进。",
+            Key::Behind,
+        ),
+        ("您的分支和 '%s' 出现了偏离，", Key::Diverged),
+        ("并且分别有 %d 和 %d 处不同的提交。", Key::DivergedCounts),
+    ],
+    labels: &[
+        ("新文件：", Change::Added),
+        ("拷贝：", Change::Copied),
+        ("删除：", Change::Deleted),
+        ("修改：", Change::Modified),
+        ("重命名：", Change::Renamed),
+        ("类型变更：", Change::TypeChanged),
+        ("双方删除：", Change::BothDeleted),
+        ("由我们添加：", Change::AddedByUs),
+        ("由他们删除：", Change::DeletedByThem),
+        ("由他们添加：", Change::AddedByThem),
+        ("由我们删除：", Change::DeletedByUs),
+        ("双方添加：", Change::BothAdded),
+        ("双方修改：", Change::BothModified),
+    ],
+};
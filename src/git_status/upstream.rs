@@ -0,0 +1,80 @@
+use std::borrow::Cow;
+
+/// How a branch compares to the branch it tracks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tracking {
+    /// `Your branch is up to date with 'origin/main'.`
+    UpToDate,
+    /// `Your branch is ahead of 'origin/main' by 2 commits.`
+    Ahead(u32),
+    /// `Your branch is behind 'origin/main' by 2 commits, and can be
+    /// fast-forwarded.`
+    Behind(u32),
+    /// `Your branch and 'origin/main' have diverged, and have 1 and 2
+    /// different commits each, respectively.`
+    Diverged {
+        /// Commits on the branch that aren't upstream
+        ahead: u32,
+        /// Commits upstream that aren't on the branch
+        behind: u32,
+    },
+    /// `Your branch is based on 'origin/main', but the upstream is gone.`
+    Gone,
+}
+
+/// The branch the current branch tracks, from git's status template
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Upstream<'a> {
+    name: Cow<'a, str>,
+    tracking: Tracking,
+}
+
+impl<'a> Upstream<'a> {
+    /// Create a new [`Upstream`]
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the branch being tracked, like `origin/main`
+    /// * `tracking` - How the current branch compares to it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::git_status::{Tracking, Upstream};
+    ///
+    /// let upstream = Upstream::new("origin/main".into(), Tracking::Ahead(2));
+    ///
+    /// assert_eq!(upstream.get_name(), "origin/main");
+    /// assert_eq!(upstream.get_tracking(), Tracking::Ahead(2));
+    /// ```
+    #[must_use]
+    pub const fn new(name: Cow<'a, str>, tracking: Tracking) -> Self {
+        Self { name, tracking }
+    }
+
+    /// Give you an [`Upstream`] that owns its text, so it can outlive the
+    /// message it was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`Upstream`], with any borrowed text copied
+    #[must_use]
+    pub fn into_owned(self) -> Upstream<'static> {
+        Upstream {
+            name: Cow::Owned(self.name.into_owned()),
+            tracking: self.tracking,
+        }
+    }
+
+    /// Get the name of the branch being tracked, like `origin/main`
+    #[must_use]
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get how the current branch compares to the one it tracks
+    #[must_use]
+    pub const fn get_tracking(&self) -> Tracking {
+        self.tracking
+    }
+}
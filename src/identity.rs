@@ -0,0 +1,378 @@
+use std::{borrow::Cow, convert::TryFrom, ops::Range};
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::{Span, Trailer, span};
+
+/// A name and email, in the `Name <email>` form git uses
+///
+/// This is the form of the `author` and `committer` headers, before the
+/// date, and of the value of trailers like `Co-authored-by` and
+/// `Signed-off-by`.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// use mit_commit::{CommitMessage, Identity};
+///
+/// let commit = CommitMessage::from(
+///     "Subject\n\nCo-authored-by: Billie Thompson <billie@example.com>\nCo-authored-by: Someone <someone.example.com>\n",
+/// );
+/// let trailers = commit.get_trailers();
+/// let co_authors = trailers
+///     .iter()
+///     .map(Identity::try_from)
+///     .collect::<Vec<_>>();
+///
+/// let billie = co_authors[0].as_ref().expect("this is a valid identity");
+/// assert_eq!(billie.get_name(), "Billie Thompson");
+/// assert_eq!(billie.get_email(), "billie@example.com");
+/// assert_eq!(
+///     co_authors[1].as_ref().unwrap_err().to_string(),
+///     "the email address is not valid"
+/// );
+/// ```
+#[derive(Debug, Clone, Eq)]
+pub struct Identity<'a> {
+    name: Cow<'a, str>,
+    email: Cow<'a, str>,
+    span: Option<Span>,
+}
+
+impl<'a> Identity<'a> {
+    /// Give you an [`Identity`] that owns its text, so it can outlive the
+    /// message it was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`Identity`], with any borrowed text copied
+    #[must_use]
+    pub fn into_owned(self) -> Identity<'static> {
+        Identity {
+            name: Cow::Owned(self.name.into_owned()),
+            email: Cow::Owned(self.email.into_owned()),
+            span: self.span,
+        }
+    }
+
+    /// Create a new [`Identity`]
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name, like `Billie Thompson`
+    /// * `email` - The email address, without the `<>` around it
+    ///
+    /// # Returns
+    ///
+    /// An identity that wasn't parsed from anything, so has no span
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::Identity;
+    ///
+    /// assert_eq!(
+    ///     String::from(Identity::new("Billie Thompson".into(), "billie@example.com".into())),
+    ///     "Billie Thompson <billie@example.com>"
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn new(name: Cow<'a, str>, email: Cow<'a, str>) -> Self {
+        Self {
+            name,
+            email,
+            span: None,
+        }
+    }
+
+    /// Get the name, like `Billie Thompson`
+    ///
+    /// A name folded over several lines in a trailer is joined back onto one.
+    #[must_use]
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the email address, without the `<>` around it
+    #[must_use]
+    pub fn get_email(&self) -> &str {
+        &self.email
+    }
+
+    /// Where this [`Identity`] came from in the text it was parsed from
+    ///
+    /// For an identity from a [`Trailer`] or [`crate::CommitHeader`] this is
+    /// in the message or object the trailer or header was parsed from.
+    ///
+    /// # Returns
+    ///
+    /// The span from the start of the name to the closing `>`, or None if it
+    /// wasn't parsed
+    #[must_use]
+    pub const fn get_span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// Read the `Name <email>` at the start of some text
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to read
+    /// * `origin` - Where the text came from, if it was parsed
+    ///
+    /// # Returns
+    ///
+    /// The identity, and the offset just past its `>`
+    ///
+    /// # Errors
+    ///
+    /// If there is no name, no email, or the email isn't valid
+    pub(crate) fn parse_prefix(
+        text: &Cow<'a, str>,
+        origin: Option<Span>,
+    ) -> Result<(Self, usize), Error> {
+        let Some(open) = text.find('<') else {
+            return Err(Error::MissingEmail(text.to_string(), (0, text.len())));
+        };
+        let Some(close) = text[open..].find('>').map(|close| open + close) else {
+            return Err(Error::UnclosedEmail(text.to_string(), (open, 1)));
+        };
+
+        let name = trimmed(text, 0..open);
+        if name.is_empty() {
+            return Err(Error::MissingName(text.to_string(), (open, 1)));
+        }
+
+        let email = open + 1..close;
+        if !is_email(&text[email.clone()]) {
+            return Err(Error::InvalidEmail(
+                text.to_string(),
+                (email.start, email.len()),
+            ));
+        }
+
+        let span = origin.map(|origin| origin.within(text, name.start..close + 1));
+        let name = span::slice(text, name);
+        // A name folded onto another line is still one name
+        let name = if name.contains('\n') {
+            Cow::Owned(name.split_whitespace().collect::<Vec<_>>().join(" "))
+        } else {
+            name
+        };
+
+        Ok((
+            Self {
+                name,
+                email: span::slice(text, email),
+                span,
+            },
+            close + 1,
+        ))
+    }
+
+    /// Read some text that is only a `Name <email>`
+    pub(crate) fn parse(text: &Cow<'a, str>, origin: Option<Span>) -> Result<Self, Error> {
+        let (identity, end) = Self::parse_prefix(text, origin)?;
+
+        let rest = trimmed(text, end..text.len());
+        if !rest.is_empty() {
+            return Err(Error::UnexpectedText(
+                text.to_string(),
+                (rest.start, rest.len()),
+            ));
+        }
+
+        Ok(identity)
+    }
+}
+
+/// The range of some text without the whitespace around it
+pub fn trimmed(text: &str, range: Range<usize>) -> Range<usize> {
+    let part = &text[range.clone()];
+    let start = range.start + (part.len() - part.trim_start().len());
+    let end = range.end - (part.len() - part.trim_end().len());
+
+    start..end.max(start)
+}
+
+/// Is this something that could be delivered to, like `name@example.com`
+fn is_email(email: &str) -> bool {
+    email.split_once('@').is_some_and(|(local, domain)| {
+        !local.is_empty()
+            && !domain.is_empty()
+            && !domain.contains('@')
+            && !domain.starts_with('.')
+            && !domain.ends_with('.')
+            && !email
+                .chars()
+                .any(|character| character.is_whitespace() || "<>,".contains(character))
+    })
+}
+
+impl PartialEq for Identity<'_> {
+    /// Identities are equal if their text is, wherever they came from
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.email == other.email
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Identity<'a> {
+    type Error = Error;
+
+    /// Parse a `Name <email>`
+    ///
+    /// # Errors
+    ///
+    /// If there is no name, no email, the email isn't valid, or there is
+    /// anything after the email. The error points at the problem.
+    fn try_from(text: &'a str) -> Result<Self, Self::Error> {
+        Self::parse(&Cow::Borrowed(text), Some(Span::new(0, text.len(), 0, 0)))
+    }
+}
+
+impl<'a> TryFrom<&Trailer<'a>> for Identity<'a> {
+    type Error = Error;
+
+    /// Parse the value of a trailer like `Co-authored-by` as an identity
+    ///
+    /// # Errors
+    ///
+    /// If there is no name, no email, the email isn't valid, or there is
+    /// anything after the email. The error points at the problem in the
+    /// value of the trailer.
+    fn try_from(trailer: &Trailer<'a>) -> Result<Self, Self::Error> {
+        let (value, origin) = trailer.value_with_span();
+
+        Self::parse(value, origin)
+    }
+}
+
+impl From<Identity<'_>> for String {
+    fn from(identity: Identity<'_>) -> Self {
+        format!("{} <{}>", identity.name, identity.email)
+    }
+}
+
+/// Errors in reading a `Name <email>`
+#[derive(Error, Debug, Diagnostic)]
+pub enum Error {
+    /// There is no `<email>`
+    #[error("there is no email address")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::identity::error::missing_email),
+        help("add an email address after the name, like `Billie Thompson <billie@example.com>`")
+    )]
+    MissingEmail(
+        #[source_code] String,
+        #[label("expected an email in `<>` here")] (usize, usize),
+    ),
+    /// The `<` before the email has no `>` after it
+    #[error("the email address is not closed")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::identity::error::unclosed_email),
+        help("close the email address with `>`")
+    )]
+    UnclosedEmail(
+        #[source_code] String,
+        #[label("this `<` has no `>`")] (usize, usize),
+    ),
+    /// There is nothing before the email
+    #[error("there is no name")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::identity::error::missing_name),
+        help("add a name before the email address, like `Billie Thompson <billie@example.com>`")
+    )]
+    MissingName(
+        #[source_code] String,
+        #[label("expected a name before this")] (usize, usize),
+    ),
+    /// The email doesn't look like `name@example.com`
+    #[error("the email address is not valid")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::identity::error::invalid_email),
+        help("email addresses look like `name@example.com`")
+    )]
+    InvalidEmail(
+        #[source_code] String,
+        #[label("this is not an email address")] (usize, usize),
+    ),
+    /// There is more after the email
+    #[error("there is text after the email address")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::identity::error::unexpected_text),
+        help("remove anything after the `>`")
+    )]
+    UnexpectedText(
+        #[source_code] String,
+        #[label("this is after the email")] (usize, usize),
+    ),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors_point_at_the_problem() {
+        let cases = [
+            ("Billie Thompson", (0, 15)),
+            ("Billie Thompson <billie@example.com", (16, 1)),
+            (" <billie@example.com>", (1, 1)),
+            ("Billie Thompson <billie>", (17, 6)),
+            ("Billie Thompson <billie@example.com> extra", (37, 5)),
+        ];
+
+        for (text, expected) in cases {
+            let label = match Identity::try_from(text).expect_err("this isn't an identity") {
+                Error::MissingEmail(_, label)
+                | Error::UnclosedEmail(_, label)
+                | Error::MissingName(_, label)
+                | Error::InvalidEmail(_, label)
+                | Error::UnexpectedText(_, label) => label,
+            };
+
+            assert_eq!(
+                label, expected,
+                "The label should be on the problem in {text:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_email() {
+        assert!(is_email("billie@example.com"), "A normal email is valid");
+        assert!(is_email("billie@localhost"), "A local domain is valid");
+        assert!(!is_email(""), "An empty email is not valid");
+        assert!(!is_email("@example.com"), "An email needs a mailbox");
+        assert!(!is_email("billie@"), "An email needs a domain");
+        assert!(!is_email("billie @example.com"), "An email has no spaces");
+        assert!(
+            !is_email("billie@example.com."),
+            "A domain can't end in a dot"
+        );
+    }
+
+    #[test]
+    fn test_folded_trailer_names_are_joined() {
+        let trailer = Trailer::try_from(crate::Body::from(
+            "Co-authored-by: Billie\n  Thompson <billie@example.com>",
+        ))
+        .expect("this is a trailer");
+
+        assert_eq!(
+            Identity::try_from(&trailer)
+                .ok()
+                .map(|identity| identity.get_name().to_string()),
+            Some("Billie Thompson".to_string()),
+            "The folded name should be on one line"
+        );
+    }
+}
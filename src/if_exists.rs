@@ -0,0 +1,19 @@
+/// What to do when adding a [`crate::Trailer`] whose key is already present
+///
+/// These mirror the actions of git's `trailer.ifExists` config option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IfExists {
+    /// Add the trailer unless one with the same key and value is already
+    /// there
+    AddIfDifferent,
+    /// Add the trailer unless the trailer it would be put next to has the
+    /// same key and value
+    #[default]
+    AddIfDifferentNeighbor,
+    /// Add the trailer anyway, even if an identical one is already there
+    Add,
+    /// Replace an existing trailer with that key with the new trailer
+    Replace,
+    /// Leave the message as it is
+    DoNothing,
+}
@@ -0,0 +1,11 @@
+/// What to do when adding a [`crate::Trailer`] whose key is not present yet
+///
+/// These mirror the actions of git's `trailer.ifMissing` config option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IfMissing {
+    /// Add the trailer
+    #[default]
+    Add,
+    /// Leave the message as it is
+    DoNothing,
+}
@@ -60,24 +60,56 @@ extern crate quickcheck_macros;
 
 pub use bodies::Bodies;
 pub use body::Body;
+pub use cleanup_mode::CleanupMode;
 pub use comment::Comment;
 pub use comments::Comments;
+pub use commit_header::CommitHeader;
 pub use commit_message::{CommitMessage, Error as CommitMessageError};
+pub use conventional_commit::{ConventionalCommit, Error as ConventionalCommitError};
+pub use fix::Fix;
 pub use fragment::Fragment;
+pub use identity::{Error as IdentityError, Identity};
+pub use if_exists::IfExists;
+pub use if_missing::IfMissing;
+pub use parse_options::{CommentMarker, ParseOptions, TrailerDetection};
+pub use raw_commit::{Error as RawCommitError, RawCommit};
 pub use scissors::Scissors;
+pub use signature::{Error as SignatureError, Signature};
+pub use span::Span;
 pub use subject::Subject;
 pub use trailer::{Error as TrailerError, Trailer};
+pub use trailer_aliases::TrailerAliases;
+pub use trailer_policy::TrailerPolicy;
+pub use trailer_where::Where;
 pub use trailers::Trailers;
 
 mod bodies;
 mod body;
+mod cleanup_mode;
 mod comment;
 mod comments;
+mod commit_header;
 mod commit_message;
+mod conventional_commit;
+pub mod diff;
+mod fix;
 mod fragment;
+pub mod git_status;
+mod identity;
+mod if_exists;
+mod if_missing;
+pub mod lint;
+mod parse_options;
+pub mod patch;
+mod raw_commit;
 mod scissors;
+mod signature;
+mod span;
 mod subject;
 mod trailer;
+mod trailer_aliases;
+mod trailer_policy;
+mod trailer_where;
 mod trailers;
 
 #[cfg(doctest)]
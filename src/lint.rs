@@ -0,0 +1,121 @@
+//! Lints for commit messages
+//!
+//! A [`Lint`] checks a [`CommitMessage`] and reports each [`Problem`] it
+//! finds. Lints are collected in a [`Registry`], where they can be enabled
+//! and disabled by their code, and the registry runs them all over a
+//! message in one go.
+//!
+//! Some lints for the subject come with the crate, and can be added with
+//! [`Registry::with_subject_lints`]. There is also [`BodyLineLength`], which
+//! can rewrap the body to fix the problems it finds.
+//!
+//! With the `config` feature, a registry can be loaded from a
+//! `.mit-commit.toml` file with [`Registry::from_repository`], which also
+//! adds [`RequiredTrailers`] and [`ConventionalCommitHeader`] when they are
+//! configured.
+//!
+//! # Examples
+//!
+//! ```
+//! use mit_commit::{
+//!     CommitMessage,
+//!     lint::{Lint, Problem, Registry},
+//! };
+//!
+//! #[derive(Debug)]
+//! struct NoWip;
+//!
+//! impl Lint for NoWip {
+//!     fn get_code(&self) -> &str {
+//!         "no-wip"
+//!     }
+//!
+//!     fn check(&self, commit: &CommitMessage<'_>) -> Vec<Problem> {
+//!         let subject = commit.get_subject();
+//!
+//!         if subject.to_string().starts_with("WIP") {
+//!             vec![
+//!                 Problem::new(self.get_code(), "work in progress commit")
+//!                     .with_help("finish the work before committing")
+//!                     .with_label("remove this", subject.get_span().unwrap_or_default()),
+//!             ]
+//!         } else {
+//!             vec![]
+//!         }
+//!     }
+//! }
+//!
+//! let registry = Registry::default().with_lint(NoWip);
+//! let problems = registry.run(&CommitMessage::from("WIP: half done\n"));
+//!
+//! assert_eq!(problems.len(), 1);
+//! assert_eq!(problems[0].get_code(), "no-wip");
+//! assert!(
+//!     registry
+//!         .with_disabled("no-wip")
+//!         .run(&CommitMessage::from("WIP: half done\n"))
+//!         .is_empty()
+//! );
+//! ```
+
+use std::fmt::Debug;
+
+pub use body_line_length::BodyLineLength;
+#[cfg(feature = "config")]
+pub use config::{CONFIG_FILE_NAME, ConfigError};
+pub use conventional_commit_header::ConventionalCommitHeader;
+pub use problem::Problem;
+pub use registry::Registry;
+pub use required_trailers::RequiredTrailers;
+pub use subject_blank_line::SubjectBlankLine;
+pub use subject_capitalised::SubjectCapitalised;
+pub use subject_length::SubjectLength;
+pub use subject_no_gutter::SubjectNoGutter;
+pub use subject_trailing_period::SubjectTrailingPeriod;
+
+use crate::CommitMessage;
+
+mod body_line_length;
+#[cfg(feature = "config")]
+mod config;
+mod conventional_commit_header;
+mod problem;
+mod registry;
+mod required_trailers;
+mod subject_blank_line;
+mod subject_capitalised;
+mod subject_length;
+mod subject_no_gutter;
+mod subject_trailing_period;
+
+/// A check that can be run over a [`CommitMessage`]
+pub trait Lint: Debug {
+    /// Get the code that identifies this lint
+    ///
+    /// This is used to enable and disable the lint in a [`Registry`], and is
+    /// usually the same as the code on the [`Problem`]s it reports.
+    fn get_code(&self) -> &str;
+
+    /// Check a commit message
+    ///
+    /// The spans of the problems point into the text of the message, as
+    /// `String::from(commit)` gives it.
+    ///
+    /// # Arguments
+    ///
+    /// * `commit` - The commit message to check
+    ///
+    /// # Returns
+    ///
+    /// Every problem found, or an empty vector if there are none
+    fn check(&self, commit: &CommitMessage<'_>) -> Vec<Problem>;
+}
+
+/// Parse the text of a message again, so its spans point into that text
+///
+/// The spans of a message point into whatever it was parsed from, which has
+/// `\r\n` line endings its text doesn't, and is out of date once the
+/// message has been edited.
+fn reparse(commit: &CommitMessage<'_>) -> CommitMessage<'static> {
+    CommitMessage::parse_with(&String::from(commit), commit.get_options().clone()).into_owned()
+}
@@ -0,0 +1,505 @@
+use crate::{
+    CommitMessage, Fix, Fragment, Span, Trailer,
+    lint::{Lint, Problem, reparse},
+};
+
+/// Lines in the body should be short enough to read in a terminal
+///
+/// Some lines can't be wrapped without breaking them, so these are left
+/// alone:
+///
+/// * A line that is a single URL
+/// * Lines in a fenced code block, or indented as code
+/// * Quoted lines, starting with `>`
+/// * Trailers
+///
+/// Length is counted in characters.
+///
+/// # Examples
+///
+/// ```
+/// use indoc::indoc;
+/// use mit_commit::{
+///     CommitMessage,
+///     lint::{BodyLineLength, Lint},
+/// };
+///
+/// let commit = CommitMessage::from(indoc!(
+///     "
+///     Add the new parser
+///
+///     This line is far too long to read comfortably in a terminal, so it should be wrapped.
+///
+///     https://example.com/a/link/that/is/far/too/long/to/fit/on/one/line/but/is/fine
+///     "
+/// ));
+/// let problems = BodyLineLength::default().check(&commit);
+///
+/// assert_eq!(problems.len(), 1);
+/// assert_eq!(problems[0].get_labels()[0].1.get_line(), 2);
+/// assert_eq!(problems[0].get_labels()[0].1.get_column(), 72);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BodyLineLength {
+    limit: usize,
+}
+
+impl BodyLineLength {
+    /// The code for this lint
+    pub const CODE: &'static str = "body-line-length";
+
+    /// Give you a new [`BodyLineLength`] with a different limit
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - How many characters a line in the body can have
+    ///
+    /// # Returns
+    ///
+    /// The lint with the limit replaced
+    #[must_use]
+    pub const fn with_limit(self, limit: usize) -> Self {
+        Self { limit }
+    }
+
+    /// Get how many characters a line in the body can have
+    #[must_use]
+    pub const fn get_limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Rewrap the prose in the body so no line is over the limit
+    ///
+    /// Only prose with a line over the limit is rewrapped, and the lines
+    /// this lint leaves alone are not changed. List items are wrapped with
+    /// their continuation lines indented under the item.
+    ///
+    /// The same rewrapping is attached as a [`Fix`] to each problem this
+    /// lint finds, so it can be applied along with the fixes of other lints.
+    ///
+    /// # Arguments
+    ///
+    /// * `commit` - The commit message to rewrap
+    ///
+    /// # Returns
+    ///
+    /// The commit message with the body rewrapped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use indoc::indoc;
+    /// use mit_commit::{CommitMessage, lint::BodyLineLength};
+    ///
+    /// let commit = CommitMessage::from(indoc!(
+    ///     "
+    ///     Add the new parser
+    ///
+    ///     This line is too long to read comfortably, so it should be wrapped.
+    ///
+    ///     > Quotes like this one are left exactly as they are, however long.
+    ///
+    ///     - Items in lists are wrapped under the item
+    ///     "
+    /// ));
+    ///
+    /// assert_eq!(
+    ///     String::from(BodyLineLength::default().with_limit(40).rewrap(&commit)),
+    ///     indoc!(
+    ///         "
+    ///         Add the new parser
+    ///
+    ///         This line is too long to read
+    ///         comfortably, so it should be wrapped.
+    ///
+    ///         > Quotes like this one are left exactly as they are, however long.
+    ///
+    ///         - Items in lists are wrapped under the
+    ///           item
+    ///         "
+    ///     )
+    /// );
+    /// ```
+    #[must_use]
+    pub fn rewrap(&self, commit: &CommitMessage<'_>) -> CommitMessage<'static> {
+        // Reparse so the spans point at the text being rewrapped
+        let current = reparse(commit);
+        let mut rewrapped = String::from(&current);
+
+        for fix in self.rewrapped_prose(&current).iter().rev() {
+            let span = fix.get_span();
+            rewrapped.replace_range(span.get_offset()..span.get_end(), fix.get_replacement());
+        }
+
+        CommitMessage::parse_commit_message(&rewrapped, commit.get_options().clone()).into_owned()
+    }
+
+    /// Fixes that rewrap the prose that needs it, in the order they appear
+    fn rewrapped_prose(self, commit: &CommitMessage<'_>) -> Vec<Fix> {
+        let mut blocks: Vec<Vec<BodyLine>> = Vec::new();
+        let mut previous: Option<&BodyLine> = None;
+        let lines = body_lines(commit);
+
+        for line in &lines {
+            let continues_block = previous
+                .is_some_and(|previous| !previous.exempt && previous.paragraph == line.paragraph)
+                && !line.exempt
+                && list_marker(&line.text).is_none();
+
+            match blocks.last_mut() {
+                Some(block) if continues_block => block.push(line.clone()),
+                _ if !line.exempt => blocks.push(vec![line.clone()]),
+                _ => {}
+            }
+            previous = Some(line);
+        }
+
+        blocks
+            .into_iter()
+            .filter(|block| block.iter().any(|line| self.is_too_long(line)))
+            .filter_map(|block| {
+                let span = block.iter().map(|line| line.span).reduce(Span::extend)?;
+
+                Some(Fix::new(span, self.wrap(&block)))
+            })
+            .collect()
+    }
+
+    fn is_too_long(self, line: &BodyLine) -> bool {
+        !line.exempt && line.text.chars().count() > self.limit
+    }
+
+    /// Fill lines with words up to the limit
+    ///
+    /// A word longer than the limit gets a line to itself.
+    fn wrap(self, block: &[BodyLine]) -> String {
+        let first = block
+            .first()
+            .map(|line| line.text.as_str())
+            .unwrap_or_default();
+        let prefix = list_marker(first).map_or_else(
+            || &first[..first.len() - first.trim_start().len()],
+            |marker| &first[..marker],
+        );
+        let indent = " ".repeat(prefix.chars().count());
+        let words = block
+            .iter()
+            .flat_map(|line| line.text.split_whitespace())
+            .skip(usize::from(list_marker(first).is_some()));
+
+        let mut wrapped = String::from(prefix);
+        let mut line_length = prefix.chars().count();
+        let mut line_has_words = false;
+
+        for word in words {
+            let word_length = word.chars().count();
+
+            if line_has_words && line_length + 1 + word_length > self.limit {
+                wrapped.push('\n');
+                wrapped.push_str(&indent);
+                line_length = indent.len();
+                line_has_words = false;
+            }
+            if line_has_words {
+                wrapped.push(' ');
+                line_length += 1;
+            }
+
+            wrapped.push_str(word);
+            line_length += word_length;
+            line_has_words = true;
+        }
+
+        wrapped
+    }
+}
+
+impl Default for BodyLineLength {
+    /// A limit of 72 characters, as git's documentation suggests
+    fn default() -> Self {
+        Self { limit: 72 }
+    }
+}
+
+impl Lint for BodyLineLength {
+    fn get_code(&self) -> &str {
+        Self::CODE
+    }
+
+    fn check(&self, commit: &CommitMessage<'_>) -> Vec<Problem> {
+        let commit = reparse(commit);
+        let fixes = self.rewrapped_prose(&commit);
+
+        body_lines(&commit)
+            .into_iter()
+            .filter(|line| self.is_too_long(line))
+            .map(|line| {
+                let start = line
+                    .text
+                    .char_indices()
+                    .nth(self.limit)
+                    .map_or(line.text.len(), |(index, _)| index);
+                let span = line.span.within(&line.text, start..line.text.len());
+                let fix = fixes.iter().find(|fix| {
+                    fix.get_span().get_offset() <= line.span.get_offset()
+                        && line.span.get_end() <= fix.get_span().get_end()
+                });
+
+                let problem = Problem::new(
+                    Self::CODE,
+                    &format!(
+                        "this line of the body is {} characters long, over the limit of {}",
+                        line.text.chars().count(),
+                        self.limit
+                    ),
+                )
+                .with_help(&format!("wrap the body at {} characters", self.limit))
+                .with_label("these characters are over the limit", span);
+
+                match fix {
+                    Some(fix) => problem.with_fix(fix.clone()),
+                    None => problem,
+                }
+            })
+            .collect()
+    }
+}
+
+/// A line in the body of a commit message
+#[derive(Debug, Clone)]
+struct BodyLine {
+    text: String,
+    span: Span,
+    paragraph: usize,
+    exempt: bool,
+}
+
+/// Every line in the body, and whether it is left alone when wrapping
+fn body_lines(commit: &CommitMessage<'_>) -> Vec<BodyLine> {
+    let trailer_spans: Vec<Span> = commit
+        .get_trailers()
+        .iter()
+        .filter_map(Trailer::get_span)
+        .collect();
+    let mut fence: Option<&str> = None;
+    let mut lines = Vec::new();
+
+    let ast = commit.get_ast();
+    let paragraphs = ast
+        .iter()
+        .filter_map(|fragment| match fragment {
+            Fragment::Body(body) => Some(body),
+            Fragment::Comment(_) => None,
+        })
+        .skip(1)
+        .enumerate();
+
+    for (paragraph, body) in paragraphs {
+        let text = body.as_str();
+        // Without a span there's nowhere to point a problem at
+        let Some(span) = body.get_span() else {
+            continue;
+        };
+        let mut start = 0;
+
+        for line in text.split('\n') {
+            let line_span = span.within(text, start..start + line.len());
+            start += line.len() + 1;
+
+            let trimmed = line.trim_start();
+            let fence_marker = ["```", "~~~"]
+                .into_iter()
+                .find(|marker| trimmed.starts_with(marker));
+            let exempt = match (fence, fence_marker) {
+                (Some(open), close) => {
+                    if close == Some(open) {
+                        fence = None;
+                    }
+                    true
+                }
+                (None, Some(open)) => {
+                    fence = Some(open);
+                    true
+                }
+                (None, None) => {
+                    is_indented_code(line)
+                        || trimmed.starts_with('>')
+                        || is_url(trimmed)
+                        || trailer_spans.iter().any(|trailer| {
+                            trailer.get_offset() <= line_span.get_offset()
+                                && line_span.get_end() <= trailer.get_end()
+                        })
+                }
+            };
+
+            lines.push(BodyLine {
+                text: line.into(),
+                span: line_span,
+                paragraph,
+                exempt,
+            });
+        }
+    }
+
+    lines
+}
+
+fn is_indented_code(line: &str) -> bool {
+    line.starts_with("    ") || line.starts_with('\t')
+}
+
+fn is_url(trimmed: &str) -> bool {
+    let url = trimmed
+        .strip_prefix('<')
+        .and_then(|url| url.strip_suffix('>'))
+        .unwrap_or(trimmed);
+
+    !url.contains(char::is_whitespace) && url.contains("://")
+}
+
+/// The length of the list marker and the space after it, if the line is a
+/// list item
+fn list_marker(line: &str) -> Option<usize> {
+    let trimmed = line.trim_start();
+    let indent = line.len() - trimmed.len();
+    let numbered = trimmed.trim_start_matches(|character: char| character.is_ascii_digit());
+    let marker = if ["- ", "* ", "+ "]
+        .iter()
+        .any(|bullet| trimmed.starts_with(bullet))
+    {
+        2
+    } else if numbered.len() < trimmed.len()
+        && (numbered.starts_with(". ") || numbered.starts_with(") "))
+    {
+        trimmed.len() - numbered.len() + 2
+    } else {
+        return None;
+    };
+
+    Some(indent + marker)
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_exempt_lines_are_not_problems() {
+        let long = "x".repeat(80);
+        let commit = CommitMessage::from(format!(
+            "Subject\n\nhttps://example.com/{long}\n<https://example.com/{long}>\n\n```\n{long}\n\n{long}\n```\n\n    {long}\n\n> {long}\n\nRelates-to: {long}\n"
+        ));
+
+        assert_eq!(
+            BodyLineLength::default().check(&commit),
+            vec![],
+            "None of these lines can be wrapped"
+        );
+    }
+
+    #[test]
+    fn test_subject_and_comments_are_not_body() {
+        let long = "x ".repeat(40);
+        let commit = CommitMessage::from(format!("{long}\n\n# {long}\n"));
+
+        assert!(
+            BodyLineLength::default().check(&commit).is_empty(),
+            "Only the body should be checked"
+        );
+    }
+
+    #[test]
+    fn test_trailer_looking_line_in_body_is_checked() {
+        let commit = CommitMessage::from(format!(
+            "Subject\n\nNot-a-trailer: {}\nBecause this line is prose\n",
+            "x ".repeat(40)
+        ));
+
+        assert_eq!(
+            BodyLineLength::default().check(&commit).len(),
+            1,
+            "Only lines in the trailer block are trailers"
+        );
+    }
+
+    #[test]
+    fn test_list_markers() {
+        assert_eq!(list_marker("- item"), Some(2), "A bullet");
+        assert_eq!(list_marker("  * item"), Some(4), "An indented bullet");
+        assert_eq!(list_marker("12. item"), Some(4), "A numbered item");
+        assert_eq!(list_marker("3) item"), Some(3), "A bracketed number");
+        assert_eq!(list_marker("-item"), None, "A bullet needs a space");
+        assert_eq!(list_marker("2024 was a year"), None, "Just a number");
+    }
+
+    #[test]
+    fn test_rewrap_keeps_exempt_lines_in_a_paragraph() {
+        let commit = CommitMessage::from(indoc!(
+            "
+            Subject
+
+            See this link, which explains everything you might want to know
+            https://example.com/a/link/that/is/far/too/long/to/fit/on/one/line
+            and then some more text after the link.
+            "
+        ));
+
+        assert_eq!(
+            String::from(BodyLineLength::default().with_limit(30).rewrap(&commit)),
+            indoc!(
+                "
+                Subject
+
+                See this link, which explains
+                everything you might want to
+                know
+                https://example.com/a/link/that/is/far/too/long/to/fit/on/one/line
+                and then some more text after
+                the link.
+                "
+            ),
+            "The link should stay on its own line"
+        );
+    }
+
+    #[test]
+    fn test_rewrap_leaves_short_prose_alone() {
+        let commit = CommitMessage::from("Subject\n\nShort\nlines\n\nAnother short one\n");
+
+        assert_eq!(
+            String::from(BodyLineLength::default().rewrap(&commit)),
+            "Subject\n\nShort\nlines\n\nAnother short one\n",
+            "Prose that fits shouldn't be reflowed"
+        );
+    }
+
+    #[test]
+    fn test_rewrap_keeps_long_words_whole() {
+        let word = "y".repeat(20);
+        let commit = CommitMessage::from(format!("Subject\n\nshort {word} end\n"));
+
+        assert_eq!(
+            String::from(BodyLineLength::default().with_limit(10).rewrap(&commit)),
+            format!("Subject\n\nshort\n{word}\nend\n"),
+            "Words longer than the limit should get their own line"
+        );
+    }
+
+    #[test]
+    fn test_labels_point_into_a_message_with_crlf_line_endings() {
+        let commit = CommitMessage::from(format!(
+            "Subject\r\n\r\nShort line\r\n{}\r\n",
+            "x ".repeat(40)
+        ));
+        let problems = BodyLineLength::default().check(&commit);
+        let span = problems[0].get_labels()[0].1;
+
+        assert_eq!(
+            &String::from(&commit)[span.get_offset()..span.get_end()],
+            "x x x x ",
+            "The label should point at the characters over the limit"
+        );
+    }
+}
@@ -0,0 +1,494 @@
+use std::{
+    fs, io,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+use miette::{Diagnostic, Severity};
+use serde::Deserialize;
+use thiserror::Error;
+use toml::Spanned;
+
+use crate::lint::{
+    BodyLineLength, ConventionalCommitHeader, Registry, RequiredTrailers, SubjectBlankLine,
+    SubjectCapitalised, SubjectLength, SubjectNoGutter, SubjectTrailingPeriod,
+};
+
+/// The name of the file [`Registry::from_repository`] looks for
+pub const CONFIG_FILE_NAME: &str = ".mit-commit.toml";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    #[serde(default)]
+    lints: LintsConfig,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+struct LintsConfig {
+    subject_length: Option<SubjectLengthConfig>,
+    subject_trailing_period: Option<ToggleConfig>,
+    subject_capitalised: Option<ToggleConfig>,
+    subject_blank_line: Option<ToggleConfig>,
+    subject_no_gutter: Option<ToggleConfig>,
+    body_line_length: Option<BodyLineLengthConfig>,
+    required_trailers: Option<RequiredTrailersConfig>,
+    conventional_commit: Option<ConventionalCommitConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ToggleConfig {
+    enabled: Option<bool>,
+    severity: Option<SeverityConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+struct SubjectLengthConfig {
+    enabled: Option<bool>,
+    severity: Option<SeverityConfig>,
+    soft_limit: Option<Spanned<usize>>,
+    hard_limit: Option<Spanned<usize>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BodyLineLengthConfig {
+    enabled: Option<bool>,
+    severity: Option<SeverityConfig>,
+    limit: Option<Spanned<usize>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RequiredTrailersConfig {
+    enabled: Option<bool>,
+    severity: Option<SeverityConfig>,
+    #[serde(default)]
+    keys: Vec<Spanned<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConventionalCommitConfig {
+    enabled: Option<bool>,
+    severity: Option<SeverityConfig>,
+    #[serde(default)]
+    types: Vec<Spanned<String>>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum SeverityConfig {
+    Error,
+    Warning,
+    Advice,
+}
+
+impl From<SeverityConfig> for Severity {
+    fn from(severity: SeverityConfig) -> Self {
+        match severity {
+            SeverityConfig::Error => Self::Error,
+            SeverityConfig::Warning => Self::Warning,
+            SeverityConfig::Advice => Self::Advice,
+        }
+    }
+}
+
+impl SubjectLengthConfig {
+    fn lint(&self, config: &str) -> Result<SubjectLength, ConfigError> {
+        let mut lint = SubjectLength::default();
+
+        if let Some(soft_limit) = &self.soft_limit {
+            lint = lint.with_soft_limit(positive(config, "soft-limit", soft_limit)?);
+        }
+        if let Some(hard_limit) = &self.hard_limit {
+            lint = lint.with_hard_limit(positive(config, "hard-limit", hard_limit)?);
+        }
+
+        if lint.get_soft_limit() <= lint.get_hard_limit() {
+            return Ok(lint);
+        }
+
+        let setting = self
+            .soft_limit
+            .as_ref()
+            .or(self.hard_limit.as_ref())
+            .map_or(0..0, Spanned::span);
+
+        Err(ConfigError::InvalidValue(
+            format!(
+                "the soft limit of {} is more than the hard limit of {}",
+                lint.get_soft_limit(),
+                lint.get_hard_limit()
+            ),
+            config.into(),
+            key_before(config, &setting),
+            "make the soft limit less than or equal to the hard limit".into(),
+        ))
+    }
+}
+
+impl BodyLineLengthConfig {
+    fn lint(&self, config: &str) -> Result<BodyLineLength, ConfigError> {
+        match &self.limit {
+            Some(limit) => {
+                Ok(BodyLineLength::default().with_limit(positive(config, "limit", limit)?))
+            }
+            None => Ok(BodyLineLength::default()),
+        }
+    }
+}
+
+impl RequiredTrailersConfig {
+    fn lint(&self, config: &str) -> Result<RequiredTrailers, ConfigError> {
+        self.keys
+            .iter()
+            .try_fold(RequiredTrailers::default(), |lint, key| {
+                token(config, key, "the key of a trailer", "Signed-off-by")
+                    .map(|key| lint.with_key(key))
+            })
+    }
+}
+
+impl ConventionalCommitConfig {
+    fn lint(&self, config: &str) -> Result<ConventionalCommitHeader, ConfigError> {
+        let types = self
+            .types
+            .iter()
+            .map(|commit_type| {
+                token(config, commit_type, "a Conventional Commit type", "feat").map(String::from)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(ConventionalCommitHeader::default().with_types(types))
+    }
+}
+
+impl Registry {
+    /// Give you a new [`Registry`] configured from the text of a
+    /// `.mit-commit.toml` file
+    ///
+    /// The subject lints and [`BodyLineLength`] are always added, and
+    /// [`RequiredTrailers`] and [`ConventionalCommitHeader`] are added when
+    /// they have a table in the file. Each table under `lints` is named
+    /// after a lint's code, and can have `enabled`, a `severity` of
+    /// `"error"`, `"warning"` or `"advice"`, and the lint's own settings.
+    ///
+    /// ```toml
+    /// [lints.subject-length]
+    /// soft-limit = 50
+    /// hard-limit = 72
+    ///
+    /// [lints.subject-capitalised]
+    /// enabled = false
+    ///
+    /// [lints.body-line-length]
+    /// severity = "warning"
+    /// limit = 72
+    ///
+    /// [lints.required-trailers]
+    /// keys = ["Signed-off-by"]
+    ///
+    /// [lints.conventional-commit]
+    /// types = ["feat", "fix", "docs"]
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The text of the config file
+    ///
+    /// # Returns
+    ///
+    /// The registry described by the config
+    ///
+    /// # Errors
+    ///
+    /// If the config isn't valid TOML, has a table or setting that doesn't
+    /// exist, or has a setting with a value that can't be used. The error
+    /// has the config as its source code, with a label on the problem.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miette::Severity;
+    /// use mit_commit::{CommitMessage, lint::Registry};
+    ///
+    /// let registry = Registry::from_toml(
+    ///     "[lints.subject-capitalised]\nseverity = \"advice\"\n\n[lints.required-trailers]\nkeys = [\"Signed-off-by\"]\n",
+    /// )
+    /// .unwrap();
+    /// let problems = registry.run(&CommitMessage::from("lowercase subject\n"));
+    ///
+    /// assert_eq!(
+    ///     problems.iter().map(|problem| problem.get_code()).collect::<Vec<_>>(),
+    ///     vec!["subject-capitalised", "required-trailers"]
+    /// );
+    /// assert_eq!(problems[0].get_severity(), Severity::Advice);
+    ///
+    /// let error = Registry::from_toml("[lints.subject-length]\nsoft-limit = 0\n").unwrap_err();
+    ///
+    /// assert_eq!(error.to_string(), "soft-limit must be more than 0");
+    /// ```
+    pub fn from_toml(config: &str) -> Result<Self, ConfigError> {
+        let file: ConfigFile = toml::from_str(config).map_err(|error| {
+            ConfigError::Invalid(
+                error.message().trim_end().into(),
+                config.into(),
+                error.span().map_or((0, 0), |span| (span.start, span.len())),
+            )
+        })?;
+        let lints = file.lints;
+        let mut registry = Self::default().with_subject_lints();
+
+        if let Some(table) = &lints.subject_length {
+            registry = registry.with_lint(table.lint(config)?).with_toggles(
+                SubjectLength::CODE,
+                table.enabled,
+                table.severity,
+            );
+        }
+
+        for (code, table) in [
+            (SubjectTrailingPeriod::CODE, &lints.subject_trailing_period),
+            (SubjectCapitalised::CODE, &lints.subject_capitalised),
+            (SubjectBlankLine::CODE, &lints.subject_blank_line),
+            (SubjectNoGutter::CODE, &lints.subject_no_gutter),
+        ] {
+            if let Some(table) = table {
+                registry = registry.with_toggles(code, table.enabled, table.severity);
+            }
+        }
+
+        registry = match &lints.body_line_length {
+            Some(table) => registry.with_lint(table.lint(config)?).with_toggles(
+                BodyLineLength::CODE,
+                table.enabled,
+                table.severity,
+            ),
+            None => registry.with_lint(BodyLineLength::default()),
+        };
+
+        if let Some(table) = &lints.required_trailers {
+            registry = registry.with_lint(table.lint(config)?).with_toggles(
+                RequiredTrailers::CODE,
+                table.enabled,
+                table.severity,
+            );
+        }
+
+        if let Some(table) = &lints.conventional_commit {
+            registry = registry.with_lint(table.lint(config)?).with_toggles(
+                ConventionalCommitHeader::CODE,
+                table.enabled,
+                table.severity,
+            );
+        }
+
+        Ok(registry)
+    }
+
+    /// Give you a new [`Registry`] configured from a `.mit-commit.toml` file
+    ///
+    /// See [`Registry::from_toml`] for what can go in the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the config file
+    ///
+    /// # Returns
+    ///
+    /// The registry described by the config
+    ///
+    /// # Errors
+    ///
+    /// If the file can't be read, or [`Registry::from_toml`] fails
+    pub fn from_toml_file(path: &Path) -> Result<Self, ConfigError> {
+        let config = fs::read_to_string(path).map_err(|source| ConfigError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        Self::from_toml(&config)
+    }
+
+    /// Give you a new [`Registry`] configured from the `.mit-commit.toml`
+    /// file in a repository
+    ///
+    /// If the repository has no config file, you get the lints that would be
+    /// added by an empty one.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The root of the repository
+    ///
+    /// # Returns
+    ///
+    /// The registry described by the config
+    ///
+    /// # Errors
+    ///
+    /// If the file exists but can't be read, or [`Registry::from_toml`]
+    /// fails
+    pub fn from_repository(directory: &Path) -> Result<Self, ConfigError> {
+        let path = directory.join(CONFIG_FILE_NAME);
+
+        match fs::read_to_string(&path) {
+            Ok(config) => Self::from_toml(&config),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Self::from_toml(""),
+            Err(source) => Err(ConfigError::Io { path, source }),
+        }
+    }
+
+    fn with_toggles(
+        self,
+        code: &str,
+        enabled: Option<bool>,
+        severity: Option<SeverityConfig>,
+    ) -> Self {
+        let registry = match enabled {
+            Some(true) => self.with_enabled(code),
+            Some(false) => self.with_disabled(code),
+            None => self,
+        };
+
+        match severity {
+            Some(severity) => registry.with_severity(code, severity.into()),
+            None => registry,
+        }
+    }
+}
+
+fn positive(config: &str, key: &str, value: &Spanned<usize>) -> Result<usize, ConfigError> {
+    if *value.get_ref() > 0 {
+        return Ok(*value.get_ref());
+    }
+
+    Err(ConfigError::InvalidValue(
+        format!("{key} must be more than 0"),
+        config.into(),
+        key_before(config, &value.span()),
+        "remove the setting to use the default".into(),
+    ))
+}
+
+fn token<'a>(
+    config: &str,
+    value: &'a Spanned<String>,
+    what: &str,
+    example: &str,
+) -> Result<&'a str, ConfigError> {
+    if is_token(value.get_ref()) {
+        return Ok(value.get_ref());
+    }
+
+    Err(ConfigError::InvalidValue(
+        format!("`{}` can't be {what}", value.get_ref()),
+        config.into(),
+        (value.span().start, value.span().len()),
+        format!("use letters, digits and hyphens, like `{example}`"),
+    ))
+}
+
+fn is_token(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .chars()
+            .all(|character| character.is_alphanumeric() || character == '-')
+}
+
+/// The span of the key that a value in the config is set with
+fn key_before(config: &str, value: &Range<usize>) -> (usize, usize) {
+    let before = config[..value.start].trim_end();
+    let before = before.strip_suffix('=').unwrap_or(before).trim_end();
+    let start = before
+        .char_indices()
+        .rev()
+        .find(|(_, character)| {
+            !(character.is_alphanumeric() || matches!(character, '-' | '_' | '"' | '\''))
+        })
+        .map_or(0, |(index, character)| index + character.len_utf8());
+
+    (start, before.len() - start)
+}
+
+/// Errors on loading lint configuration
+#[derive(Error, Debug, Diagnostic)]
+pub enum ConfigError {
+    /// Failed to read the config file
+    #[error("failed to read config file {}: {source}", path.display())]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::lint::config::error::io),
+        help("check the file is readable")
+    )]
+    Io {
+        /// The config file
+        path: PathBuf,
+        /// Why it couldn't be read
+        source: io::Error,
+    },
+    /// The config isn't TOML, or has a table or setting that doesn't exist
+    #[error("{0}")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::lint::config::error::invalid),
+        help("tables go under `lints` and are named after the lint's code")
+    )]
+    Invalid(
+        String,
+        #[source_code] String,
+        #[label("here")] (usize, usize),
+    ),
+    /// A setting has a value that can't be used
+    #[error("{0}")]
+    #[diagnostic(url(docsrs), code(mit_commit::lint::config::error::invalid_value))]
+    InvalidValue(
+        String,
+        #[source_code] String,
+        #[label("this setting")] (usize, usize),
+        #[help] String,
+    ),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_before_finds_the_key() {
+        let config = "[lints.subject-length]\nsoft-limit  =  0\n";
+        let value = config.find('0').expect("has a value");
+
+        assert_eq!(
+            key_before(config, &(value..value + 1)),
+            (23, 10),
+            "The span should cover the key and nothing else"
+        );
+    }
+
+    #[test]
+    fn test_key_before_in_an_inline_table() {
+        let config = "[lints]\nbody-line-length = { \"limit\" = 0 }\n";
+        let value = config.find('0').expect("has a value");
+
+        assert_eq!(
+            &config[{
+                let (start, length) = key_before(config, &(value..value + 1));
+                start..start + length
+            }],
+            "\"limit\"",
+            "Quoted keys should be included with their quotes"
+        );
+    }
+
+    #[test]
+    fn test_tokens() {
+        assert!(is_token("Signed-off-by"), "Hyphenated keys are tokens");
+        assert!(!is_token(""), "Empty keys are not tokens");
+        assert!(!is_token("Signed off by"), "Spaces are not allowed");
+        assert!(!is_token("Relates-to:"), "Separators are not allowed");
+    }
+}
@@ -0,0 +1,174 @@
+use std::convert::TryFrom;
+
+use miette::Diagnostic;
+
+use crate::{
+    CommitMessage, ConventionalCommit,
+    lint::{Lint, Problem, reparse},
+    span,
+};
+
+/// The subject should be a [Conventional Commit](https://www.conventionalcommits.org/en/v1.0.0/)
+/// header, optionally with a type from a fixed list
+///
+/// # Examples
+///
+/// ```
+/// use mit_commit::{
+///     CommitMessage,
+///     lint::{ConventionalCommitHeader, Lint},
+/// };
+///
+/// let lint = ConventionalCommitHeader::default();
+///
+/// assert!(lint.check(&CommitMessage::from("feat: add a thing\n")).is_empty());
+/// assert_eq!(lint.check(&CommitMessage::from("Add a thing\n")).len(), 1);
+///
+/// let lint = lint.with_types(vec!["fix".into(), "feat".into()]);
+///
+/// assert_eq!(lint.get_types(), ["fix", "feat"]);
+/// assert_eq!(lint.check(&CommitMessage::from("chore: tidy\n")).len(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ConventionalCommitHeader {
+    types: Vec<String>,
+}
+
+impl ConventionalCommitHeader {
+    /// The code for this lint
+    pub const CODE: &'static str = "conventional-commit";
+
+    /// Give you a new [`ConventionalCommitHeader`] that only allows these
+    /// types
+    ///
+    /// # Arguments
+    ///
+    /// * `types` - The allowed types, or nothing to allow any type
+    ///
+    /// # Returns
+    ///
+    /// The lint with the allowed types replaced
+    #[must_use]
+    pub fn with_types(self, types: Vec<String>) -> Self {
+        Self { types }
+    }
+
+    /// Get the allowed types, which is empty if any type is allowed
+    #[must_use]
+    pub fn get_types(&self) -> &[String] {
+        &self.types
+    }
+}
+
+impl Lint for ConventionalCommitHeader {
+    fn get_code(&self) -> &str {
+        Self::CODE
+    }
+
+    fn check(&self, commit: &CommitMessage<'_>) -> Vec<Problem> {
+        let commit = reparse(commit);
+        let conventional = match ConventionalCommit::try_from(&commit) {
+            Ok(conventional) => conventional,
+            Err(error) => {
+                let text = String::from(&commit);
+                let problem = Problem::new(Self::CODE, &error.to_string());
+                let problem = match error.help() {
+                    Some(help) => problem.with_help(&help.to_string()),
+                    None => problem,
+                };
+
+                return vec![error.labels().into_iter().flatten().fold(
+                    problem,
+                    |problem, label| {
+                        problem.with_label(
+                            label.label().unwrap_or_default(),
+                            span::in_text(&text, label.offset(), label.len()),
+                        )
+                    },
+                )];
+            }
+        };
+
+        let commit_type = conventional.get_type();
+        if self.types.is_empty() || self.types.iter().any(|allowed| allowed == commit_type) {
+            return vec![];
+        }
+
+        let Some(subject_span) = commit.get_subject().get_span() else {
+            return vec![];
+        };
+        let subject = commit.get_subject().to_string();
+        let span = subject_span.within(&subject, 0..commit_type.len());
+
+        vec![
+            Problem::new(
+                Self::CODE,
+                &format!("`{commit_type}` is not an allowed type"),
+            )
+            .with_help(&format!("use one of {}", self.types.join(", ")))
+            .with_label("this type", span),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header_errors_keep_their_labels() {
+        let problems = ConventionalCommitHeader::default()
+            .check(&CommitMessage::from("# A comment\nfeat(x: y\n"));
+
+        assert_eq!(
+            problems[0].get_error(),
+            "the scope is not closed",
+            "The error should come from the header parser"
+        );
+        assert_eq!(
+            problems[0].get_labels(),
+            vec![(
+                "this scope has no `)`",
+                span::in_text("# A comment\nfeat(x: y\n", 16, 5)
+            )],
+            "The label should point at the scope"
+        );
+        assert!(
+            problems[0].get_help().is_some(),
+            "The help should come from the header parser"
+        );
+    }
+
+    #[test]
+    fn test_labels_a_type_that_is_not_allowed() {
+        let problems = ConventionalCommitHeader::default()
+            .with_types(vec!["feat".into()])
+            .check(&CommitMessage::from("chore(deps): bump\n"));
+
+        assert_eq!(
+            problems[0].get_labels()[0].1.get_length(),
+            5,
+            "The label should cover the type"
+        );
+        assert_eq!(
+            problems[0].get_help(),
+            Some("use one of feat"),
+            "The help should list the allowed types"
+        );
+    }
+
+    #[test]
+    fn test_labels_the_type_in_a_message_with_crlf_line_endings() {
+        let commit = CommitMessage::from("# A comment\r\nchore(deps): bump\r\n");
+        let problems = ConventionalCommitHeader::default()
+            .with_types(vec!["feat".into()])
+            .check(&commit);
+        let span = problems[0].get_labels()[0].1;
+
+        assert_eq!(
+            &String::from(&commit)[span.get_offset()..span.get_end()],
+            "chore",
+            "The label should point at the type in the text of the message"
+        );
+    }
+}
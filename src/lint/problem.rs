@@ -0,0 +1,357 @@
+use std::fmt::{Display, Formatter};
+
+use miette::{Diagnostic, LabeledSpan, Severity, SourceCode};
+
+use crate::{Fix, Span};
+
+/// Something wrong with a commit message, found by a [`crate::lint::Lint`]
+///
+/// This is a [`Diagnostic`], so it can be shown with [`miette`], pointing at
+/// the part of the message that is wrong.
+///
+/// # Examples
+///
+/// ```
+/// use miette::Diagnostic;
+/// use mit_commit::{Span, lint::Problem};
+///
+/// let problem = Problem::new("subject-not-empty", "the subject is empty")
+///     .with_help("add a subject describing the change")
+///     .with_label("expected a subject here", Span::new(0, 0, 0, 0))
+///     .with_source_code("\n# A comment\n".into());
+///
+/// assert_eq!(problem.get_code(), "subject-not-empty");
+/// assert_eq!(problem.to_string(), "the subject is empty");
+/// assert_eq!(
+///     problem.code().map(|code| code.to_string()),
+///     Some("subject-not-empty".into())
+/// );
+/// assert_eq!(problem.labels().map(Iterator::count), Some(1));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    code: String,
+    error: String,
+    help: Option<String>,
+    url: Option<String>,
+    severity: Option<Severity>,
+    labels: Vec<(String, Span)>,
+    fixes: Vec<Fix>,
+    source_code: Option<String>,
+}
+
+impl Problem {
+    /// Create a new [`Problem`]
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The code of the lint that found the problem
+    /// * `error` - A short description of what is wrong
+    ///
+    /// # Returns
+    ///
+    /// A problem with no help, labels or source code
+    #[must_use]
+    pub fn new(code: &str, error: &str) -> Self {
+        Self {
+            code: code.into(),
+            error: error.into(),
+            help: None,
+            url: None,
+            severity: None,
+            labels: Vec::new(),
+            fixes: Vec::new(),
+            source_code: None,
+        }
+    }
+
+    /// Give you a new [`Problem`] with help on how to fix it
+    ///
+    /// # Arguments
+    ///
+    /// * `help` - What to do to fix the problem
+    ///
+    /// # Returns
+    ///
+    /// The problem with the help replaced
+    #[must_use]
+    pub fn with_help(self, help: &str) -> Self {
+        Self {
+            help: Some(help.into()),
+            ..self
+        }
+    }
+
+    /// Give you a new [`Problem`] with a link to more information
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Where to find out more about the problem
+    ///
+    /// # Returns
+    ///
+    /// The problem with the url replaced
+    #[must_use]
+    pub fn with_url(self, url: &str) -> Self {
+        Self {
+            url: Some(url.into()),
+            ..self
+        }
+    }
+
+    /// Give you a new [`Problem`] with a severity
+    ///
+    /// Problems without a severity are shown as errors.
+    ///
+    /// # Arguments
+    ///
+    /// * `severity` - How serious the problem is
+    ///
+    /// # Returns
+    ///
+    /// The problem with the severity replaced
+    #[must_use]
+    pub fn with_severity(self, severity: Severity) -> Self {
+        Self {
+            severity: Some(severity),
+            ..self
+        }
+    }
+
+    /// Give you a new [`Problem`] with a label pointing at part of the
+    /// message
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - What is wrong with this part of the message
+    /// * `span` - Where the part of the message is
+    ///
+    /// # Returns
+    ///
+    /// The problem with the label added to any it already has
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{Span, lint::Problem};
+    ///
+    /// let problem = Problem::new("example", "an example")
+    ///     .with_label("first", Span::new(0, 1, 0, 0))
+    ///     .with_label("second", Span::new(2, 1, 0, 2));
+    ///
+    /// assert_eq!(
+    ///     problem.get_labels(),
+    ///     vec![
+    ///         ("first", Span::new(0, 1, 0, 0)),
+    ///         ("second", Span::new(2, 1, 0, 2))
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_label(mut self, label: &str, span: Span) -> Self {
+        self.labels.push((label.into(), span));
+        self
+    }
+
+    /// Give you a new [`Problem`] with a [`Fix`] that would solve it
+    ///
+    /// # Arguments
+    ///
+    /// * `fix` - An edit to the message that solves the problem
+    ///
+    /// # Returns
+    ///
+    /// The problem with the fix added to any it already has
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{CommitMessage, Fix, Span, lint::Problem};
+    ///
+    /// let commit = CommitMessage::from("Subject.\n");
+    /// let problem = Problem::new("subject-trailing-period", "the subject ends with a period")
+    ///     .with_fix(Fix::new(Span::new(7, 1, 0, 7), String::new()));
+    ///
+    /// assert_eq!(
+    ///     String::from(commit.apply_fixes(problem.get_fixes()).unwrap()),
+    ///     "Subject\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_fix(mut self, fix: Fix) -> Self {
+        self.fixes.push(fix);
+        self
+    }
+
+    /// Give you a new [`Problem`] with the text of the message its labels
+    /// point into
+    ///
+    /// You don't usually need this, as [`crate::lint::Registry::run`] adds
+    /// the message to problems that don't have it.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_code` - The text of the commit message
+    ///
+    /// # Returns
+    ///
+    /// The problem with the source code replaced
+    #[must_use]
+    pub fn with_source_code(self, source_code: String) -> Self {
+        Self {
+            source_code: Some(source_code),
+            ..self
+        }
+    }
+
+    /// Get the code of the lint that found the problem
+    #[must_use]
+    pub fn get_code(&self) -> &str {
+        &self.code
+    }
+
+    /// Get the description of what is wrong
+    #[must_use]
+    pub fn get_error(&self) -> &str {
+        &self.error
+    }
+
+    /// Get the help on how to fix the problem, if there is any
+    #[must_use]
+    pub fn get_help(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+
+    /// Get the link to more information, if there is one
+    #[must_use]
+    pub fn get_url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    /// Get how serious the problem is
+    #[must_use]
+    pub fn get_severity(&self) -> Severity {
+        self.severity.unwrap_or(Severity::Error)
+    }
+
+    /// Get the labels, and where in the message they point
+    #[must_use]
+    pub fn get_labels(&self) -> Vec<(&str, Span)> {
+        self.labels
+            .iter()
+            .map(|(label, span)| (label.as_str(), *span))
+            .collect()
+    }
+
+    /// Get the fixes that would solve the problem
+    ///
+    /// Apply them with [`crate::CommitMessage::apply_fixes`].
+    #[must_use]
+    pub fn get_fixes(&self) -> &[Fix] {
+        &self.fixes
+    }
+
+    /// Get the text of the message the labels point into, if it has been
+    /// added
+    #[must_use]
+    pub fn get_source_code(&self) -> Option<&str> {
+        self.source_code.as_deref()
+    }
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.error)
+    }
+}
+
+impl std::error::Error for Problem {}
+
+impl Diagnostic for Problem {
+    fn code<'b>(&'b self) -> Option<Box<dyn Display + 'b>> {
+        Some(Box::new(&self.code))
+    }
+
+    fn help<'b>(&'b self) -> Option<Box<dyn Display + 'b>> {
+        self.help
+            .as_ref()
+            .map(|help| Box::new(help) as Box<dyn Display>)
+    }
+
+    fn url<'b>(&'b self) -> Option<Box<dyn Display + 'b>> {
+        self.url
+            .as_ref()
+            .map(|url| Box::new(url) as Box<dyn Display>)
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.severity
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.source_code
+            .as_ref()
+            .map(|source_code| source_code as &dyn SourceCode)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        if self.labels.is_empty() {
+            return None;
+        }
+
+        Some(Box::new(self.labels.iter().map(|(label, span)| {
+            LabeledSpan::new_with_span(Some(label.clone()), *span)
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use miette::NarratableReportHandler;
+
+    use super::*;
+
+    #[test]
+    fn test_no_labels_is_no_labels() {
+        let problem = Problem::new("example", "an example");
+
+        assert!(
+            problem.labels().is_none(),
+            "A problem without labels should not give miette an empty list"
+        );
+        assert!(
+            problem.source_code().is_none(),
+            "A problem has no source code until it is added"
+        );
+    }
+
+    #[test]
+    fn test_renders_with_miette() {
+        let problem = Problem::new("subject-trailing-period", "the subject ends with a period")
+            .with_help("remove the period")
+            .with_label("this period", Span::new(7, 1, 0, 7))
+            .with_source_code("Subject.\n".into());
+        let mut rendered = String::new();
+
+        NarratableReportHandler::new()
+            .render_report(&mut rendered, &problem)
+            .expect("should render");
+
+        assert!(
+            rendered.contains("subject-trailing-period"),
+            "The code should be shown: {rendered}"
+        );
+        assert!(
+            rendered.contains("Subject."),
+            "The message should be shown: {rendered}"
+        );
+        assert!(
+            rendered.contains("this period"),
+            "The label should be shown: {rendered}"
+        );
+        assert!(
+            rendered.contains("remove the period"),
+            "The help should be shown: {rendered}"
+        );
+    }
+}
@@ -0,0 +1,384 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use miette::Severity;
+
+use crate::{
+    CommitMessage,
+    lint::{
+        Lint, Problem, SubjectBlankLine, SubjectCapitalised, SubjectLength, SubjectNoGutter,
+        SubjectTrailingPeriod,
+    },
+};
+
+/// A collection of [`Lint`]s that can be run over a [`CommitMessage`]
+///
+/// Lints are enabled when they are added, and can be disabled and enabled
+/// again by their code. Codes are matched exactly.
+///
+/// # Examples
+///
+/// ```
+/// use mit_commit::{
+///     CommitMessage,
+///     lint::{Lint, Problem, Registry},
+/// };
+///
+/// #[derive(Debug)]
+/// struct SubjectNotEmpty;
+///
+/// impl Lint for SubjectNotEmpty {
+///     fn get_code(&self) -> &str {
+///         "subject-not-empty"
+///     }
+///
+///     fn check(&self, commit: &CommitMessage<'_>) -> Vec<Problem> {
+///         if commit.get_subject().is_empty() {
+///             vec![Problem::new(self.get_code(), "the subject is empty")]
+///         } else {
+///             vec![]
+///         }
+///     }
+/// }
+///
+/// let registry = Registry::default().with_lint(SubjectNotEmpty);
+///
+/// assert_eq!(registry.get_codes(), vec!["subject-not-empty"]);
+/// assert!(registry.is_enabled("subject-not-empty"));
+/// assert_eq!(registry.run(&CommitMessage::from("\n")).len(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct Registry {
+    lints: Vec<Box<dyn Lint>>,
+    disabled: BTreeSet<String>,
+    severities: BTreeMap<String, Severity>,
+}
+
+impl Registry {
+    /// Give you a new [`Registry`] with a lint added
+    ///
+    /// If there is already a lint with the same code, it is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `lint` - The lint to add
+    ///
+    /// # Returns
+    ///
+    /// The registry with the lint added
+    #[must_use]
+    pub fn with_lint(mut self, lint: impl Lint + 'static) -> Self {
+        let lint: Box<dyn Lint> = Box::new(lint);
+
+        match self
+            .lints
+            .iter()
+            .position(|existing| existing.get_code() == lint.get_code())
+        {
+            Some(position) => self.lints[position] = lint,
+            None => self.lints.push(lint),
+        }
+
+        self
+    }
+
+    /// Give you a new [`Registry`] with the subject lints that come with the
+    /// crate added, with their default settings
+    ///
+    /// These are [`SubjectLength`], [`SubjectTrailingPeriod`],
+    /// [`SubjectCapitalised`], [`SubjectBlankLine`] and [`SubjectNoGutter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{CommitMessage, lint::Registry};
+    ///
+    /// let registry = Registry::default().with_subject_lints();
+    /// let problems = registry.run(&CommitMessage::from("fix the bug.\nIt was bad\n"));
+    ///
+    /// assert_eq!(
+    ///     problems.iter().map(|problem| problem.get_code()).collect::<Vec<_>>(),
+    ///     vec![
+    ///         "subject-trailing-period",
+    ///         "subject-capitalised",
+    ///         "subject-no-gutter"
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_subject_lints(self) -> Self {
+        self.with_lint(SubjectLength::default())
+            .with_lint(SubjectTrailingPeriod)
+            .with_lint(SubjectCapitalised)
+            .with_lint(SubjectBlankLine)
+            .with_lint(SubjectNoGutter)
+    }
+
+    /// Give you a new [`Registry`] with a lint disabled
+    ///
+    /// The code doesn't need to belong to a lint in the registry yet. If a
+    /// lint with the code is added later it will start disabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The code of the lint to disable
+    ///
+    /// # Returns
+    ///
+    /// The registry with the lint disabled
+    #[must_use]
+    pub fn with_disabled(mut self, code: &str) -> Self {
+        self.disabled.insert(code.into());
+        self
+    }
+
+    /// Give you a new [`Registry`] with a lint enabled
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The code of the lint to enable
+    ///
+    /// # Returns
+    ///
+    /// The registry with the lint enabled
+    #[must_use]
+    pub fn with_enabled(mut self, code: &str) -> Self {
+        self.disabled.remove(code);
+        self
+    }
+
+    /// Give you a new [`Registry`] where every problem from a lint has this
+    /// severity
+    ///
+    /// This replaces whatever severity the lint gave its problems.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The code of the lint
+    /// * `severity` - The severity its problems should have
+    ///
+    /// # Returns
+    ///
+    /// The registry with the severity set
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use miette::Severity;
+    /// use mit_commit::{
+    ///     CommitMessage,
+    ///     lint::{Registry, SubjectTrailingPeriod},
+    /// };
+    ///
+    /// let registry = Registry::default()
+    ///     .with_lint(SubjectTrailingPeriod)
+    ///     .with_severity(SubjectTrailingPeriod::CODE, Severity::Advice);
+    /// let problems = registry.run(&CommitMessage::from("Subject.\n"));
+    ///
+    /// assert_eq!(problems[0].get_severity(), Severity::Advice);
+    /// ```
+    #[must_use]
+    pub fn with_severity(mut self, code: &str, severity: Severity) -> Self {
+        self.severities.insert(code.into(), severity);
+        self
+    }
+
+    /// Get the severity set for a lint with [`Registry::with_severity`], if
+    /// there is one
+    #[must_use]
+    pub fn get_severity(&self, code: &str) -> Option<Severity> {
+        self.severities.get(code).copied()
+    }
+
+    /// Is the lint with this code enabled
+    ///
+    /// # Returns
+    ///
+    /// True if there is a lint with this code and it hasn't been disabled
+    #[must_use]
+    pub fn is_enabled(&self, code: &str) -> bool {
+        !self.disabled.contains(code) && self.lints.iter().any(|lint| lint.get_code() == code)
+    }
+
+    /// Get the codes of all the lints, enabled or not, in the order they
+    /// were added
+    #[must_use]
+    pub fn get_codes(&self) -> Vec<&str> {
+        self.lints.iter().map(|lint| lint.get_code()).collect()
+    }
+
+    /// Run every enabled lint over a commit message
+    ///
+    /// Problems are returned in the order the lints were added, and each
+    /// has the text of the message added as its source code, so it can be
+    /// shown with [`miette`]. The lints check that text parsed again, so the
+    /// labels point into it even if the message has `\r\n` line endings or
+    /// has been edited.
+    ///
+    /// # Arguments
+    ///
+    /// * `commit` - The commit message to check
+    ///
+    /// # Returns
+    ///
+    /// Every problem found by every enabled lint
+    #[must_use]
+    pub fn run(&self, commit: &CommitMessage<'_>) -> Vec<Problem> {
+        let source_code = String::from(commit);
+        let commit = CommitMessage::parse_with(&source_code, commit.get_options().clone());
+
+        self.lints
+            .iter()
+            .filter(|lint| !self.disabled.contains(lint.get_code()))
+            .flat_map(|lint| {
+                let severity = self.get_severity(lint.get_code());
+
+                lint.check(&commit)
+                    .into_iter()
+                    .map(move |problem| match severity {
+                        Some(severity) => problem.with_severity(severity),
+                        None => problem,
+                    })
+            })
+            .map(|problem| {
+                if problem.get_source_code().is_some() {
+                    problem
+                } else {
+                    problem.with_source_code(source_code.clone())
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Body;
+
+    #[derive(Debug)]
+    struct Always(&'static str, &'static str);
+
+    #[derive(Debug)]
+    struct LabelsTheBody;
+
+    impl Lint for LabelsTheBody {
+        fn get_code(&self) -> &'static str {
+            "labels-the-body"
+        }
+
+        fn check(&self, commit: &CommitMessage<'_>) -> Vec<Problem> {
+            commit
+                .get_body()
+                .iter()
+                .filter(|body| !body.is_empty())
+                .filter_map(Body::get_span)
+                .map(|span| Problem::new("labels-the-body", "a body").with_label("here", span))
+                .collect()
+        }
+    }
+
+    impl Lint for Always {
+        fn get_code(&self) -> &str {
+            self.0
+        }
+
+        fn check(&self, _: &CommitMessage<'_>) -> Vec<Problem> {
+            vec![Problem::new(self.0, self.1)]
+        }
+    }
+
+    #[test]
+    fn test_adding_a_lint_with_the_same_code_replaces_it() {
+        let registry = Registry::default()
+            .with_lint(Always("first", "one"))
+            .with_lint(Always("second", "two"))
+            .with_lint(Always("first", "three"));
+
+        assert_eq!(
+            registry.get_codes(),
+            vec!["first", "second"],
+            "The replacement should keep its place"
+        );
+        assert_eq!(
+            registry
+                .run(&CommitMessage::default())
+                .iter()
+                .map(Problem::get_error)
+                .collect::<Vec<_>>(),
+            vec!["three", "two"],
+            "Only the replacement should run"
+        );
+    }
+
+    #[test]
+    fn test_disabling_before_adding() {
+        let registry = Registry::default()
+            .with_disabled("first")
+            .with_lint(Always("first", "one"));
+
+        assert!(
+            !registry.is_enabled("first"),
+            "A lint disabled before it is added should start disabled"
+        );
+        assert!(
+            registry.run(&CommitMessage::default()).is_empty(),
+            "Disabled lints should not run"
+        );
+        assert!(
+            registry.with_enabled("first").is_enabled("first"),
+            "Enabling should undo disabling"
+        );
+    }
+
+    #[test]
+    fn test_severity_replaces_the_lints_own() {
+        let registry = Registry::default()
+            .with_lint(Always("first", "one"))
+            .with_lint(Always("second", "two"))
+            .with_severity("first", Severity::Warning);
+        let problems = registry.run(&CommitMessage::default());
+
+        assert_eq!(
+            problems
+                .iter()
+                .map(Problem::get_severity)
+                .collect::<Vec<_>>(),
+            vec![Severity::Warning, Severity::Error],
+            "Only the lint with the severity set should change"
+        );
+    }
+
+    #[test]
+    fn test_unknown_codes_are_not_enabled() {
+        assert!(
+            !Registry::default().is_enabled("unknown"),
+            "There is no lint to be enabled"
+        );
+    }
+
+    #[test]
+    fn test_run_adds_the_message_as_source_code() {
+        let registry = Registry::default().with_lint(Always("first", "one"));
+        let problems = registry.run(&CommitMessage::from("Subject\n\nBody\n"));
+
+        assert_eq!(
+            problems[0].get_source_code(),
+            Some("Subject\n\nBody\n"),
+            "The message should be attached to show the labels"
+        );
+    }
+
+    #[test]
+    fn test_labels_point_into_the_source_code() {
+        let registry = Registry::default().with_lint(LabelsTheBody);
+        let problems = registry.run(&CommitMessage::from("Subject\r\n\r\nBody\r\n"));
+        let source_code = problems[0].get_source_code().unwrap_or_default();
+        let span = problems[0].get_labels()[0].1;
+
+        assert_eq!(
+            &source_code[span.get_offset()..span.get_end()],
+            "Body",
+            "The label should point at the body in the source code, which has no carriage returns"
+        );
+    }
+}
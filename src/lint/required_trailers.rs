@@ -0,0 +1,142 @@
+use crate::{
+    CommitMessage, Fragment,
+    lint::{Lint, Problem},
+};
+
+/// Some trailers must be in every commit message
+///
+/// Keys are compared ignoring case, like [`crate::Trailers::get`].
+///
+/// # Examples
+///
+/// ```
+/// use mit_commit::{
+///     CommitMessage,
+///     lint::{Lint, RequiredTrailers},
+/// };
+///
+/// let lint = RequiredTrailers::default().with_key("Signed-off-by");
+///
+/// assert_eq!(lint.get_keys(), ["Signed-off-by"]);
+/// assert_eq!(lint.check(&CommitMessage::from("Subject\n\nBody\n")).len(), 1);
+/// assert!(
+///     lint.check(&CommitMessage::from(
+///         "Subject\n\nBody\n\nsigned-off-by: Someone <someone@example.com>\n"
+///     ))
+///     .is_empty()
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RequiredTrailers {
+    keys: Vec<String>,
+}
+
+impl RequiredTrailers {
+    /// The code for this lint
+    pub const CODE: &'static str = "required-trailers";
+
+    /// Give you a new [`RequiredTrailers`] that also requires this key
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of a trailer that must be present
+    ///
+    /// # Returns
+    ///
+    /// The lint with the key added to the keys it requires
+    #[must_use]
+    pub fn with_key(mut self, key: &str) -> Self {
+        self.keys.push(key.into());
+        self
+    }
+
+    /// Get the keys of the trailers that must be present
+    #[must_use]
+    pub fn get_keys(&self) -> &[String] {
+        &self.keys
+    }
+}
+
+impl Lint for RequiredTrailers {
+    fn get_code(&self) -> &str {
+        Self::CODE
+    }
+
+    fn check(&self, commit: &CommitMessage<'_>) -> Vec<Problem> {
+        let trailers = commit.get_trailers();
+        let missing: Vec<&String> = self
+            .keys
+            .iter()
+            .filter(|key| trailers.get(key).is_none())
+            .collect();
+
+        if missing.is_empty() {
+            return vec![];
+        }
+
+        // Trailers go at the end of the message, so point there
+        let end = commit
+            .get_ast()
+            .iter()
+            .rev()
+            .find_map(|fragment| match fragment {
+                Fragment::Body(body) if !body.is_empty() => body.get_span().map(|span| {
+                    let text = body.as_str();
+                    span.within(text, text.len()..text.len())
+                }),
+                Fragment::Body(_) | Fragment::Comment(_) => None,
+            })
+            .unwrap_or_default();
+
+        missing
+            .into_iter()
+            .map(|key| {
+                Problem::new(Self::CODE, &format!("the {key} trailer is missing"))
+                    .with_help(&format!(
+                        "add a `{key}: ` trailer to the end of the message"
+                    ))
+                    .with_label("expected it after this", end)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reports_each_missing_key() {
+        let lint = RequiredTrailers::default()
+            .with_key("Signed-off-by")
+            .with_key("Relates-to")
+            .with_key("Co-authored-by");
+        let problems = lint.check(&CommitMessage::from(
+            "Subject\n\nBody\n\nRelates-to: #128\n\n# A comment\n",
+        ));
+
+        assert_eq!(
+            problems.iter().map(Problem::get_error).collect::<Vec<_>>(),
+            vec![
+                "the Signed-off-by trailer is missing",
+                "the Co-authored-by trailer is missing"
+            ],
+            "Only the missing trailers should be reported"
+        );
+        assert_eq!(
+            problems[0].get_labels()[0].1.get_offset(),
+            31,
+            "The label should be at the end of the last paragraph"
+        );
+    }
+
+    #[test]
+    fn test_no_keys_is_no_problems() {
+        assert!(
+            RequiredTrailers::default()
+                .check(&CommitMessage::from("Subject\n"))
+                .is_empty(),
+            "Nothing is required by default"
+        );
+    }
+}
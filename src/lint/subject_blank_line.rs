@@ -0,0 +1,158 @@
+use crate::{
+    CommitMessage, Fix, Fragment, Span,
+    lint::{Lint, Problem, reparse},
+};
+
+/// The line after the subject should be empty
+///
+/// This catches the gutters that look empty but aren't: a line of only
+/// whitespace, or comments between the subject and the body, which git
+/// removes and leaves the body straight after the subject. A body with no
+/// gutter at all is reported by [`crate::lint::SubjectNoGutter`].
+///
+/// # Examples
+///
+/// ```
+/// use mit_commit::{
+///     CommitMessage,
+///     lint::{Lint, SubjectBlankLine},
+/// };
+///
+/// let problems = SubjectBlankLine.check(&CommitMessage::from("Subject\n  \nBody\n"));
+///
+/// assert_eq!(problems.len(), 1);
+/// assert_eq!(problems[0].get_labels()[0].1.get_offset(), 8);
+///
+/// let problems = SubjectBlankLine.check(&CommitMessage::from("Subject\n# Comment\nBody\n"));
+///
+/// assert_eq!(problems.len(), 1);
+/// assert!(
+///     SubjectBlankLine
+///         .check(&CommitMessage::from("Subject\n\nBody\n"))
+///         .is_empty()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SubjectBlankLine;
+
+impl SubjectBlankLine {
+    /// The code for this lint
+    pub const CODE: &'static str = "subject-blank-line";
+}
+
+impl Lint for SubjectBlankLine {
+    fn get_code(&self) -> &str {
+        Self::CODE
+    }
+
+    fn check(&self, commit: &CommitMessage<'_>) -> Vec<Problem> {
+        let commit = reparse(commit);
+        let subject = commit.get_subject().to_string();
+
+        if let Some((first_line, rest)) = subject.split_once('\n') {
+            let second_line = rest.split('\n').next().unwrap_or_default();
+            if !second_line.is_empty() && second_line.trim().is_empty() {
+                let Some(subject_span) = commit.get_subject().get_span() else {
+                    return vec![];
+                };
+                let start = first_line.len() + 1;
+                let span = subject_span.within(&subject, start..start + second_line.len());
+
+                return vec![
+                    Problem::new(Self::CODE, "the line after the subject isn't empty")
+                        .with_help("remove the whitespace from the line after the subject")
+                        .with_label("this line has whitespace in it", span)
+                        .with_fix(Fix::new(span, String::new())),
+                ];
+            }
+
+            return vec![];
+        }
+
+        let ast = commit.get_ast();
+        let after_subject = ast
+            .iter()
+            .skip_while(|fragment| !matches!(fragment, Fragment::Body(_)))
+            .skip(1);
+        let mut comments = None;
+
+        for fragment in after_subject {
+            match fragment {
+                Fragment::Comment(comment) => {
+                    if let Some(span) = comment.get_span() {
+                        comments = Some(comments.map_or(span, |first: Span| first.extend(span)));
+                    }
+                }
+                Fragment::Body(body) if body.is_empty() => return vec![],
+                Fragment::Body(_) => break,
+            }
+        }
+
+        comments.map_or_else(Vec::new, |span| {
+            vec![
+                Problem::new(
+                    Self::CODE,
+                    "there is no empty line after the subject once comments are removed",
+                )
+                .with_help("add an empty line between the subject and the comments")
+                .with_label("git removes these lines", span)
+                .with_fix(Fix::new(
+                    Span::new(span.get_offset(), 0, span.get_line(), span.get_column()),
+                    "\n".into(),
+                )),
+            ]
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comments_then_empty_line_is_fine() {
+        assert!(
+            SubjectBlankLine
+                .check(&CommitMessage::from("Subject\n# Comment\n\nBody\n"))
+                .is_empty(),
+            "The empty line is still there once comments are removed"
+        );
+    }
+
+    #[test]
+    fn test_only_comments_after_subject_is_fine() {
+        assert!(
+            SubjectBlankLine
+                .check(&CommitMessage::from("Subject\n# Comment\n# More\n"))
+                .is_empty(),
+            "There is no body to run into the subject"
+        );
+    }
+
+    #[test]
+    fn test_labels_all_the_comments() {
+        let problems =
+            SubjectBlankLine.check(&CommitMessage::from("Subject\n# Comment\n# More\nBody\n"));
+
+        assert_eq!(
+            problems[0].get_labels()[0].1.get_offset(),
+            8,
+            "The label should start at the first comment"
+        );
+        assert_eq!(
+            problems[0].get_labels()[0].1.get_length(),
+            16,
+            "The label should run to the end of the last comment"
+        );
+    }
+
+    #[test]
+    fn test_no_gutter_is_not_this_lint() {
+        assert!(
+            SubjectBlankLine
+                .check(&CommitMessage::from("Subject\nBody\n"))
+                .is_empty(),
+            "No gutter at all is reported by SubjectNoGutter"
+        );
+    }
+}
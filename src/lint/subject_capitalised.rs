@@ -0,0 +1,97 @@
+use crate::{
+    CommitMessage, Fix,
+    lint::{Lint, Problem, reparse},
+};
+
+/// The subject should start with a capital letter
+///
+/// Subjects that start with something other than a letter, like a number or
+/// a bracket, are fine.
+///
+/// # Examples
+///
+/// ```
+/// use mit_commit::{
+///     CommitMessage,
+///     lint::{Lint, SubjectCapitalised},
+/// };
+///
+/// let problems = SubjectCapitalised.check(&CommitMessage::from("add a feature\n"));
+///
+/// assert_eq!(problems.len(), 1);
+/// assert_eq!(problems[0].get_labels()[0].1.get_length(), 1);
+/// assert!(
+///     SubjectCapitalised
+///         .check(&CommitMessage::from("Add a feature\n"))
+///         .is_empty()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SubjectCapitalised;
+
+impl SubjectCapitalised {
+    /// The code for this lint
+    pub const CODE: &'static str = "subject-capitalised";
+}
+
+impl Lint for SubjectCapitalised {
+    fn get_code(&self) -> &str {
+        Self::CODE
+    }
+
+    fn check(&self, commit: &CommitMessage<'_>) -> Vec<Problem> {
+        let commit = reparse(commit);
+        let subject = commit.get_subject().to_string();
+        let Some(first) = subject.chars().next().filter(|first| first.is_lowercase()) else {
+            return vec![];
+        };
+
+        let Some(subject_span) = commit.get_subject().get_span() else {
+            return vec![];
+        };
+        let span = subject_span.within(&subject, 0..first.len_utf8());
+
+        vec![
+            Problem::new(
+                Self::CODE,
+                "the subject doesn't start with a capital letter",
+            )
+            .with_help(&format!("start the subject with {}", first.to_uppercase()))
+            .with_label("this letter", span)
+            .with_fix(Fix::new(span, first.to_uppercase().to_string())),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_non_letters_are_fine() {
+        for subject in ["2 things\n", "[ci] Fix it\n", "\n"] {
+            assert!(
+                SubjectCapitalised
+                    .check(&CommitMessage::from(subject))
+                    .is_empty(),
+                "{subject:?} doesn't start with a letter"
+            );
+        }
+    }
+
+    #[test]
+    fn test_labels_multibyte_letters() {
+        let problems = SubjectCapitalised.check(&CommitMessage::from("élan\n"));
+
+        assert_eq!(
+            problems[0].get_labels()[0].1.get_length(),
+            2,
+            "The label should cover the whole letter"
+        );
+        assert_eq!(
+            problems[0].get_help(),
+            Some("start the subject with É"),
+            "The help should suggest the capital"
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use miette::Severity;
+
+use crate::{
+    CommitMessage,
+    lint::{Lint, Problem, reparse},
+};
+
+/// The subject should be short enough to read in a one line log
+///
+/// Subjects over the soft limit are warnings, and subjects over the hard
+/// limit are errors. Only the first line of the subject is checked, and
+/// length is counted in characters.
+///
+/// # Examples
+///
+/// ```
+/// use miette::Severity;
+/// use mit_commit::{
+///     CommitMessage,
+///     lint::{Lint, SubjectLength},
+/// };
+///
+/// let lint = SubjectLength::default();
+/// let commit = CommitMessage::from(format!("{}\n", "x".repeat(60)));
+/// let problems = lint.check(&commit);
+///
+/// assert_eq!(problems.len(), 1);
+/// assert_eq!(problems[0].get_severity(), Severity::Warning);
+/// assert_eq!(problems[0].get_labels()[0].1.get_offset(), 50);
+/// assert_eq!(problems[0].get_labels()[0].1.get_length(), 10);
+///
+/// let lint = lint.with_soft_limit(60);
+///
+/// assert!(lint.check(&commit).is_empty());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubjectLength {
+    soft_limit: usize,
+    hard_limit: usize,
+}
+
+impl SubjectLength {
+    /// The code for this lint
+    pub const CODE: &'static str = "subject-length";
+
+    /// Give you a new [`SubjectLength`] with a different soft limit
+    ///
+    /// # Arguments
+    ///
+    /// * `soft_limit` - How many characters a subject can have before it is
+    ///   a warning
+    ///
+    /// # Returns
+    ///
+    /// The lint with the soft limit replaced
+    #[must_use]
+    pub const fn with_soft_limit(self, soft_limit: usize) -> Self {
+        Self { soft_limit, ..self }
+    }
+
+    /// Give you a new [`SubjectLength`] with a different hard limit
+    ///
+    /// # Arguments
+    ///
+    /// * `hard_limit` - How many characters a subject can have before it is
+    ///   an error
+    ///
+    /// # Returns
+    ///
+    /// The lint with the hard limit replaced
+    #[must_use]
+    pub const fn with_hard_limit(self, hard_limit: usize) -> Self {
+        Self { hard_limit, ..self }
+    }
+
+    /// Get how many characters a subject can have before it is a warning
+    #[must_use]
+    pub const fn get_soft_limit(&self) -> usize {
+        self.soft_limit
+    }
+
+    /// Get how many characters a subject can have before it is an error
+    #[must_use]
+    pub const fn get_hard_limit(&self) -> usize {
+        self.hard_limit
+    }
+}
+
+impl Default for SubjectLength {
+    /// A soft limit of 50 and a hard limit of 72, as git's documentation
+    /// suggests
+    fn default() -> Self {
+        Self {
+            soft_limit: 50,
+            hard_limit: 72,
+        }
+    }
+}
+
+impl Lint for SubjectLength {
+    fn get_code(&self) -> &str {
+        Self::CODE
+    }
+
+    fn check(&self, commit: &CommitMessage<'_>) -> Vec<Problem> {
+        let commit = reparse(commit);
+        let subject = commit.get_subject().to_string();
+        let first_line = subject.split('\n').next().unwrap_or_default();
+        let length = first_line.chars().count();
+
+        let (limit, severity) = if length > self.hard_limit {
+            (self.hard_limit, Severity::Error)
+        } else if length > self.soft_limit {
+            (self.soft_limit, Severity::Warning)
+        } else {
+            return vec![];
+        };
+
+        let start = first_line
+            .char_indices()
+            .nth(limit)
+            .map_or(first_line.len(), |(index, _)| index);
+        let Some(subject_span) = commit.get_subject().get_span() else {
+            return vec![];
+        };
+        let span = subject_span.within(&subject, start..first_line.len());
+
+        vec![
+            Problem::new(
+                Self::CODE,
+                &format!("the subject is {length} characters long, over the limit of {limit}"),
+            )
+            .with_help("shorten the subject, and put the detail in the body")
+            .with_severity(severity)
+            .with_label("these characters are over the limit", span),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_over_the_hard_limit_is_an_error() {
+        let commit = CommitMessage::from(format!("{}\n", "ä".repeat(80)));
+        let problems = SubjectLength::default().check(&commit);
+
+        assert_eq!(problems.len(), 1, "Only one problem should be reported");
+        assert_eq!(
+            problems[0].get_severity(),
+            Severity::Error,
+            "Over the hard limit should be an error"
+        );
+        assert_eq!(
+            problems[0].get_labels()[0].1.get_offset(),
+            144,
+            "The label should start after the 72nd character, counting bytes"
+        );
+        assert_eq!(
+            problems[0].get_labels()[0].1.get_column(),
+            72,
+            "The column should be counted in characters"
+        );
+    }
+
+    #[test]
+    fn test_at_the_limit_is_fine() {
+        let commit = CommitMessage::from(format!("{}\n\nBody\n", "x".repeat(50)));
+
+        assert!(
+            SubjectLength::default().check(&commit).is_empty(),
+            "A subject exactly at the limit is allowed"
+        );
+    }
+
+    #[test]
+    fn test_only_the_first_line_counts() {
+        let commit = CommitMessage::from(format!("Subject\n{}\n", "x".repeat(80)));
+
+        assert!(
+            SubjectLength::default().check(&commit).is_empty(),
+            "Lines after the first aren't part of the one line log"
+        );
+    }
+}
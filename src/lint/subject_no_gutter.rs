@@ -0,0 +1,109 @@
+use crate::{
+    CommitMessage, Fix, Span,
+    lint::{Lint, Problem, reparse},
+};
+
+/// The body shouldn't run straight on from the subject
+///
+/// Without an empty line, the gutter, between them, git and this crate read
+/// the body as part of the subject.
+///
+/// # Examples
+///
+/// ```
+/// use mit_commit::{
+///     CommitMessage,
+///     lint::{Lint, SubjectNoGutter},
+/// };
+///
+/// let commit = CommitMessage::from("Example Commit Message\nwithout gutter\n");
+/// let problems = SubjectNoGutter.check(&commit);
+///
+/// assert_eq!(problems.len(), 1);
+/// assert_eq!(problems[0].get_labels()[0].1.get_offset(), 23);
+/// assert!(
+///     SubjectNoGutter
+///         .check(&CommitMessage::from("Subject\n\nBody\n"))
+///         .is_empty()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SubjectNoGutter;
+
+impl SubjectNoGutter {
+    /// The code for this lint
+    pub const CODE: &'static str = "subject-no-gutter";
+}
+
+impl Lint for SubjectNoGutter {
+    fn get_code(&self) -> &str {
+        Self::CODE
+    }
+
+    fn check(&self, commit: &CommitMessage<'_>) -> Vec<Problem> {
+        let commit = reparse(commit);
+        let subject = commit.get_subject().to_string();
+        let Some((_, rest)) = subject.split_once('\n') else {
+            return vec![];
+        };
+
+        // A gutter with only whitespace in it is left to SubjectBlankLine
+        if rest
+            .split('\n')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .is_empty()
+        {
+            return vec![];
+        }
+
+        let Some(subject_span) = commit.get_subject().get_span() else {
+            return vec![];
+        };
+        let span = subject_span.within(&subject, subject.len() - rest.len()..subject.len());
+
+        vec![
+            Problem::new(Self::CODE, "there is no empty line after the subject")
+                .with_help("add an empty line between the subject and the body")
+                .with_label("this is read as part of the subject", span)
+                .with_fix(Fix::new(
+                    Span::new(span.get_offset(), 0, span.get_line(), span.get_column()),
+                    "\n".into(),
+                )),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_labels_all_of_the_run_on_body() {
+        let problems = SubjectNoGutter.check(&CommitMessage::from(
+            "Subject\nBody line one\nBody line two\n\nMore\n",
+        ));
+
+        assert_eq!(
+            problems[0].get_labels()[0].1.get_length(),
+            27,
+            "Everything read as the subject after the first line should be labelled"
+        );
+        assert_eq!(
+            problems[0].get_labels()[0].1.get_line(),
+            1,
+            "The label should start on the second line"
+        );
+    }
+
+    #[test]
+    fn test_whitespace_gutter_is_not_this_lint() {
+        assert!(
+            SubjectNoGutter
+                .check(&CommitMessage::from("Subject\n \nBody\n"))
+                .is_empty(),
+            "A whitespace gutter is still a gutter"
+        );
+    }
+}
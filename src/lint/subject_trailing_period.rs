@@ -0,0 +1,105 @@
+use crate::{
+    CommitMessage, Fix,
+    lint::{Lint, Problem, reparse},
+};
+
+/// The subject is a title, so it shouldn't end with a period
+///
+/// # Examples
+///
+/// ```
+/// use mit_commit::{
+///     CommitMessage,
+///     lint::{Lint, SubjectTrailingPeriod},
+/// };
+///
+/// let problems = SubjectTrailingPeriod.check(&CommitMessage::from("Add a feature.\n"));
+///
+/// assert_eq!(problems.len(), 1);
+/// assert_eq!(problems[0].get_labels()[0].1.get_offset(), 13);
+/// assert!(
+///     SubjectTrailingPeriod
+///         .check(&CommitMessage::from("Add a feature\n"))
+///         .is_empty()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SubjectTrailingPeriod;
+
+impl SubjectTrailingPeriod {
+    /// The code for this lint
+    pub const CODE: &'static str = "subject-trailing-period";
+}
+
+impl Lint for SubjectTrailingPeriod {
+    fn get_code(&self) -> &str {
+        Self::CODE
+    }
+
+    fn check(&self, commit: &CommitMessage<'_>) -> Vec<Problem> {
+        let commit = reparse(commit);
+        let subject = commit.get_subject().to_string();
+        let first_line = subject.split('\n').next().unwrap_or_default();
+        let without_periods = first_line.trim_end_matches('.');
+
+        if without_periods.len() == first_line.len() {
+            return vec![];
+        }
+
+        let Some(subject_span) = commit.get_subject().get_span() else {
+            return vec![];
+        };
+        let span = subject_span.within(&subject, without_periods.len()..first_line.len());
+
+        vec![
+            Problem::new(Self::CODE, "the subject ends with a period")
+                .with_help("remove the period from the end of the subject")
+                .with_label("this period", span)
+                .with_fix(Fix::new(span, String::new())),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_labels_every_trailing_period() {
+        let problems = SubjectTrailingPeriod.check(&CommitMessage::from("Wait for it...\n"));
+
+        assert_eq!(
+            problems[0].get_labels()[0].1.get_length(),
+            3,
+            "All the trailing periods should be labelled"
+        );
+    }
+
+    #[test]
+    fn test_only_the_first_line_counts() {
+        assert!(
+            SubjectTrailingPeriod
+                .check(&CommitMessage::from("Subject\nBody.\n"))
+                .is_empty(),
+            "A period in the line after the subject isn't at the end of the subject"
+        );
+    }
+
+    #[test]
+    fn test_labels_the_period_in_a_message_with_crlf_line_endings() {
+        let commit = CommitMessage::from("# Comment\r\nSubject.\r\n\r\nBody\r\n");
+        let problems = SubjectTrailingPeriod.check(&commit);
+        let span = problems[0].get_labels()[0].1;
+
+        assert_eq!(
+            &String::from(&commit)[span.get_offset()..span.get_end()],
+            ".",
+            "The label should point at the period in the text of the message"
+        );
+        assert_eq!(
+            String::from(commit.apply_fixes(problems[0].get_fixes()).unwrap()),
+            "# Comment\nSubject\n\nBody\n",
+            "The fix should only remove the period"
+        );
+    }
+}
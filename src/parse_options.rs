@@ -0,0 +1,424 @@
+use std::borrow::Cow;
+
+/// How the comment marker of a [`crate::CommitMessage`] is decided
+///
+/// Git lets you configure this with `core.commentChar`, or since 2.45
+/// `core.commentString`, which may be more than one character long.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum CommentMarker {
+    /// Guess the marker from the message, the same way
+    /// [`crate::CommitMessage::from`] does
+    #[default]
+    Guess,
+    /// Treat lines starting with this string as comments
+    Explicit(String),
+    /// Treat no lines as comments, as git does with `--cleanup=verbatim`
+    Disabled,
+}
+
+impl CommentMarker {
+    /// Work out the marker to use for a given message
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The message that the marker is being resolved for
+    ///
+    /// # Returns
+    ///
+    /// The comment marker, or None if no lines should be treated as comments
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::CommentMarker;
+    ///
+    /// assert_eq!(
+    ///     CommentMarker::Guess.resolve("Subject\n\n; Comment"),
+    ///     Some(";".into())
+    /// );
+    /// assert_eq!(
+    ///     CommentMarker::from("//").resolve("Subject\n\n; Comment"),
+    ///     Some("//".into())
+    /// );
+    /// assert_eq!(
+    ///     CommentMarker::Disabled.resolve("Subject\n\n; Comment"),
+    ///     None
+    /// );
+    /// ```
+    #[must_use]
+    pub fn resolve(&self, message: &str) -> Option<Cow<'_, str>> {
+        self.resolve_with(|| crate::scissors::Scissors::guess_comment_character(message))
+    }
+
+    /// Work out the marker to use, only guessing if the marker is
+    /// [`CommentMarker::Guess`]
+    pub(crate) fn resolve_with(
+        &self,
+        guess: impl FnOnce() -> Option<char>,
+    ) -> Option<Cow<'_, str>> {
+        match self {
+            Self::Guess => guess().map(|character| Cow::Owned(character.to_string())),
+            Self::Explicit(marker) if marker.is_empty() => None,
+            Self::Explicit(marker) => Some(Cow::Borrowed(marker)),
+            Self::Disabled => None,
+        }
+    }
+}
+
+impl From<char> for CommentMarker {
+    /// Use a single character as the comment marker
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::CommentMarker;
+    ///
+    /// assert_eq!(
+    ///     CommentMarker::from(';'),
+    ///     CommentMarker::Explicit(";".into())
+    /// );
+    /// ```
+    fn from(marker: char) -> Self {
+        Self::Explicit(marker.to_string())
+    }
+}
+
+impl From<&str> for CommentMarker {
+    /// Use a string as the comment marker
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::CommentMarker;
+    ///
+    /// assert_eq!(
+    ///     CommentMarker::from("//"),
+    ///     CommentMarker::Explicit("//".into())
+    /// );
+    /// ```
+    fn from(marker: &str) -> Self {
+        Self::Explicit(marker.to_string())
+    }
+}
+
+impl From<String> for CommentMarker {
+    /// Use a string as the comment marker
+    fn from(marker: String) -> Self {
+        Self::Explicit(marker)
+    }
+}
+
+/// How the block of [`crate::Trailers`] at the end of a message is found
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum TrailerDetection {
+    /// Every non-empty line at the end of the message, after the subject, up
+    /// to the first line that isn't a trailer
+    #[default]
+    Strict,
+    /// The algorithm `git interpret-trailers` uses
+    ///
+    /// The last paragraph is a trailer block if every line in it is a
+    /// trailer, or if at least 25% of its lines are trailers and one of them
+    /// was generated by git, like `Signed-off-by: ` or `(cherry picked from
+    /// commit `. Lines after a `---` divider are ignored, and only `:` is
+    /// treated as a separator.
+    Git,
+}
+
+/// Options controlling how a [`crate::CommitMessage`] is parsed
+///
+/// The defaults match the behaviour of [`crate::CommitMessage::from`].
+///
+/// # Examples
+///
+/// ```
+/// use mit_commit::{CommentMarker, CommitMessage, ParseOptions};
+///
+/// let options = ParseOptions::default().with_comment_marker("//".into());
+/// let message = CommitMessage::parse_with("Subject\n\n// A comment", options);
+///
+/// assert_eq!(message.get_comments().iter().count(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct ParseOptions {
+    comment_marker: CommentMarker,
+    trailer_detection: TrailerDetection,
+    trailer_separators: Option<String>,
+}
+
+impl ParseOptions {
+    /// Give you new [`ParseOptions`] with the provided comment marker
+    ///
+    /// # Arguments
+    ///
+    /// * `comment_marker` - How to decide which lines are comments
+    ///
+    /// # Returns
+    ///
+    /// The options with the comment marker replaced
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{CommentMarker, ParseOptions};
+    ///
+    /// let options = ParseOptions::default().with_comment_marker(CommentMarker::Disabled);
+    ///
+    /// assert_eq!(options.get_comment_marker(), &CommentMarker::Disabled);
+    /// ```
+    #[must_use]
+    pub fn with_comment_marker(self, comment_marker: CommentMarker) -> Self {
+        Self {
+            comment_marker,
+            ..self
+        }
+    }
+
+    /// Give you new [`ParseOptions`] with the provided trailer detection
+    ///
+    /// # Arguments
+    ///
+    /// * `trailer_detection` - How to find the block of trailers
+    ///
+    /// # Returns
+    ///
+    /// The options with the trailer detection replaced
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{ParseOptions, TrailerDetection};
+    ///
+    /// let options = ParseOptions::default().with_trailer_detection(TrailerDetection::Git);
+    ///
+    /// assert_eq!(options.get_trailer_detection(), TrailerDetection::Git);
+    /// ```
+    #[must_use]
+    pub fn with_trailer_detection(self, trailer_detection: TrailerDetection) -> Self {
+        Self {
+            trailer_detection,
+            ..self
+        }
+    }
+
+    /// Give you new [`ParseOptions`] with the provided trailer separators
+    ///
+    /// This mirrors git's `trailer.separators` config option. Each character
+    /// in the string is a separator. Trailer keys must then be made of
+    /// letters, digits and hyphens, and may have whitespace before the
+    /// separator, just like in git. The separator used by each trailer is
+    /// kept, so the message round-trips.
+    ///
+    /// # Arguments
+    ///
+    /// * `trailer_separators` - The characters that may separate a trailer's
+    ///   key from its value, for example `":=#"`
+    ///
+    /// # Returns
+    ///
+    /// The options with the trailer separators replaced
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{CommitMessage, ParseOptions, Trailer};
+    ///
+    /// let options = ParseOptions::default().with_trailer_separators(":=");
+    /// let message = CommitMessage::parse_with("Subject\n\nFixes=123\nKey:value\n", options);
+    ///
+    /// assert_eq!(
+    ///     message.get_trailers().iter().cloned().collect::<Vec<_>>(),
+    ///     vec![
+    ///         Trailer::new_with_separator("Fixes".into(), "123".into(), "=".into()),
+    ///         Trailer::new_with_separator("Key".into(), "value".into(), ":".into()),
+    ///     ]
+    /// );
+    /// assert_eq!(
+    ///     String::from(message),
+    ///     "Subject\n\nFixes=123\nKey:value\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_trailer_separators(self, trailer_separators: &str) -> Self {
+        Self {
+            trailer_separators: Some(trailer_separators.to_string()),
+            ..self
+        }
+    }
+
+    /// Get the [`CommentMarker`] these options use
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{CommentMarker, ParseOptions};
+    ///
+    /// assert_eq!(
+    ///     ParseOptions::default().get_comment_marker(),
+    ///     &CommentMarker::Guess
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn get_comment_marker(&self) -> &CommentMarker {
+        &self.comment_marker
+    }
+
+    /// Get the [`TrailerDetection`] these options use
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{ParseOptions, TrailerDetection};
+    ///
+    /// assert_eq!(
+    ///     ParseOptions::default().get_trailer_detection(),
+    ///     TrailerDetection::Strict
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn get_trailer_detection(&self) -> TrailerDetection {
+        self.trailer_detection
+    }
+
+    /// Get the trailer separators these options use
+    ///
+    /// When none have been set, trailers are found with `: ` or, for short
+    /// keys, ` #`, unless [`TrailerDetection::Git`] is used, in which case
+    /// git's default of `:` applies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::ParseOptions;
+    ///
+    /// assert_eq!(ParseOptions::default().get_trailer_separators(), None);
+    /// assert_eq!(
+    ///     ParseOptions::default()
+    ///         .with_trailer_separators(":#")
+    ///         .get_trailer_separators(),
+    ///     Some(":#")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn get_trailer_separators(&self) -> Option<&str> {
+        self.trailer_separators.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_guesses_the_comment_marker() {
+        assert_eq!(
+            ParseOptions::default().get_comment_marker(),
+            &CommentMarker::Guess,
+            "Default options should guess the comment marker"
+        );
+    }
+
+    #[test]
+    fn test_default_uses_strict_trailer_detection() {
+        assert_eq!(
+            ParseOptions::default().get_trailer_detection(),
+            TrailerDetection::Strict,
+            "Default options should keep the existing trailer detection"
+        );
+    }
+
+    #[test]
+    fn test_setting_one_option_keeps_the_others() {
+        let options = ParseOptions::default()
+            .with_trailer_detection(TrailerDetection::Git)
+            .with_comment_marker(CommentMarker::Disabled);
+
+        assert_eq!(
+            options.get_trailer_detection(),
+            TrailerDetection::Git,
+            "Setting the comment marker should not reset the trailer detection"
+        );
+    }
+
+    #[test]
+    fn test_default_has_no_trailer_separators() {
+        assert_eq!(
+            ParseOptions::default().get_trailer_separators(),
+            None,
+            "Default options should keep the existing trailer separators"
+        );
+    }
+
+    #[test]
+    fn test_setting_trailer_separators_keeps_the_others() {
+        let options = ParseOptions::default()
+            .with_comment_marker(CommentMarker::Disabled)
+            .with_trailer_separators("=");
+
+        assert_eq!(
+            options.get_comment_marker(),
+            &CommentMarker::Disabled,
+            "Setting the trailer separators should not reset the comment marker"
+        );
+    }
+
+    #[test]
+    fn test_guess_resolves_to_the_guessed_character() {
+        assert_eq!(
+            CommentMarker::Guess.resolve("Subject\n\n# Comment"),
+            Some(Cow::from("#")),
+            "Guessing should find the comment character used in the message"
+        );
+    }
+
+    #[test]
+    fn test_guess_resolves_to_none_without_comments() {
+        assert_eq!(
+            CommentMarker::Guess.resolve("Subject\n\nBody"),
+            None,
+            "Guessing should find nothing when there are no comments"
+        );
+    }
+
+    #[test]
+    fn test_explicit_marker_is_used_as_is() {
+        assert_eq!(
+            CommentMarker::from("//").resolve("Subject\n\n# Comment"),
+            Some(Cow::from("//")),
+            "An explicit marker should be used even if another character looks like a comment"
+        );
+    }
+
+    #[test]
+    fn test_empty_explicit_marker_disables_comments() {
+        assert_eq!(
+            CommentMarker::from("").resolve("Subject\n\n# Comment"),
+            None,
+            "An empty marker would match every line, so it should disable comments instead"
+        );
+    }
+
+    #[test]
+    fn test_disabled_marker_resolves_to_none() {
+        assert_eq!(
+            CommentMarker::Disabled.resolve("Subject\n\n# Comment"),
+            None,
+            "A disabled marker should never resolve"
+        );
+    }
+}
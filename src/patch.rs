@@ -0,0 +1,62 @@
+//! Patches sent by email, as written by `git format-patch`
+//!
+//! A [`Patch`] is one email. The commit message in it is read into a
+//! [`CommitMessage`](crate::CommitMessage), so the same lints can be run on
+//! patches that are emailed in as on local commits. An [`Mbox`] is a file of
+//! several of them, like the output of `git format-patch --stdout`.
+//!
+//! Going the other way, a [`Patch`] can be made from a commit with
+//! [`Patch::new`], and turned into an email with [`String::from`]. Reading
+//! that email back gives the same message, author, date, notes and diff.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::convert::TryFrom;
+//!
+//! use mit_commit::{lint::Registry, patch::Mbox};
+//!
+//! let mbox = Mbox::try_from(concat!(
+//!     "From 5f3c0a1e6d2b4a8f9c7e1d3b5a7c9e0f2d4b6a81 Mon Sep 17 00:00:00 2001\n",
+//!     "From: Billie Thompson <billie@example.com>\n",
+//!     "Date: Sat, 13 Jun 2020 19:32:00 +0100\n",
+//!     "Subject: [PATCH 1/2] Add a readme\n",
+//!     "\n",
+//!     "---\n",
+//!     " README.md | 1 +\n",
+//!     "\n",
+//!     "diff --git a/README.md b/README.md\n",
+//!     "--- /dev/null\n",
+//!     "+++ b/README.md\n",
+//!     "@@ -0,0 +1 @@\n",
+//!     "+# Hello\n",
+//!     "-- \n",
+//!     "2.39.0\n",
+//!     "\n",
+//!     "From 0e8d6c4b2a0f8e6d4c2b0a9f7e5d3c1b9a7f5e3d Mon Sep 17 00:00:00 2001\n",
+//!     "From: Billie Thompson <billie@example.com>\n",
+//!     "Date: Sat, 13 Jun 2020 19:33:00 +0100\n",
+//!     "Subject: [PATCH 2/2] fix the typo.\n",
+//!     "\n",
+//!     "---\n",
+//!     "diff --git a/README.md b/README.md\n",
+//! ))
+//! .expect("the patches are valid");
+//! let registry = Registry::default().with_subject_lints();
+//!
+//! let problems = mbox
+//!     .get_patches()
+//!     .iter()
+//!     .map(|patch| registry.run(patch.get_message()).len())
+//!     .collect::<Vec<_>>();
+//!
+//! assert_eq!(problems, vec![0, 2]);
+//! ```
+
+pub use email::{Patch, PatchError};
+pub use mbox::Mbox;
+
+mod email;
+mod encoded_word;
+mod format;
+mod mbox;
@@ -0,0 +1,935 @@
+use std::{borrow::Cow, convert::TryFrom};
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+use super::{encoded_word, format, mbox::is_from_line};
+use crate::{
+    CommentMarker, CommitMessage, Identity, IdentityError, ParseOptions, Signature, Span, span,
+};
+
+/// Headers that can be repeated at the top of the body, when the author of
+/// a patch isn't the person who sent it
+const IN_BODY_HEADERS: [&str; 3] = ["From", "Date", "Subject"];
+
+/// One patch sent by email, as written by `git format-patch`
+///
+/// The commit message is put back together the way `git am` would: the
+/// subject is the `Subject` header without the `[PATCH v2 3/7]` before it,
+/// and the body is everything in the email up to the `---` line, or up to
+/// the diff if there isn't one. What comes between the `---` and the diff,
+/// like the diffstat and notes for reviewers, is kept separately.
+///
+/// `From`, `Date` and `Subject` lines at the very top of the body replace
+/// the headers of the email, as they do for `git am`. Encoded words in the
+/// headers, like `=?UTF-8?q?Bj=C3=B6rn?=`, are decoded. The body has to be
+/// sent as `7bit` or `8bit`.
+///
+/// Turning a [`Patch`] into a [`String`] writes it as an email the way
+/// `git format-patch --zero-commit` would.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// use mit_commit::{Subject, patch::Patch};
+///
+/// let patch = Patch::try_from(concat!(
+///     "From 5f3c0a1e6d2b4a8f9c7e1d3b5a7c9e0f2d4b6a81 Mon Sep 17 00:00:00 2001\n",
+///     "From: =?UTF-8?q?Bj=C3=B6rn_Ek?= <bjorn@example.com>\n",
+///     "Date: Sat, 13 Jun 2020 19:32:00 +0100\n",
+///     "Subject: [PATCH v2 3/7] Read the config from the\n",
+///     " repository\n",
+///     "\n",
+///     "Users keep their settings with the code.\n",
+///     "\n",
+///     "Signed-off-by: Björn Ek <bjorn@example.com>\n",
+///     "---\n",
+///     "v2: read it from the root of the repository\n",
+///     "\n",
+///     " src/config.rs | 2 +-\n",
+///     "\n",
+///     "diff --git a/src/config.rs b/src/config.rs\n",
+///     "-- \n",
+///     "2.39.0\n",
+/// ))
+/// .expect("the patch is valid");
+///
+/// assert_eq!(
+///     patch.get_from().map(|from| from.get_name()),
+///     Some("Björn Ek")
+/// );
+/// assert_eq!(patch.get_date(), Some("Sat, 13 Jun 2020 19:32:00 +0100"));
+/// assert_eq!(patch.get_subject_prefix(), Some("[PATCH v2 3/7]"));
+/// assert_eq!(patch.get_version(), Some(2));
+/// assert_eq!(patch.get_number(), Some(3));
+/// assert_eq!(patch.get_total(), Some(7));
+/// assert_eq!(
+///     patch.get_message().get_subject(),
+///     Subject::from("Read the config from the repository")
+/// );
+/// assert_eq!(
+///     patch.get_message().get_trailers().iter().count(),
+///     1
+/// );
+/// assert_eq!(
+///     patch.get_notes(),
+///     Some("v2: read it from the root of the repository\n\n src/config.rs | 2 +-\n\n")
+/// );
+/// assert_eq!(
+///     patch.get_diff(),
+///     Some("diff --git a/src/config.rs b/src/config.rs\n")
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Patch<'a> {
+    headers: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+    from: Option<Identity<'a>>,
+    date: Option<Cow<'a, str>>,
+    subject_prefix: Option<Cow<'a, str>>,
+    message: CommitMessage<'a>,
+    notes: Option<Cow<'a, str>>,
+    diff: Option<Cow<'a, str>>,
+}
+
+/// A header line as it was read, before it is decoded
+#[derive(Debug, Clone)]
+struct RawHeader<'a> {
+    key: &'a str,
+    value: Cow<'a, str>,
+    span: Span,
+    value_span: Option<Span>,
+}
+
+impl<'a> Patch<'a> {
+    /// Give you a [`Patch`] that owns all of its text, so it can outlive the
+    /// email it was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`Patch`], with any borrowed text copied
+    #[must_use]
+    pub fn into_owned<'b>(self) -> Patch<'b> {
+        let owned = |text: Cow<'_, str>| Cow::Owned(text.into_owned());
+
+        Patch {
+            headers: self
+                .headers
+                .into_iter()
+                .map(|(key, value)| (owned(key), owned(value)))
+                .collect(),
+            from: self.from.map(Identity::into_owned),
+            date: self.date.map(owned),
+            subject_prefix: self.subject_prefix.map(owned),
+            message: self.message.into_owned(),
+            notes: self.notes.map(owned),
+            diff: self.diff.map(owned),
+        }
+    }
+
+    /// Create a new [`Patch`] to send by email
+    ///
+    /// The patch has no diff, and its subject prefix is `[PATCH]`, until
+    /// they are added with [`Patch::with_diff`], [`Patch::with_version`] and
+    /// [`Patch::with_number`].
+    ///
+    /// # Arguments
+    ///
+    /// * `author` - Who wrote the commit and when, usually from the `author`
+    ///   header of a [`crate::RawCommit`]
+    /// * `message` - The commit message
+    ///
+    /// # Returns
+    ///
+    /// A patch from the author, dated when they wrote the commit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    ///
+    /// use mit_commit::{CommitMessage, Signature, patch::Patch};
+    ///
+    /// let author = Signature::try_from("Billie Thompson <billie@example.com> 1592073120 +0100")
+    ///     .expect("the author is valid");
+    /// let patch = Patch::new(&author, CommitMessage::from("Add a readme\n"));
+    ///
+    /// assert_eq!(
+    ///     patch.get_from().map(|from| from.get_name()),
+    ///     Some("Billie Thompson")
+    /// );
+    /// assert_eq!(patch.get_date(), Some("Sat, 13 Jun 2020 19:32:00 +0100"));
+    /// ```
+    #[must_use]
+    pub fn new(author: &Signature<'a>, message: CommitMessage<'a>) -> Self {
+        Self {
+            headers: vec![],
+            from: Some(author.get_identity().clone()),
+            date: Some(Cow::Owned(format::date(author))),
+            subject_prefix: None,
+            message,
+            notes: None,
+            diff: None,
+        }
+    }
+
+    /// Give you a new [`Patch`] with notes for reviewers, which go after the
+    /// `---` line where they won't become part of the commit
+    ///
+    /// # Arguments
+    ///
+    /// * `notes` - The notes, which can include a diffstat
+    ///
+    /// # Returns
+    ///
+    /// The patch with the notes
+    #[must_use]
+    pub fn with_notes(self, notes: Cow<'a, str>) -> Self {
+        Self {
+            notes: Some(notes),
+            ..self
+        }
+    }
+
+    /// Give you a new [`Patch`] with a diff
+    ///
+    /// # Arguments
+    ///
+    /// * `diff` - The diff, like the output of `git diff`
+    ///
+    /// # Returns
+    ///
+    /// The patch with the diff
+    #[must_use]
+    pub fn with_diff(self, diff: Cow<'a, str>) -> Self {
+        Self {
+            diff: Some(diff),
+            ..self
+        }
+    }
+
+    /// Give you a new [`Patch`] that is a later version of a series, like
+    /// `[PATCH v2]`
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The version of the series
+    ///
+    /// # Returns
+    ///
+    /// The patch with the version in its subject prefix
+    #[must_use]
+    pub fn with_version(self, version: u32) -> Self {
+        let series = self.series();
+
+        Self {
+            subject_prefix: Some(Cow::Owned(format::subject_prefix(Some(version), series))),
+            ..self
+        }
+    }
+
+    /// Give you a new [`Patch`] that is part of a series, like
+    /// `[PATCH 3/7]`
+    ///
+    /// The number is padded with zeros to the width of the total, as
+    /// `git format-patch` does.
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - Where this patch is in the series, starting at 1, or 0
+    ///   for a cover letter
+    /// * `total` - How many patches there are in the series
+    ///
+    /// # Returns
+    ///
+    /// The patch with its number in its subject prefix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    ///
+    /// use mit_commit::{CommitMessage, Signature, patch::Patch};
+    ///
+    /// let author = Signature::try_from("Billie Thompson <billie@example.com> 1592073120 +0100")
+    ///     .expect("the author is valid");
+    /// let patch = Patch::new(&author, CommitMessage::from("Add a readme\n"))
+    ///     .with_version(2)
+    ///     .with_number(3, 12);
+    ///
+    /// assert_eq!(patch.get_subject_prefix(), Some("[PATCH v2 03/12]"));
+    /// ```
+    #[must_use]
+    pub fn with_number(self, number: u32, total: u32) -> Self {
+        let version = self.get_version();
+
+        Self {
+            subject_prefix: Some(Cow::Owned(format::subject_prefix(
+                version,
+                Some((number, total)),
+            ))),
+            ..self
+        }
+    }
+
+    /// Get the value of a header of the email, with encoded words decoded
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The name of the header, in any case, like `message-id`
+    ///
+    /// # Returns
+    ///
+    /// The value of the first header with that name, or None if there isn't
+    /// one
+    #[must_use]
+    pub fn get_header(&self, key: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value.as_ref())
+    }
+
+    /// Get the author of the patch, from the `From` header
+    ///
+    /// A quoted name, like `"Thompson, Billie" <billie@example.com>`, has
+    /// its quotes taken off.
+    #[must_use]
+    pub const fn get_from(&self) -> Option<&Identity<'a>> {
+        self.from.as_ref()
+    }
+
+    /// Get the date the patch was written, as it is in the `Date` header
+    #[must_use]
+    pub fn get_date(&self) -> Option<&str> {
+        self.date.as_deref()
+    }
+
+    /// Get what was taken off the start of the subject, like
+    /// `[PATCH v2 3/7]`
+    #[must_use]
+    pub fn get_subject_prefix(&self) -> Option<&str> {
+        self.subject_prefix.as_deref()
+    }
+
+    /// Get the version of the series, from a `v2` in the subject prefix
+    ///
+    /// # Returns
+    ///
+    /// The version, or None if there isn't one, which means this is the
+    /// first version
+    #[must_use]
+    pub fn get_version(&self) -> Option<u32> {
+        self.prefix_words().find_map(|word| {
+            word.strip_prefix(['v', 'V'])
+                .and_then(|version| version.parse().ok())
+        })
+    }
+
+    /// Get where this patch is in its series, from the `3` in a `3/7` in
+    /// the subject prefix
+    #[must_use]
+    pub fn get_number(&self) -> Option<u32> {
+        self.series().map(|(number, _)| number)
+    }
+
+    /// Get how many patches there are in the series, from the `7` in a `3/7`
+    /// in the subject prefix
+    #[must_use]
+    pub fn get_total(&self) -> Option<u32> {
+        self.series().map(|(_, total)| total)
+    }
+
+    /// Get the commit message in the email
+    ///
+    /// Lines starting with `#` are part of an emailed message, so they are
+    /// read as [`crate::Body`] rather than comments. The spans in the message
+    /// are from the start of the message, not the email.
+    #[must_use]
+    pub const fn get_message(&self) -> &CommitMessage<'a> {
+        &self.message
+    }
+
+    /// Get what is between the `---` line and the diff, like the diffstat
+    /// and any notes for reviewers
+    ///
+    /// # Returns
+    ///
+    /// The notes, or None if there aren't any
+    #[must_use]
+    pub fn get_notes(&self) -> Option<&str> {
+        self.notes.as_deref()
+    }
+
+    /// Get the diff, without the signature at the end of the email
+    #[must_use]
+    pub fn get_diff(&self) -> Option<&str> {
+        self.diff.as_deref()
+    }
+
+    fn prefix_words(&self) -> impl Iterator<Item = &str> {
+        self.subject_prefix
+            .as_deref()
+            .unwrap_or_default()
+            .split(|character: char| character.is_whitespace() || "[]".contains(character))
+            .filter(|word| !word.is_empty())
+    }
+
+    fn series(&self) -> Option<(u32, u32)> {
+        self.prefix_words().find_map(|word| {
+            let (number, total) = word.split_once('/')?;
+
+            Some((number.parse().ok()?, total.parse().ok()?))
+        })
+    }
+
+    fn parse(email: &'a str) -> Result<Self, PatchError> {
+        let (mut headers, body) = read_headers(email)?;
+        let (lines, in_body_headers) = read_in_body_headers(email, body);
+        headers.extend(in_body_headers);
+
+        let mut from = None;
+        let mut date = None;
+        let mut subject = Cow::Borrowed("");
+        let mut decoded = Vec::with_capacity(headers.len());
+
+        // Later headers win, so the ones in the body replace the email's
+        for header in headers {
+            let value = encoded_word::decode(&header.value)?;
+
+            if header.key.eq_ignore_ascii_case("From") {
+                let origin = header
+                    .value_span
+                    .filter(|_| matches!(value, Cow::Borrowed(_)));
+                from = Some(parse_from(&value, origin)?);
+            } else if header.key.eq_ignore_ascii_case("Date") {
+                date = Some(value.clone());
+            } else if header.key.eq_ignore_ascii_case("Subject") {
+                subject.clone_from(&value);
+            } else if header.key.eq_ignore_ascii_case("Content-Transfer-Encoding")
+                && !["7bit", "8bit", "binary"]
+                    .iter()
+                    .any(|known| value.eq_ignore_ascii_case(known))
+            {
+                return Err(PatchError::UnsupportedEncoding(
+                    email.to_string(),
+                    (header.span.get_offset(), header.span.get_length()),
+                ));
+            }
+
+            decoded.push((Cow::Borrowed(header.key), value));
+        }
+
+        let (subject_prefix, subject) = strip_subject_prefix(&subject);
+        let sections = Sections::find(&lines);
+        let message_end = sections
+            .separator
+            .or(sections.diff)
+            .or(sections.signature)
+            .map_or(email.len(), |index| lines[index].0);
+        let message_start = lines.first().map_or(email.len(), |(offset, _)| *offset);
+        // Empty lines around the body aren't part of the message
+        let body = email[message_start..message_end].trim_matches(['\n', '\r']);
+
+        let message = if body.trim().is_empty() {
+            format!("{subject}\n")
+        } else {
+            format!("{subject}\n\n{body}\n")
+        };
+        let line_offset = |index: Option<usize>, fallback: usize| {
+            index.map_or(fallback, |index: usize| lines[index].0)
+        };
+        let end = line_offset(sections.signature, email.len());
+
+        Ok(Self {
+            headers: decoded,
+            from,
+            date,
+            subject_prefix,
+            message: CommitMessage::parse_with(
+                &message,
+                ParseOptions::default().with_comment_marker(CommentMarker::Disabled),
+            )
+            .into_owned(),
+            notes: sections
+                .separator
+                .map(|separator| {
+                    let (offset, line) = lines[separator];
+                    &email[offset + line.len()..line_offset(sections.diff, end)]
+                })
+                .filter(|notes| !notes.is_empty())
+                .map(Cow::Borrowed),
+            diff: sections
+                .diff
+                .map(|diff| Cow::Borrowed(&email[lines[diff].0..end])),
+        })
+    }
+}
+
+/// Where the parts of the body after the commit message start, as indexes
+/// into its lines
+#[derive(Debug, Clone, Copy, Default)]
+struct Sections {
+    separator: Option<usize>,
+    diff: Option<usize>,
+    signature: Option<usize>,
+}
+
+impl Sections {
+    fn find(lines: &[(usize, &str)]) -> Self {
+        let content = |index: usize| lines[index].1.trim_end_matches(['\n', '\r']);
+        let starts_diff = |index: usize| {
+            let line = content(index);
+
+            line.starts_with("diff -")
+                || line.starts_with("Index: ")
+                || (line.starts_with("--- ")
+                    && index + 1 < lines.len()
+                    && content(index + 1).starts_with("+++ "))
+        };
+
+        // The signature is the last `-- ` line, so long as nothing after it
+        // looks like part of a diff
+        let signature = (0..lines.len())
+            .rev()
+            .find(|&index| content(index) == "-- ")
+            .filter(|&signature| {
+                (signature + 1..lines.len())
+                    .all(|index| !content(index).starts_with([' ', '+', '-', '@', '\\']))
+            });
+        let end = signature.unwrap_or(lines.len());
+
+        let first =
+            (0..end).find(|&index| content(index).trim_end() == "---" || starts_diff(index));
+        let separator = first.filter(|&first| !starts_diff(first));
+        let diff = separator.map_or(first, |separator| {
+            (separator + 1..end).find(|&index| starts_diff(index))
+        });
+
+        Self {
+            separator,
+            diff,
+            signature,
+        }
+    }
+}
+
+/// Read the headers at the top of an email, up to the empty line after them
+///
+/// # Returns
+///
+/// The headers, and the offset and line number of the start of the body
+fn read_headers(email: &str) -> Result<(Vec<RawHeader<'_>>, (usize, usize)), PatchError> {
+    let mut headers: Vec<RawHeader<'_>> = vec![];
+    let mut offset = 0;
+
+    for (number, line) in email.split_inclusive('\n').enumerate() {
+        let start = offset;
+        offset += line.len();
+
+        let content = line.trim_end_matches(['\n', '\r']);
+        if number == 0 && is_from_line(content) {
+            continue;
+        }
+        if content.is_empty() {
+            return Ok((headers, (offset, number + 1)));
+        }
+
+        if content.starts_with([' ', '\t']) {
+            let Some(header) = headers.last_mut() else {
+                return Err(PatchError::UnexpectedContinuation(
+                    email.to_string(),
+                    (start, content.len()),
+                ));
+            };
+
+            // Unfolding takes away the line break, but not the whitespace
+            header.value.to_mut().push_str(content.trim_end());
+            header.span = header
+                .span
+                .extend(Span::new(start, content.len(), number, 0));
+            header.value_span = None;
+            continue;
+        }
+
+        let Some(header) = read_header(content, start, number) else {
+            return Err(PatchError::InvalidHeader(
+                email.to_string(),
+                (start, content.len()),
+            ));
+        };
+
+        headers.push(header);
+    }
+
+    Ok((headers, (email.len(), 0)))
+}
+
+/// Read a `Key: value` line
+fn read_header(line: &str, offset: usize, number: usize) -> Option<RawHeader<'_>> {
+    let (key, value) = line.split_once(':')?;
+    if key.is_empty() || key.contains(char::is_whitespace) {
+        return None;
+    }
+
+    let column = key.len() + 1 + (value.len() - value.trim_start().len());
+    let value = value.trim();
+
+    Some(RawHeader {
+        key,
+        value: Cow::Borrowed(value),
+        span: Span::new(offset, line.len(), number, 0),
+        value_span: Some(Span::new(offset + column, value.len(), number, column)),
+    })
+}
+
+/// Split the body into lines, taking off any `From`, `Date` and `Subject`
+/// lines at the top of it
+///
+/// # Returns
+///
+/// The lines after them, with their offsets in the email, and the headers
+fn read_in_body_headers(
+    email: &str,
+    (start, first_line): (usize, usize),
+) -> (Vec<(usize, &str)>, Vec<RawHeader<'_>>) {
+    let mut offset = start;
+    let lines = email[start..]
+        .split_inclusive('\n')
+        .map(|line| {
+            let line_start = offset;
+            offset += line.len();
+            (line_start, line)
+        })
+        .collect::<Vec<_>>();
+
+    let headers = lines
+        .iter()
+        .enumerate()
+        .map_while(|(index, (offset, line))| {
+            read_header(
+                line.trim_end_matches(['\n', '\r']),
+                *offset,
+                first_line + index,
+            )
+        })
+        .take_while(|header| {
+            IN_BODY_HEADERS
+                .iter()
+                .any(|key| header.key.eq_ignore_ascii_case(key))
+        })
+        .collect::<Vec<_>>();
+
+    (lines[headers.len()..].to_vec(), headers)
+}
+
+/// Read the `From` header as an identity, without quotes around the name
+fn parse_from<'a>(value: &Cow<'a, str>, origin: Option<Span>) -> Result<Identity<'a>, PatchError> {
+    let identity = Identity::parse(value, origin)?;
+
+    Ok(
+        match identity
+            .get_name()
+            .strip_prefix('"')
+            .and_then(|name| name.strip_suffix('"'))
+        {
+            Some(name) => Identity::new(
+                unquote(name).into(),
+                identity.get_email().to_string().into(),
+            ),
+            None => identity,
+        },
+    )
+}
+
+/// Undo the `\"` and `\\` escapes in a quoted name
+fn unquote(name: &str) -> String {
+    let mut unquoted = String::with_capacity(name.len());
+    let mut characters = name.chars();
+
+    while let Some(character) = characters.next() {
+        unquoted.extend(match character {
+            '\\' => characters.next(),
+            _ => Some(character),
+        });
+    }
+
+    unquoted
+}
+
+/// Take the `[PATCH v2 3/7]` and any `Re:` off the start of a subject
+///
+/// # Returns
+///
+/// What was taken off, if anything, and the subject without it
+fn strip_subject_prefix<'a>(subject: &Cow<'a, str>) -> (Option<Cow<'a, str>>, Cow<'a, str>) {
+    let mut start = 0;
+
+    loop {
+        let rest = &subject[start..];
+        let trimmed = rest.trim_start();
+        let skipped = rest.len() - trimmed.len();
+
+        if trimmed.starts_with('[')
+            && let Some(end) = trimmed.find(']')
+        {
+            start += skipped + end + 1;
+        } else if trimmed
+            .get(..3)
+            .is_some_and(|reply| reply.eq_ignore_ascii_case("re:"))
+        {
+            start += skipped + 3;
+        } else {
+            start += skipped;
+            break;
+        }
+    }
+
+    let prefix = subject[..start].trim_end().len();
+
+    (
+        (prefix > 0).then(|| span::slice(subject, 0..prefix)),
+        span::slice(subject, start..subject.len()),
+    )
+}
+
+impl<'a> TryFrom<&'a str> for Patch<'a> {
+    type Error = PatchError;
+
+    /// Parse an email written by `git format-patch`
+    ///
+    /// # Errors
+    ///
+    /// If a header isn't valid, an encoded word can't be decoded, the `From`
+    /// header isn't a `Name <email>`, or the body isn't sent as `7bit` or
+    /// `8bit`. The error points at the problem.
+    fn try_from(email: &'a str) -> Result<Self, Self::Error> {
+        Self::parse(email)
+    }
+}
+
+/// Errors in reading a patch sent by email
+#[derive(Error, Debug, Diagnostic)]
+pub enum PatchError {
+    /// The `From` header isn't a `Name <email>`
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Identity(#[from] IdentityError),
+    /// A line in the headers isn't a `Key: value`
+    #[error("the header is not valid")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::patch::error::invalid_header),
+        help("headers look like `Subject: [PATCH] Add a feature`, and end with an empty line")
+    )]
+    InvalidHeader(
+        #[source_code] String,
+        #[label("this line has no `:` after the name of the header")] (usize, usize),
+    ),
+    /// A line starting with whitespace comes before any header
+    #[error("the continuation line has no header to continue")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::patch::error::unexpected_continuation),
+        help("only lines after a header can start with whitespace")
+    )]
+    UnexpectedContinuation(
+        #[source_code] String,
+        #[label("this line starts with whitespace")] (usize, usize),
+    ),
+    /// An encoded word has text that isn't in its encoding
+    #[error("the encoded word could not be decoded")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::patch::error::invalid_encoded_word),
+        help("encoded words look like `=?UTF-8?q?Bj=C3=B6rn?=` or `=?UTF-8?b?QmrDtnJu?=`")
+    )]
+    InvalidEncodedWord(
+        #[source_code] String,
+        #[label("this is not valid")] (usize, usize),
+    ),
+    /// An encoded word is in a charset that can't be read
+    #[error("the charset is not supported")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::patch::error::unsupported_charset),
+        help("only UTF-8, US-ASCII and ISO-8859-1 encoded words can be read")
+    )]
+    UnsupportedCharset(
+        #[source_code] String,
+        #[label("this charset is not supported")] (usize, usize),
+    ),
+    /// The body is encoded, like in `quoted-printable`
+    #[error("the transfer encoding is not supported")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::patch::error::unsupported_encoding),
+        help("send the patch with `git send-email --transfer-encoding=8bit`")
+    )]
+    UnsupportedEncoding(
+        #[source_code] String,
+        #[label("only `7bit` and `8bit` bodies can be read")] (usize, usize),
+    ),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Subject;
+
+    #[test]
+    fn test_subject_prefixes_are_taken_off() {
+        let cases = [
+            ("[PATCH] Add a readme", Some("[PATCH]"), "Add a readme"),
+            (
+                "[RFC][PATCH v3 02/10] Add a readme",
+                Some("[RFC][PATCH v3 02/10]"),
+                "Add a readme",
+            ),
+            (
+                "Re: [PATCH] Add a readme",
+                Some("Re: [PATCH]"),
+                "Add a readme",
+            ),
+            ("Add a [readme]", None, "Add a [readme]"),
+        ];
+
+        for (subject, prefix, expected) in cases {
+            let (actual_prefix, actual) = strip_subject_prefix(&Cow::Borrowed(subject));
+
+            assert_eq!(
+                actual_prefix.as_deref(),
+                prefix,
+                "The prefix of {subject:?} should be taken off"
+            );
+            assert_eq!(actual, expected, "The rest of {subject:?} should be kept");
+        }
+    }
+
+    #[test]
+    fn test_the_series_is_read_from_the_prefix() {
+        let patch = Patch::try_from("Subject: [RFC PATCH v12 02/10] Add a readme\n\n")
+            .expect("the patch is valid");
+
+        assert_eq!(patch.get_version(), Some(12), "The version is after a v");
+        assert_eq!(patch.get_number(), Some(2), "Leading zeros are ignored");
+        assert_eq!(patch.get_total(), Some(10), "The total is after the /");
+    }
+
+    #[test]
+    fn test_in_body_headers_replace_the_email_headers() {
+        let patch = Patch::try_from(concat!(
+            "From: Mailing List <list@example.com>\n",
+            "Subject: [PATCH] Wrong subject\n",
+            "\n",
+            "From: Billie Thompson <billie@example.com>\n",
+            "Subject: Add a readme\n",
+            "\n",
+            "The body\n",
+        ))
+        .expect("the patch is valid");
+
+        assert_eq!(
+            patch.get_from().map(Identity::get_email),
+            Some("billie@example.com"),
+            "The author is in the body"
+        );
+        assert_eq!(
+            patch.get_message().get_subject(),
+            Subject::from("Add a readme"),
+            "The subject is in the body"
+        );
+        assert_eq!(
+            String::from(patch.get_message()),
+            "Add a readme\n\nThe body\n",
+            "The in-body headers aren't part of the message"
+        );
+    }
+
+    #[test]
+    fn test_from_spans_point_into_the_email() {
+        let email = "Subject: Hi\nFrom: Billie Thompson <billie@example.com>\n\n";
+        let patch = Patch::try_from(email).expect("the patch is valid");
+        let span = patch
+            .get_from()
+            .and_then(Identity::get_span)
+            .expect("the from header was parsed");
+
+        assert_eq!(
+            &email[span.get_offset()..span.get_end()],
+            "Billie Thompson <billie@example.com>",
+            "The span should be on the identity in the email"
+        );
+    }
+
+    #[test]
+    fn test_quoted_names_have_their_quotes_taken_off() {
+        let patch = Patch::try_from("From: \"Thompson, Billie\" <billie@example.com>\n\n")
+            .expect("the patch is valid");
+
+        assert_eq!(
+            patch.get_from().map(Identity::get_name),
+            Some("Thompson, Billie"),
+            "The quotes aren't part of the name"
+        );
+    }
+
+    #[test]
+    fn test_a_diff_without_a_separator_ends_the_message() {
+        let patch = Patch::try_from(concat!(
+            "Subject: [PATCH] Add a readme\n",
+            "\n",
+            "The body\n",
+            "--- /dev/null\n",
+            "+++ b/README.md\n",
+            "@@ -0,0 +1 @@\n",
+            "+-- \n",
+        ))
+        .expect("the patch is valid");
+
+        assert_eq!(patch.get_notes(), None, "There is no `---` line");
+        assert_eq!(
+            patch.get_diff(),
+            Some("--- /dev/null\n+++ b/README.md\n@@ -0,0 +1 @@\n+-- \n"),
+            "The diff starts at the file names"
+        );
+        assert_eq!(
+            String::from(patch.get_message()),
+            "Add a readme\n\nThe body\n",
+            "The diff isn't part of the message"
+        );
+    }
+
+    #[test]
+    fn test_errors_point_at_the_problem() {
+        assert!(
+            matches!(
+                Patch::try_from("Subject: Hi\nnonsense\n\n"),
+                Err(PatchError::InvalidHeader(_, (12, 8)))
+            ),
+            "A line without a colon isn't a header"
+        );
+        assert!(
+            matches!(
+                Patch::try_from(" continued\n\n"),
+                Err(PatchError::UnexpectedContinuation(_, (0, 10)))
+            ),
+            "There is no header to continue"
+        );
+        assert!(
+            matches!(
+                Patch::try_from("Subject: Hi\nContent-Transfer-Encoding: base64\n\n"),
+                Err(PatchError::UnsupportedEncoding(_, (12, 33)))
+            ),
+            "Base64 bodies can't be read"
+        );
+        assert!(
+            matches!(
+                Patch::try_from("From: billie@example.com\n\n"),
+                Err(PatchError::Identity(IdentityError::MissingEmail(..)))
+            ),
+            "The author needs a name and email"
+        );
+    }
+}
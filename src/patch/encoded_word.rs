@@ -0,0 +1,361 @@
+use std::{borrow::Cow, fmt::Write, ops::Range};
+
+use super::PatchError;
+
+/// The parts of an RFC 2047 encoded word, like `=?UTF-8?q?Bj=C3=B6rn?=`
+#[derive(Debug, Clone, Copy)]
+struct EncodedWord<'t> {
+    charset: &'t str,
+    encoding: &'t str,
+    text: &'t str,
+}
+
+/// Decode the RFC 2047 encoded words in a header, like
+/// `=?UTF-8?q?Bj=C3=B6rn?=`
+///
+/// Text that only looks a bit like an encoded word is left as it is, as mail
+/// clients do. Whitespace between two encoded words is dropped, so a long
+/// name can be split over several of them.
+///
+/// # Arguments
+///
+/// * `header` - The value of the header, with any folding undone
+///
+/// # Returns
+///
+/// The decoded value, which is still borrowed if there was nothing to decode
+///
+/// # Errors
+///
+/// If an encoded word isn't in UTF-8, US-ASCII or ISO-8859-1, or its text
+/// can't be decoded. The error points at the word in the header.
+pub fn decode<'a>(header: &Cow<'a, str>) -> Result<Cow<'a, str>, PatchError> {
+    if !header.contains("=?") {
+        return Ok(header.clone());
+    }
+
+    let mut decoded = String::with_capacity(header.len());
+    let mut copied = 0;
+    let mut search = 0;
+    let mut after_word = false;
+
+    while let Some(found) = header[search..].find("=?") {
+        let start = search + found;
+        let Some((word, length)) = encoded_word(&header[start..]) else {
+            search = start + 2;
+            continue;
+        };
+
+        let between = &header[copied..start];
+        if !(after_word && between.chars().all(char::is_whitespace)) {
+            decoded.push_str(between);
+        }
+        decoded.push_str(&decode_word(header, start..start + length, word)?);
+
+        copied = start + length;
+        search = copied;
+        after_word = true;
+    }
+
+    decoded.push_str(&header[copied..]);
+
+    Ok(Cow::Owned(decoded))
+}
+
+/// Longest a line of encoded words can be, from RFC 2047
+pub const MAX_LINE_LENGTH: usize = 76;
+
+/// Where encoded words are going in a header, which changes which
+/// characters have to be encoded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Placement {
+    /// The name in an address, like in the `From` header
+    Name,
+    /// Free text, like the `Subject` header
+    Text,
+}
+
+/// Does this text need to be encoded to go in a header
+///
+/// Text that isn't ASCII does, as does text that would be read as an
+/// encoded word when it is decoded.
+pub fn needs_encoding(text: &str) -> bool {
+    !text.is_ascii() || text.contains("=?")
+}
+
+/// Encode text as UTF-8 `Q` encoded words, like `=?UTF-8?q?Bj=C3=B6rn?=`,
+/// the way `git format-patch` does
+///
+/// The words are folded onto new lines so that no line is too long, and a
+/// character is never split between two words. Spaces are encoded as `=20`
+/// rather than `_`, as some mail clients leave the `_` in place.
+///
+/// # Arguments
+///
+/// * `text` - The text to encode
+/// * `placement` - Where in the header the words are going
+/// * `column` - How long the line already is before the first word
+///
+/// # Returns
+///
+/// The encoded words, which decode back to the text
+pub fn encode(text: &str, placement: Placement, column: usize) -> String {
+    const START: &str = "=?UTF-8?q?";
+    const END: &str = "?=";
+
+    let mut encoded = String::from(START);
+    let mut line_length = column + START.len();
+
+    for character in text.chars() {
+        let mut bytes = [0; 4];
+        let part = if is_special(character, placement) {
+            character
+                .encode_utf8(&mut bytes)
+                .bytes()
+                .fold(String::new(), |mut part, byte| {
+                    let _ = write!(part, "={byte:02X}");
+                    part
+                })
+        } else {
+            character.to_string()
+        };
+
+        if line_length + part.len() + END.len() > MAX_LINE_LENGTH {
+            encoded.push_str(END);
+            encoded.push_str("\n ");
+            encoded.push_str(START);
+            line_length = 1 + START.len();
+        }
+
+        encoded.push_str(&part);
+        line_length += part.len();
+    }
+
+    encoded.push_str(END);
+    encoded
+}
+
+/// Does a character have to be encoded in an encoded word, using the same
+/// rules as git
+fn is_special(character: char, placement: Placement) -> bool {
+    if !character.is_ascii_graphic() || " ?=_".contains(character) {
+        return true;
+    }
+
+    placement == Placement::Name
+        && !(character.is_ascii_alphanumeric() || "!*+-/".contains(character))
+}
+
+/// Read the encoded word at the start of some text, and how long it is
+fn encoded_word(text: &str) -> Option<(EncodedWord<'_>, usize)> {
+    let (charset, rest) = text.strip_prefix("=?")?.split_once('?')?;
+    let (encoding, rest) = rest.split_once('?')?;
+    let text = &rest[..rest.find("?=")?];
+
+    if charset.is_empty()
+        || encoding.len() != 1
+        || charset.contains(char::is_whitespace)
+        || text.contains(char::is_whitespace)
+    {
+        return None;
+    }
+
+    Some((
+        EncodedWord {
+            charset,
+            encoding,
+            text,
+        },
+        charset.len() + encoding.len() + text.len() + 6,
+    ))
+}
+
+fn decode_word(
+    header: &str,
+    range: Range<usize>,
+    word: EncodedWord<'_>,
+) -> Result<String, PatchError> {
+    let invalid = || PatchError::InvalidEncodedWord(header.to_string(), (range.start, range.len()));
+    let bytes = match word.encoding {
+        "q" | "Q" => decode_q(word.text),
+        "b" | "B" => decode_base64(word.text),
+        _ => None,
+    }
+    .ok_or_else(invalid)?;
+
+    // A charset can have a language after it, like `UTF-8*en`
+    let charset = word.charset.split('*').next().unwrap_or_default();
+
+    if ["utf-8", "utf8", "us-ascii"]
+        .iter()
+        .any(|known| charset.eq_ignore_ascii_case(known))
+    {
+        String::from_utf8(bytes).map_err(|_| invalid())
+    } else if ["iso-8859-1", "latin1"]
+        .iter()
+        .any(|known| charset.eq_ignore_ascii_case(known))
+    {
+        Ok(bytes.into_iter().map(char::from).collect())
+    } else {
+        Err(PatchError::UnsupportedCharset(
+            header.to_string(),
+            (range.start + 2, word.charset.len()),
+        ))
+    }
+}
+
+/// Decode the `Q` encoding, where `_` is a space and `=C3` is a byte
+fn decode_q(text: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut rest = text.as_bytes();
+
+    while let Some((&byte, after)) = rest.split_first() {
+        rest = after;
+
+        match byte {
+            b'_' => bytes.push(b' '),
+            b'=' => {
+                let hex = std::str::from_utf8(rest.get(..2)?).ok()?;
+                bytes.push(u8::from_str_radix(hex, 16).ok()?);
+                rest = &rest[2..];
+            }
+            _ => bytes.push(byte),
+        }
+    }
+
+    Some(bytes)
+}
+
+/// Decode the `B` encoding, which is base64
+fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(text.len() / 4 * 3);
+    let mut buffer = 0_u32;
+    let mut bits = 0;
+
+    for character in text.trim_end_matches('=').bytes() {
+        let value = match character {
+            b'A'..=b'Z' => character - b'A',
+            b'a'..=b'z' => character - b'a' + 26,
+            b'0'..=b'9' => character - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+
+        buffer = (buffer << 6) | u32::from(value);
+        bits += 6;
+
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push(u8::try_from(buffer >> bits).ok()?);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decoded(header: &str) -> Option<String> {
+        decode(&Cow::Borrowed(header)).ok().map(Cow::into_owned)
+    }
+
+    #[test]
+    fn test_headers_without_encoded_words_are_borrowed() {
+        assert!(
+            matches!(
+                decode(&Cow::Borrowed("Billie Thompson <billie@example.com>")),
+                Ok(Cow::Borrowed(_))
+            ),
+            "There is nothing to decode, so nothing should be copied"
+        );
+    }
+
+    #[test]
+    fn test_q_and_b_words_are_decoded() {
+        assert_eq!(
+            decoded("=?UTF-8?q?Bj=C3=B6rn_Ek?= <bjorn@example.com>"),
+            Some("Björn Ek <bjorn@example.com>".into()),
+            "Q words use `_` for spaces and `=XX` for bytes"
+        );
+        assert_eq!(
+            decoded("=?utf-8?B?QmrDtnJu?= Ek"),
+            Some("Björn Ek".into()),
+            "B words are base64"
+        );
+        assert_eq!(
+            decoded("=?ISO-8859-1?Q?Bj=F6rn?="),
+            Some("Björn".into()),
+            "Latin 1 bytes are characters"
+        );
+    }
+
+    #[test]
+    fn test_whitespace_between_words_is_dropped() {
+        assert_eq!(
+            decoded("[PATCH] =?UTF-8?q?Fix_the_?=\n =?UTF-8?q?caf=C3=A9?= menu"),
+            Some("[PATCH] Fix the café menu".into()),
+            "Only the whitespace between two words should go"
+        );
+    }
+
+    #[test]
+    fn test_things_that_are_not_words_are_left_alone() {
+        assert_eq!(
+            decoded("Is =? a question =?UTF-8?q?with spaces?="),
+            Some("Is =? a question =?UTF-8?q?with spaces?=".into()),
+            "Only well formed words should be decoded"
+        );
+    }
+
+    #[test]
+    fn test_encoded_words_decode_back_to_the_text() {
+        let text = "Björn's café menu, with a subject long enough to need more than one word";
+        let encoded = encode(text, Placement::Text, "Subject: [PATCH] ".len());
+
+        assert!(
+            encoded.lines().all(|line| line.len() <= MAX_LINE_LENGTH),
+            "Every line should be short enough: {encoded:?}"
+        );
+        assert_eq!(
+            decoded(&encoded),
+            Some(text.to_string()),
+            "The words should decode back to the text"
+        );
+    }
+
+    #[test]
+    fn test_names_encode_more_characters_than_text() {
+        assert_eq!(
+            encode("Ek, Björn (the_boss)", Placement::Name, 0),
+            "=?UTF-8?q?Ek=2C=20Bj=C3=B6rn=20=28the=5Fboss=29?=",
+            "Punctuation in a name has to be encoded"
+        );
+        assert_eq!(
+            encode("Fix: the_café (again)?", Placement::Text, 0),
+            "=?UTF-8?q?Fix:=20the=5Fcaf=C3=A9=20(again)=3F?=",
+            "Only spaces, `?`, `=` and `_` have to be encoded in text"
+        );
+    }
+
+    #[test]
+    fn test_errors_point_at_the_word() {
+        assert!(
+            matches!(
+                decode(&Cow::Borrowed("Hi =?UTF-8?b?!!!?=")),
+                Err(PatchError::InvalidEncodedWord(_, (3, 15)))
+            ),
+            "Text that isn't base64 can't be decoded"
+        );
+        assert!(
+            matches!(
+                decode(&Cow::Borrowed("=?KOI8-R?q?abc?=")),
+                Err(PatchError::UnsupportedCharset(_, (2, 6)))
+            ),
+            "The error should point at the charset"
+        );
+    }
+}
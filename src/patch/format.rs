@@ -0,0 +1,319 @@
+use std::fmt::Write;
+
+use super::{
+    Patch,
+    encoded_word::{self, Placement},
+};
+use crate::{CleanupMode, Identity, Signature};
+
+/// What `git format-patch` writes on the first line, when it is asked not
+/// to say which commit the patch is from
+const FROM_LINE: &str = "From 0000000000000000000000000000000000000000 Mon Sep 17 00:00:00 2001";
+
+/// How long a header line should be before it is folded, from RFC 5322
+const MAX_LINE_LENGTH: usize = 78;
+
+/// Characters that have to be quoted in a name, from RFC 5322
+const SPECIALS: &str = "()<>[]:;@\\,.\"";
+
+const DAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// The date of a signature as an RFC 2822 date, like
+/// `Sat, 13 Jun 2020 19:32:00 +0100`, in the signature's own timezone
+pub fn date(signature: &Signature<'_>) -> String {
+    let local = signature.get_timestamp() + i64::from(signature.get_offset_minutes()) * 60;
+    let days = local.div_euclid(86_400);
+    let seconds = local.rem_euclid(86_400);
+    let (year, month, day) = civil_from_days(days);
+    let index = |value: i64| usize::try_from(value).unwrap_or_default();
+
+    format!(
+        "{}, {day} {} {year} {:02}:{:02}:{:02} {}",
+        DAYS[index((days + 4).rem_euclid(7))],
+        MONTHS[index(month - 1)],
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60,
+        signature.get_timezone()
+    )
+}
+
+/// The year, month and day of a number of days since the Unix epoch
+///
+/// This is Howard Hinnant's `civil_from_days`, which works for any day in
+/// the proleptic Gregorian calendar.
+const fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+    let year = year_of_era + era * 400;
+
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+/// The subject prefix `git format-patch` writes, like `[PATCH v2 03/12]`
+pub fn subject_prefix(version: Option<u32>, series: Option<(u32, u32)>) -> String {
+    let mut prefix = String::from("[PATCH");
+
+    if let Some(version) = version {
+        let _ = write!(prefix, " v{version}");
+    }
+    if let Some((number, total)) = series {
+        let width = total.to_string().len();
+        let _ = write!(prefix, " {number:0width$}/{total}");
+    }
+
+    prefix.push(']');
+    prefix
+}
+
+/// The `From` header, with the name encoded or quoted if it needs to be
+///
+/// The address goes on a line of its own if it would make the line too long,
+/// as `git format-patch` does.
+fn from_header(identity: &Identity<'_>) -> String {
+    let name = identity.get_name();
+    let email = identity.get_email();
+    let mut header = String::from("From: ");
+    let max_length = if encoded_word::needs_encoding(name) {
+        header.push_str(&encoded_word::encode(name, Placement::Name, header.len()));
+        encoded_word::MAX_LINE_LENGTH
+    } else if name.contains(|character| SPECIALS.contains(character)) {
+        let _ = write!(
+            header,
+            "\"{}\"",
+            name.replace('\\', "\\\\").replace('"', "\\\"")
+        );
+        MAX_LINE_LENGTH
+    } else {
+        header.push_str(name);
+        MAX_LINE_LENGTH
+    };
+
+    let line_length = header.len() - header.rfind('\n').map_or(0, |newline| newline + 1);
+    if line_length + " <".len() + email.len() + ">".len() > max_length {
+        header.push('\n');
+    }
+
+    let _ = writeln!(header, " <{email}>");
+    header
+}
+
+/// The `Subject` header, folded if it is long, and encoded if it needs to be
+fn subject_header(prefix: &str, subject: &str) -> String {
+    let mut header = format!("Subject: {prefix}");
+
+    if encoded_word::needs_encoding(subject) {
+        header.push(' ');
+        header.push_str(&encoded_word::encode(
+            subject,
+            Placement::Text,
+            header.len(),
+        ));
+        header.push('\n');
+
+        return header;
+    }
+
+    let mut line_length = header.len();
+
+    for word in subject.split(' ') {
+        // Folding only happens between words, so that unfolding the header
+        // puts back exactly one space
+        if !word.is_empty() && line_length > 1 && line_length + 1 + word.len() > MAX_LINE_LENGTH {
+            header.push('\n');
+            line_length = 0;
+        }
+
+        header.push(' ');
+        header.push_str(word);
+        line_length += 1 + word.len();
+    }
+
+    header.push('\n');
+    header
+}
+
+/// Put some text in the body, making sure it ends in a line feed
+fn push_section(email: &mut String, section: &str) {
+    email.push_str(section);
+
+    if !section.ends_with('\n') {
+        email.push('\n');
+    }
+}
+
+fn patch_to_string(patch: &Patch<'_>) -> String {
+    let message = patch.get_message().cleanup(CleanupMode::Strip);
+    let (subject, body) = message.split_once("\n\n").unwrap_or((&message, ""));
+    // A subject over several lines is one line in an email
+    let subject = subject.lines().collect::<Vec<_>>().join(" ");
+
+    let mut email = format!("{FROM_LINE}\n");
+
+    if let Some(from) = patch.get_from() {
+        email.push_str(&from_header(from));
+    }
+    if let Some(date) = patch.get_date() {
+        let _ = writeln!(email, "Date: {date}");
+    }
+    email.push_str(&subject_header(
+        patch.get_subject_prefix().unwrap_or("[PATCH]"),
+        &subject,
+    ));
+
+    let sections = [
+        Some(subject.as_str()),
+        patch.get_from().map(Identity::get_name),
+        Some(body),
+        patch.get_notes(),
+        patch.get_diff(),
+    ];
+    if !sections.iter().flatten().all(|section| section.is_ascii()) {
+        email.push_str("MIME-Version: 1.0\n");
+        email.push_str("Content-Type: text/plain; charset=UTF-8\n");
+        email.push_str("Content-Transfer-Encoding: 8bit\n");
+    }
+
+    email.push('\n');
+    email.push_str(body);
+    email.push_str("---\n");
+
+    for section in [patch.get_notes(), patch.get_diff()].into_iter().flatten() {
+        push_section(&mut email, section);
+    }
+
+    email
+}
+
+impl From<Patch<'_>> for String {
+    /// The patch as an email, the way `git format-patch --no-signature`
+    /// writes it
+    fn from(patch: Patch<'_>) -> Self {
+        patch_to_string(&patch)
+    }
+}
+
+impl From<&Patch<'_>> for String {
+    /// The patch as an email, the way `git format-patch --no-signature`
+    /// writes it
+    fn from(patch: &Patch<'_>) -> Self {
+        patch_to_string(patch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::*;
+
+    #[test]
+    fn test_dates_are_in_the_timezone_of_the_signature() {
+        let cases = [
+            ("0 +0000", "Thu, 1 Jan 1970 00:00:00 +0000"),
+            ("1592073120 +0100", "Sat, 13 Jun 2020 19:32:00 +0100"),
+            ("1592073120 -0530", "Sat, 13 Jun 2020 13:02:00 -0530"),
+            ("951782400 +0000", "Tue, 29 Feb 2000 00:00:00 +0000"),
+            ("0 -0100", "Wed, 31 Dec 1969 23:00:00 -0100"),
+        ];
+
+        for (time, expected) in cases {
+            let signature =
+                Signature::try_from(format!("Billie <billie@example.com> {time}").as_str())
+                    .map(|signature| date(&signature))
+                    .ok();
+
+            assert_eq!(
+                signature.as_deref(),
+                Some(expected),
+                "{time} should be written as a date"
+            );
+        }
+    }
+
+    #[test]
+    fn test_subject_prefixes_match_git() {
+        assert_eq!(subject_prefix(None, None), "[PATCH]", "A single patch");
+        assert_eq!(
+            subject_prefix(Some(2), Some((3, 12))),
+            "[PATCH v2 03/12]",
+            "The number is padded to the width of the total"
+        );
+    }
+
+    #[test]
+    fn test_long_subjects_are_folded_between_words() {
+        let subject =
+            "Read the configuration from the root of the repository rather than the home directory";
+        let header = subject_header("[PATCH]", subject);
+
+        assert!(
+            header.lines().all(|line| line.len() <= MAX_LINE_LENGTH),
+            "No line should be too long: {header:?}"
+        );
+        assert_eq!(
+            header.replace("\n ", " "),
+            format!("Subject: [PATCH] {subject}\n"),
+            "Unfolding should give the subject back"
+        );
+    }
+
+    #[test]
+    fn test_names_with_specials_are_quoted() {
+        assert_eq!(
+            from_header(&Identity::new(
+                "Thompson, \"Billie\"".into(),
+                "billie@example.com".into()
+            )),
+            "From: \"Thompson, \\\"Billie\\\"\" <billie@example.com>\n",
+            "The comma and quotes need quoting"
+        );
+        assert_eq!(
+            from_header(&Identity::new(
+                "Björn Ek".into(),
+                "bjorn@example.com".into()
+            )),
+            "From: =?UTF-8?q?Bj=C3=B6rn=20Ek?= <bjorn@example.com>\n",
+            "Names that aren't ASCII are encoded"
+        );
+    }
+
+    #[test]
+    fn test_mime_headers_are_written_for_a_subject_or_name_that_is_not_ascii() {
+        let cases = [
+            (
+                "Björn Ek <bjorn@example.com> 1592073120 +0100",
+                "Add a menu\n",
+            ),
+            (
+                "Billie <billie@example.com> 1592073120 +0100",
+                "Add a café\n",
+            ),
+        ];
+
+        for (author, message) in cases {
+            let email = Signature::try_from(author)
+                .map(|author| String::from(Patch::new(&author, message.into())))
+                .unwrap_or_default();
+
+            assert!(
+                email.contains("\nContent-Type: text/plain; charset=UTF-8\n"),
+                "{author} and {message:?} need the charset: {email}"
+            );
+        }
+    }
+}
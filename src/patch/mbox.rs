@@ -0,0 +1,187 @@
+use std::{convert::TryFrom, slice::Iter, vec::IntoIter};
+
+use super::{Patch, PatchError};
+
+/// A file of patches, as written by `git format-patch --stdout`
+///
+/// Each email starts with a `From ` line with a date at the end of it, like
+/// `From 5f3c0a1e Mon Sep 17 00:00:00 2001`, after an empty line. Lines that
+/// start with `From ` anywhere else, like in the middle of a commit message,
+/// don't start a new email. A file with a single email doesn't need a `From `
+/// line at all.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// use mit_commit::patch::Mbox;
+///
+/// let mbox = Mbox::try_from(concat!(
+///     "From 5f3c0a1e6d2b4a8f9c7e1d3b5a7c9e0f2d4b6a81 Mon Sep 17 00:00:00 2001\n",
+///     "Subject: [PATCH 1/2] Add a readme\n",
+///     "\n",
+///     "From now on there is a readme.\n",
+///     "\n",
+///     "From 0e8d6c4b2a0f8e6d4c2b0a9f7e5d3c1b9a7f5e3d Mon Sep 17 00:00:00 2001\n",
+///     "Subject: [PATCH 2/2] Fix the typo\n",
+///     "\n",
+/// ))
+/// .expect("the patches are valid");
+///
+/// assert_eq!(
+///     mbox.iter()
+///         .map(|patch| patch.get_message().get_subject().to_string())
+///         .collect::<Vec<_>>(),
+///     vec!["Add a readme", "Fix the typo"]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Mbox<'a> {
+    patches: Vec<Patch<'a>>,
+}
+
+impl<'a> Mbox<'a> {
+    /// Give you an [`Mbox`] that owns all of its text, so it can outlive the
+    /// file it was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`Mbox`], with any borrowed text copied
+    #[must_use]
+    pub fn into_owned(self) -> Mbox<'static> {
+        Mbox {
+            patches: self.patches.into_iter().map(Patch::into_owned).collect(),
+        }
+    }
+
+    /// Get the patches, in the order they are in the file
+    #[must_use]
+    pub fn get_patches(&self) -> &[Patch<'a>] {
+        &self.patches
+    }
+
+    /// Iterate over the [`Patch`] in the [`Mbox`]
+    pub fn iter(&self) -> Iter<'_, Patch<'a>> {
+        self.patches.iter()
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Mbox<'a> {
+    type Error = PatchError;
+
+    /// Split a file into emails, and parse each of them
+    ///
+    /// # Errors
+    ///
+    /// If any of the emails can't be parsed. The error points at the problem
+    /// in that email, rather than in the whole file.
+    fn try_from(mbox: &'a str) -> Result<Self, Self::Error> {
+        let mut starts = vec![0];
+        let mut offset = 0;
+        let mut after_empty_line = true;
+
+        for line in mbox.split_inclusive('\n') {
+            let content = line.trim_end_matches(['\n', '\r']);
+
+            if offset > 0 && after_empty_line && is_from_line(content) {
+                starts.push(offset);
+            }
+
+            after_empty_line = content.is_empty();
+            offset += line.len();
+        }
+
+        starts.push(mbox.len());
+
+        let patches = starts
+            .windows(2)
+            .map(|email| &mbox[email[0]..email[1]])
+            .filter(|email| !email.trim().is_empty())
+            .map(Patch::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { patches })
+    }
+}
+
+impl<'a> IntoIterator for Mbox<'a> {
+    type Item = Patch<'a>;
+    type IntoIter = IntoIter<Patch<'a>>;
+
+    /// Iterate over the [`Patch`] in the [`Mbox`]
+    fn into_iter(self) -> Self::IntoIter {
+        self.patches.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Mbox<'a> {
+    type Item = &'a Patch<'a>;
+    type IntoIter = Iter<'a, Patch<'a>>;
+
+    /// Iterate over the [`Patch`] in the [`Mbox`]
+    fn into_iter(self) -> Self::IntoIter {
+        self.patches.iter()
+    }
+}
+
+impl<'a> From<Vec<Patch<'a>>> for Mbox<'a> {
+    fn from(patches: Vec<Patch<'a>>) -> Self {
+        Self { patches }
+    }
+}
+
+/// Is this the line that starts an email in an mbox, like
+/// `From 5f3c0a1e Mon Sep 17 00:00:00 2001`
+///
+/// The sender and a date ending in a year have to be there, so that a
+/// sentence starting with "From" isn't mistaken for one.
+pub fn is_from_line(line: &str) -> bool {
+    line.strip_prefix("From ").is_some_and(|rest| {
+        let words = rest.split_whitespace().collect::<Vec<_>>();
+
+        words.len() >= 3
+            && words.last().is_some_and(|year| {
+                year.len() == 4 && year.bytes().all(|byte| byte.is_ascii_digit())
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_lines_need_a_date() {
+        assert!(
+            is_from_line("From 5f3c0a1e Mon Sep 17 00:00:00 2001"),
+            "This is what format-patch writes"
+        );
+        assert!(
+            is_from_line("From billie@example.com Sat Jun 13 19:32:00 2020"),
+            "Mail clients put the sender there"
+        );
+        assert!(
+            !is_from_line("From now on there is a readme"),
+            "A sentence isn't a from line"
+        );
+        assert!(!is_from_line("From: Billie"), "A header isn't a from line");
+    }
+
+    #[test]
+    fn test_an_email_without_a_from_line_is_one_patch() {
+        let mbox =
+            Mbox::try_from("Subject: [PATCH] Add a readme\n\nBody\n").expect("the patch is valid");
+
+        assert_eq!(mbox.get_patches().len(), 1, "There is one email");
+    }
+
+    #[test]
+    fn test_an_empty_file_has_no_patches() {
+        assert_eq!(
+            Mbox::try_from("\n").ok(),
+            Some(Mbox::default()),
+            "There are no emails"
+        );
+    }
+}
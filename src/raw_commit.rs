@@ -0,0 +1,404 @@
+use std::{borrow::Cow, convert::TryFrom};
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::{CommentMarker, CommitHeader, CommitMessage, ParseOptions, Span};
+
+/// A raw git commit object, as printed by `git cat-file commit`
+///
+/// The headers are kept in the order they appear in, and turning a
+/// [`RawCommit`] back into a [`String`] gives exactly the text it was parsed
+/// from, so the object hash is the same.
+///
+/// Only UTF-8 objects can be read. An object with an `encoding` header needs
+/// decoding first, and is then no longer byte for byte the same.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// use mit_commit::{RawCommit, Subject};
+///
+/// let object = concat!(
+///     "tree 4b825dc642cb6eb9a060e54bf8d69288fbee4904\n",
+///     "parent 1f2c3a9d6e0b7c8a9f0e1d2c3b4a5968778695a4\n",
+///     "author Billie Thompson <billie@example.com> 1592073120 +0100\n",
+///     "committer Billie Thompson <billie@example.com> 1592073120 +0100\n",
+///     "gpgsig -----BEGIN SSH SIGNATURE-----\n",
+///     " U1NIU0lHAAAAAQAAADMAAAALc3NoLWVkMjU1MTkAAAAg\n",
+///     " -----END SSH SIGNATURE-----\n",
+///     "\n",
+///     "Add a feature\n",
+///     "\n",
+///     "# Stored commits have no comments\n",
+/// );
+/// let commit = RawCommit::try_from(object).expect("the object is valid");
+///
+/// assert_eq!(
+///     commit.get_tree(),
+///     Some("4b825dc642cb6eb9a060e54bf8d69288fbee4904")
+/// );
+/// assert_eq!(
+///     commit.get_parents(),
+///     vec!["1f2c3a9d6e0b7c8a9f0e1d2c3b4a5968778695a4"]
+/// );
+/// assert_eq!(
+///     commit.get_gpgsig(),
+///     Some(
+///         "-----BEGIN SSH SIGNATURE-----\nU1NIU0lHAAAAAQAAADMAAAALc3NoLWVkMjU1MTkAAAAg\n-----END SSH SIGNATURE-----"
+///     )
+/// );
+/// assert_eq!(commit.get_message().get_subject(), Subject::from("Add a feature"));
+/// assert_eq!(commit.get_message().get_comments().iter().count(), 0);
+/// assert_eq!(String::from(&commit), object);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawCommit<'a> {
+    headers: Vec<CommitHeader<'a>>,
+    message: Option<Cow<'a, str>>,
+}
+
+impl<'a> RawCommit<'a> {
+    /// Give you a [`RawCommit`] that owns all of its text, so it can outlive
+    /// the object it was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`RawCommit`], with any borrowed text copied
+    #[must_use]
+    pub fn into_owned(self) -> RawCommit<'static> {
+        RawCommit {
+            headers: self
+                .headers
+                .into_iter()
+                .map(CommitHeader::into_owned)
+                .collect(),
+            message: self.message.map(|message| Cow::Owned(message.into_owned())),
+        }
+    }
+
+    /// Get every header, in the order they appear in the object
+    #[must_use]
+    pub fn get_headers(&self) -> &[CommitHeader<'a>] {
+        &self.headers
+    }
+
+    /// Get the first header with the given name
+    ///
+    /// Names are compared exactly, as git writes them in lower case.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The name of the header, like `author`
+    ///
+    /// # Returns
+    ///
+    /// The first header with that name, or None if there isn't one
+    #[must_use]
+    pub fn get_header(&self, key: &str) -> Option<&CommitHeader<'a>> {
+        self.headers.iter().find(|header| header.get_key() == key)
+    }
+
+    /// Get every header with the given name, like all the `parent` headers
+    /// of a merge
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The name of the headers
+    ///
+    /// # Returns
+    ///
+    /// The headers with that name, in the order they appear in the object
+    #[must_use]
+    pub fn get_all_headers(&self, key: &str) -> Vec<&CommitHeader<'a>> {
+        self.headers
+            .iter()
+            .filter(|header| header.get_key() == key)
+            .collect()
+    }
+
+    /// Get the hash of the tree this commit records
+    #[must_use]
+    pub fn get_tree(&self) -> Option<&str> {
+        self.get_value("tree")
+    }
+
+    /// Get the hashes of the parents, of which a merge has more than one
+    #[must_use]
+    pub fn get_parents(&self) -> Vec<&str> {
+        self.get_all_headers("parent")
+            .into_iter()
+            .map(CommitHeader::get_value)
+            .collect()
+    }
+
+    /// Get the author line, with the name, email and date
+    #[must_use]
+    pub fn get_author(&self) -> Option<&str> {
+        self.get_value("author")
+    }
+
+    /// Get the committer line, with the name, email and date
+    #[must_use]
+    pub fn get_committer(&self) -> Option<&str> {
+        self.get_value("committer")
+    }
+
+    /// Get the encoding of the message, if it isn't UTF-8
+    #[must_use]
+    pub fn get_encoding(&self) -> Option<&str> {
+        self.get_value("encoding")
+    }
+
+    /// Get the signature of the commit, if it is signed
+    #[must_use]
+    pub fn get_gpgsig(&self) -> Option<&str> {
+        self.get_value("gpgsig")
+    }
+
+    /// Get the signed tags merged by this commit, from `git merge` of a
+    /// signed tag
+    #[must_use]
+    pub fn get_mergetags(&self) -> Vec<&str> {
+        self.get_all_headers("mergetag")
+            .into_iter()
+            .map(CommitHeader::get_value)
+            .collect()
+    }
+
+    /// Get the message after the headers as a [`CommitMessage`]
+    ///
+    /// Lines starting with `#` are part of a stored message, so they are
+    /// read as [`crate::Body`] rather than comments. The spans in the
+    /// message are from the start of the message, not the object.
+    ///
+    /// # Returns
+    ///
+    /// The message, which is empty if the object has none
+    #[must_use]
+    pub fn get_message(&self) -> CommitMessage<'_> {
+        CommitMessage::parse_with(
+            self.message.as_deref().unwrap_or_default(),
+            ParseOptions::default().with_comment_marker(CommentMarker::Disabled),
+        )
+    }
+
+    fn get_value(&self, key: &str) -> Option<&str> {
+        self.get_header(key).map(CommitHeader::get_value)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for RawCommit<'a> {
+    type Error = Error;
+
+    /// Parse the text of a commit object
+    ///
+    /// # Errors
+    ///
+    /// If a header has no space after its name, a continuation line comes
+    /// before any header, or the headers aren't ended with a line feed. The
+    /// error points at the problem in the object.
+    fn try_from(object: &'a str) -> Result<Self, Self::Error> {
+        let mut headers: Vec<CommitHeader<'a>> = Vec::new();
+        let mut offset = 0;
+
+        for (number, line) in object.split_inclusive('\n').enumerate() {
+            let start = offset;
+            offset += line.len();
+
+            let Some(content) = line.strip_suffix('\n') else {
+                return Err(Error::Unterminated(object.to_string(), (start, line.len())));
+            };
+
+            // An empty line ends the headers, and everything after it is
+            // the message
+            if content.is_empty() {
+                return Ok(Self {
+                    headers,
+                    message: Some(object[offset..].into()),
+                });
+            }
+
+            let span = Span::new(start, content.len(), number, 0);
+
+            if let Some(continuation) = content.strip_prefix(' ') {
+                let Some(header) = headers.last_mut() else {
+                    return Err(Error::UnexpectedContinuation(
+                        object.to_string(),
+                        (start, content.len()),
+                    ));
+                };
+
+                header.continue_with(continuation, span);
+                continue;
+            }
+
+            let Some((key, value)) = content.split_once(' ') else {
+                return Err(Error::MissingValue(
+                    object.to_string(),
+                    (start, content.len()),
+                ));
+            };
+
+            headers.push(CommitHeader::new(key.into(), value.into()).with_span(span));
+        }
+
+        Ok(Self {
+            headers,
+            message: None,
+        })
+    }
+}
+
+fn raw_commit_to_string(commit: &RawCommit<'_>) -> String {
+    let headers = commit
+        .headers
+        .iter()
+        .fold(String::new(), |mut headers, header| {
+            headers.push_str(&String::from(header.clone()));
+            headers.push('\n');
+            headers
+        });
+
+    match &commit.message {
+        Some(message) => format!("{headers}\n{message}"),
+        None => headers,
+    }
+}
+
+impl From<RawCommit<'_>> for String {
+    fn from(commit: RawCommit<'_>) -> Self {
+        raw_commit_to_string(&commit)
+    }
+}
+
+impl From<&RawCommit<'_>> for String {
+    fn from(commit: &RawCommit<'_>) -> Self {
+        raw_commit_to_string(commit)
+    }
+}
+
+/// Errors in reading a raw commit object
+#[derive(Error, Debug, Diagnostic)]
+pub enum Error {
+    /// A header line has a name but no value
+    #[error("the header has no value")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::raw_commit::error::missing_value),
+        help("separate the name of the header from its value with a space, like `tree <hash>`")
+    )]
+    MissingValue(
+        #[source_code] String,
+        #[label("this header has no space after its name")] (usize, usize),
+    ),
+    /// A line starting with a space comes before any header
+    #[error("the continuation line has no header to continue")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::raw_commit::error::unexpected_continuation),
+        help("only lines after a header can start with a space")
+    )]
+    UnexpectedContinuation(
+        #[source_code] String,
+        #[label("this line starts with a space")] (usize, usize),
+    ),
+    /// The object ends part way through a header
+    #[error("the header has no line feed after it")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::raw_commit::error::unterminated),
+        help("end every header with a line feed, and the headers with an empty line")
+    )]
+    Unterminated(
+        #[source_code] String,
+        #[label("this header is not ended")] (usize, usize),
+    ),
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::TestResult;
+
+    use super::*;
+
+    #[test]
+    fn test_headers_without_a_value_are_errors() {
+        let error = RawCommit::try_from("tree abc\nnonsense\n\nSubject\n")
+            .expect_err("the second header has no value");
+
+        assert!(
+            matches!(error, Error::MissingValue(_, (9, 8))),
+            "The error should point at the header, got {error:?}"
+        );
+    }
+
+    #[test]
+    fn test_continuations_need_a_header() {
+        let error = RawCommit::try_from(" continued\n\nSubject\n")
+            .expect_err("there is no header to continue");
+
+        assert!(
+            matches!(error, Error::UnexpectedContinuation(_, (0, 10))),
+            "The error should point at the line, got {error:?}"
+        );
+    }
+
+    #[test]
+    fn test_headers_need_a_line_feed() {
+        let error =
+            RawCommit::try_from("tree abc\nparent def").expect_err("the last header isn't ended");
+
+        assert!(
+            matches!(error, Error::Unterminated(_, (9, 10))),
+            "The error should point at the header, got {error:?}"
+        );
+    }
+
+    #[test]
+    fn test_headers_without_a_message() {
+        let commit = RawCommit::try_from("tree abc\n").expect("the headers are valid");
+
+        assert_eq!(commit.message, None, "There is no message");
+        assert_eq!(
+            String::from(&commit),
+            "tree abc\n",
+            "No empty line should be added"
+        );
+    }
+
+    #[test]
+    fn test_continuation_spans_cover_every_line() {
+        let commit =
+            RawCommit::try_from("tree abc\ngpgsig one\n \n two\n\n").expect("the object is valid");
+
+        assert_eq!(
+            commit.get_headers()[1].get_span(),
+            Some(Span::new(9, 17, 1, 0)),
+            "The span should run to the end of the last continuation line"
+        );
+        assert_eq!(
+            commit.get_gpgsig(),
+            Some("one\n\ntwo"),
+            "Lines with only a space are empty lines of the value"
+        );
+    }
+
+    #[allow(clippy::needless_pass_by_value)]
+    #[quickcheck]
+    fn test_parsed_objects_are_byte_identical(lines: Vec<String>, message: String) -> TestResult {
+        let headers: String = lines
+            .iter()
+            .filter(|line| !line.contains('\n'))
+            .flat_map(|line| [line.as_str(), "\n"])
+            .collect();
+        let object = format!("{headers}\n{message}");
+
+        RawCommit::try_from(object.as_str()).map_or_else(
+            |_| TestResult::discard(),
+            |commit| TestResult::from_bool(String::from(&commit) == object),
+        )
+    }
+}
@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use crate::Comment;
+use crate::{Comment, diff::Diff};
 
 const SCISSORS_MARKER: &str = "------------------------ >8 ------------------------";
 
@@ -9,11 +9,62 @@ const SCISSORS_MARKER: &str = "------------------------ >8 ---------------------
 /// Represents the scissors section of a commit message, which separates the commit message
 /// from the diff or other content that should not be included in the commit message.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Scissors<'a> {
     scissors: Cow<'a, str>,
 }
 
 impl<'a> Scissors<'a> {
+    /// Give you a [`Scissors`] that owns its text, so it can outlive the message it
+    /// was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`Scissors`], with any borrowed text copied
+    #[must_use]
+    pub fn into_owned(self) -> Scissors<'static> {
+        Scissors {
+            scissors: Cow::Owned(self.scissors.into_owned()),
+        }
+    }
+
+    /// Read the diff `git commit --verbose` puts after the scissors line
+    ///
+    /// # Returns
+    ///
+    /// The files changed, which is empty if there is no diff
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{CommitMessage, diff::FileStatus};
+    ///
+    /// let commit = CommitMessage::from(concat!(
+    ///     "Make the script runnable\n",
+    ///     "\n",
+    ///     "# ------------------------ >8 ------------------------\n",
+    ///     "# Do not modify or remove the line above.\n",
+    ///     "# Everything below it will be ignored.\n",
+    ///     "diff --git a/run.sh b/run.sh\n",
+    ///     "old mode 100644\n",
+    ///     "new mode 100755\n",
+    /// ));
+    /// let scissors = commit.get_scissors().expect("there is a scissors line");
+    /// let diff = scissors.diff();
+    ///
+    /// assert_eq!(diff.get_files()[0].get_path(), "run.sh");
+    /// assert_eq!(diff.get_files()[0].get_status(), FileStatus::Modified);
+    /// assert_eq!(diff.get_files()[0].get_new_mode(), Some("100755"));
+    /// ```
+    #[must_use]
+    pub fn diff(&self) -> Diff<'_> {
+        Diff::from(self.scissors.as_ref())
+    }
+
     /// Attempts to guess the comment character used in a commit message.
     ///
     /// # Arguments
@@ -24,8 +75,32 @@ impl<'a> Scissors<'a> {
     ///
     /// The comment character if one can be determined, or None if no comment character is found
     pub(crate) fn guess_comment_character(message: &str) -> Option<char> {
-        Self::guess_comment_char_from_scissors(message)
-            .or_else(|| Self::guess_comment_char_from_last_possibility(message))
+        let (body, scissors) = Scissors::parse_sections(message);
+
+        Scissors::guess_comment_character_from_sections(&body, scissors.as_ref())
+    }
+
+    /// Attempts to guess the comment character from a message that has
+    /// already been split by [`Scissors::parse_sections`].
+    ///
+    /// Only the scissors lines themselves and the body are looked at, so a
+    /// large diff after the scissors isn't read.
+    ///
+    /// # Arguments
+    ///
+    /// * `body` - The part of the message before the scissors
+    /// * `scissors` - The scissors section, if there is one
+    ///
+    /// # Returns
+    ///
+    /// The comment character if one can be determined, or None if no comment character is found
+    pub(crate) fn guess_comment_character_from_sections(
+        body: &str,
+        scissors: Option<&Scissors<'_>>,
+    ) -> Option<char> {
+        scissors
+            .and_then(Scissors::guess_comment_char_from_scissors)
+            .or_else(|| Self::guess_comment_char_from_last_possibility(body))
     }
 
     /// Attempts to guess the comment character by looking at the first character of each line.
@@ -48,18 +123,17 @@ impl<'a> Scissors<'a> {
             .next_back()
     }
 
-    /// Attempts to guess the comment character by looking for scissors markers.
-    ///
-    /// # Arguments
-    ///
-    /// * `message` - The commit message to analyze
+    /// Attempts to guess the comment character from the scissors lines at
+    /// the start of the section.
     ///
     /// # Returns
     ///
-    /// The comment character from the scissors line, or None if no scissors line is found
-    fn guess_comment_char_from_scissors(message: &str) -> Option<char> {
-        message
+    /// The comment character from the last of the scissors lines, or None if none of them have
+    /// one
+    fn guess_comment_char_from_scissors(&self) -> Option<char> {
+        self.scissors
             .lines()
+            .take_while(|line| line.ends_with(SCISSORS_MARKER))
             .filter_map(|line| {
                 let mut line_chars = line.chars();
                 let first_character = line_chars.next();
@@ -72,7 +146,7 @@ impl<'a> Scissors<'a> {
 
                 first_character
             })
-            .next_back()
+            .last()
     }
 
     /// Parses a commit message into body and scissors sections.
@@ -84,26 +158,39 @@ impl<'a> Scissors<'a> {
     /// # Returns
     ///
     /// A tuple containing the body of the commit message and an optional scissors section
-    pub(crate) fn parse_sections(message: &str) -> (Cow<'a, str>, Option<Self>) {
-        message
+    pub(crate) fn parse_sections(message: &'a str) -> (Cow<'a, str>, Option<Self>) {
+        let Some(scissors_position) = message
             .lines()
             .position(|line| line.ends_with(SCISSORS_MARKER))
-            .map_or_else(
-                || (message.to_string().into(), None),
-                |scissors_position| {
-                    let lines: Vec<&str> = message.lines().collect();
-                    let body = lines[..scissors_position].join("\n");
-                    let scissors_string = lines[scissors_position..].join("\n");
-
-                    let scissors = if message.ends_with('\n') {
-                        Self::from(format!("{scissors_string}\n"))
-                    } else {
-                        Self::from(scissors_string)
-                    };
-
-                    (body.into(), Some(scissors))
-                },
-            )
+        else {
+            return (message.into(), None);
+        };
+        let start = message
+            .split_inclusive('\n')
+            .take(scissors_position)
+            .map(str::len)
+            .sum::<usize>();
+
+        // Without carriage returns, both sections are slices of the message.
+        // Only the body is checked, so a large diff isn't searched.
+        if !message[..start].contains('\r') {
+            return (
+                message[..start.saturating_sub(1)].into(),
+                Some(Self::from(&message[start..])),
+            );
+        }
+
+        let lines: Vec<&str> = message.lines().collect();
+        let body = lines[..scissors_position].join("\n");
+        let scissors_string = lines[scissors_position..].join("\n");
+
+        let scissors = if message.ends_with('\n') {
+            Self::from(format!("{scissors_string}\n"))
+        } else {
+            Self::from(scissors_string)
+        };
+
+        (body.into(), Some(scissors))
     }
 }
 
@@ -135,6 +222,12 @@ impl<'a> From<Scissors<'a>> for String {
     }
 }
 
+impl<'a> From<Scissors<'a>> for Cow<'a, str> {
+    fn from(scissors: Scissors<'a>) -> Self {
+        scissors.scissors
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,311 @@
+use std::{borrow::Cow, convert::TryFrom, ops::Range};
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::{CommitHeader, Identity, IdentityError, Span, identity::trimmed, span};
+
+/// Who made a commit and when, as in the `author` and `committer` headers
+///
+/// This is a `Name <email>` followed by the seconds since the Unix epoch and
+/// the timezone offset, like `Billie Thompson <billie@example.com>
+/// 1592073120 +0100`.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// use mit_commit::{RawCommit, Signature};
+///
+/// let commit = RawCommit::try_from(concat!(
+///     "tree 4b825dc642cb6eb9a060e54bf8d69288fbee4904\n",
+///     "author Billie Thompson <billie@example.com> 1592073120 +0100\n",
+///     "committer Billie Thompson <billie@example.com> 1592073180 -0530\n",
+///     "\n",
+///     "Subject\n",
+/// ))
+/// .expect("the object is valid");
+/// let author = commit
+///     .get_header("author")
+///     .map(Signature::try_from)
+///     .expect("there is an author")
+///     .expect("the author is valid");
+///
+/// assert_eq!(author.get_identity().get_name(), "Billie Thompson");
+/// assert_eq!(author.get_timestamp(), 1_592_073_120);
+/// assert_eq!(author.get_timezone(), "+0100");
+/// assert_eq!(author.get_offset_minutes(), 60);
+/// assert_eq!(author.get_span().map(|span| span.get_offset()), Some(53));
+/// ```
+#[derive(Debug, Clone, Eq)]
+pub struct Signature<'a> {
+    identity: Identity<'a>,
+    timestamp: i64,
+    timezone: Cow<'a, str>,
+    span: Option<Span>,
+}
+
+impl<'a> Signature<'a> {
+    /// Give you a [`Signature`] that owns its text, so it can outlive the
+    /// object it was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`Signature`], with any borrowed text copied
+    #[must_use]
+    pub fn into_owned(self) -> Signature<'static> {
+        Signature {
+            identity: self.identity.into_owned(),
+            timestamp: self.timestamp,
+            timezone: Cow::Owned(self.timezone.into_owned()),
+            span: self.span,
+        }
+    }
+
+    /// Get the name and email
+    #[must_use]
+    pub const fn get_identity(&self) -> &Identity<'a> {
+        &self.identity
+    }
+
+    /// Get the time, in seconds since the Unix epoch
+    #[must_use]
+    pub const fn get_timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    /// Get the timezone as it was written, like `+0100`
+    #[must_use]
+    pub fn get_timezone(&self) -> &str {
+        &self.timezone
+    }
+
+    /// Get the timezone as minutes ahead of UTC
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    ///
+    /// use mit_commit::Signature;
+    ///
+    /// let signature = Signature::try_from("Billie Thompson <billie@example.com> 1592073120 -0530")
+    ///     .expect("the signature is valid");
+    ///
+    /// assert_eq!(signature.get_offset_minutes(), -330);
+    /// ```
+    #[must_use]
+    pub fn get_offset_minutes(&self) -> i32 {
+        let (sign, digits) = self.timezone.split_at(1);
+        let hours: i32 = digits[..2].parse().unwrap_or_default();
+        let minutes: i32 = digits[2..].parse().unwrap_or_default();
+        let offset = hours * 60 + minutes;
+
+        if sign == "-" { -offset } else { offset }
+    }
+
+    /// Where this [`Signature`] came from in the text it was parsed from
+    ///
+    /// For a signature from a [`CommitHeader`] this is in the object the
+    /// header was parsed from.
+    ///
+    /// # Returns
+    ///
+    /// The span from the start of the name to the end of the timezone, or
+    /// None if it wasn't parsed
+    #[must_use]
+    pub const fn get_span(&self) -> Option<Span> {
+        self.span
+    }
+
+    fn parse(text: &Cow<'a, str>, origin: Option<Span>) -> Result<Self, Error> {
+        let (identity, end) = Identity::parse_prefix(text, origin)?;
+        let timestamp = next_word(text, end)
+            .ok_or_else(|| Error::MissingTimestamp(text.to_string(), (text.len(), 0)))?;
+        let timestamp_value = Some(&text[timestamp.clone()])
+            .filter(|timestamp| timestamp.bytes().all(|byte| byte.is_ascii_digit()))
+            .and_then(|timestamp| timestamp.parse::<i64>().ok())
+            .ok_or_else(|| {
+                Error::InvalidTimestamp(text.to_string(), (timestamp.start, timestamp.len()))
+            })?;
+
+        let timezone = next_word(text, timestamp.end)
+            .ok_or_else(|| Error::InvalidTimezone(text.to_string(), (text.len(), 0)))?;
+        if !is_timezone(&text[timezone.clone()]) {
+            return Err(Error::InvalidTimezone(
+                text.to_string(),
+                (timezone.start, timezone.len()),
+            ));
+        }
+
+        let rest = trimmed(text, timezone.end..text.len());
+        if !rest.is_empty() {
+            return Err(
+                IdentityError::UnexpectedText(text.to_string(), (rest.start, rest.len())).into(),
+            );
+        }
+
+        Ok(Self {
+            identity,
+            timestamp: timestamp_value,
+            span: origin.map(|origin| origin.within(text, 0..timezone.end)),
+            timezone: span::slice(text, timezone),
+        })
+    }
+}
+
+/// The next run of text without whitespace in it, from an offset
+fn next_word(text: &str, from: usize) -> Option<Range<usize>> {
+    let rest = trimmed(text, from..text.len());
+    let length = text[rest.clone()]
+        .find(char::is_whitespace)
+        .unwrap_or_else(|| rest.len());
+
+    (length > 0).then(|| rest.start..rest.start + length)
+}
+
+/// Is this a timezone like `+0100`
+fn is_timezone(timezone: &str) -> bool {
+    let bytes = timezone.as_bytes();
+
+    bytes.len() == 5
+        && matches!(bytes[0], b'+' | b'-')
+        && bytes[1..].iter().all(u8::is_ascii_digit)
+        && bytes[3] < b'6'
+}
+
+impl PartialEq for Signature<'_> {
+    /// Signatures are equal if their text is, wherever they came from
+    fn eq(&self, other: &Self) -> bool {
+        self.identity == other.identity
+            && self.timestamp == other.timestamp
+            && self.timezone == other.timezone
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Signature<'a> {
+    type Error = Error;
+
+    /// Parse a `Name <email> timestamp timezone`
+    ///
+    /// # Errors
+    ///
+    /// If the name and email aren't valid, or the timestamp or timezone are
+    /// missing or not valid. The error points at the problem.
+    fn try_from(text: &'a str) -> Result<Self, Self::Error> {
+        Self::parse(&Cow::Borrowed(text), Some(Span::new(0, text.len(), 0, 0)))
+    }
+}
+
+impl<'a> TryFrom<&CommitHeader<'a>> for Signature<'a> {
+    type Error = Error;
+
+    /// Parse the value of an `author` or `committer` header
+    ///
+    /// # Errors
+    ///
+    /// If the name and email aren't valid, or the timestamp or timezone are
+    /// missing or not valid. The error points at the problem in the value of
+    /// the header.
+    fn try_from(header: &CommitHeader<'a>) -> Result<Self, Self::Error> {
+        let (value, origin) = header.value_with_span();
+
+        Self::parse(value, origin)
+    }
+}
+
+impl From<Signature<'_>> for String {
+    /// The signature as git writes it
+    fn from(signature: Signature<'_>) -> Self {
+        format!(
+            "{} {} {}",
+            Self::from(signature.identity),
+            signature.timestamp,
+            signature.timezone
+        )
+    }
+}
+
+/// Errors in reading a [`Signature`]
+#[derive(Error, Debug, Diagnostic)]
+pub enum Error {
+    /// The name and email aren't valid
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Identity(#[from] IdentityError),
+    /// There is nothing after the email
+    #[error("there is no timestamp")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::signature::error::missing_timestamp),
+        help("add the seconds since the Unix epoch after the email, like `1592073120 +0100`")
+    )]
+    MissingTimestamp(
+        #[source_code] String,
+        #[label("expected a timestamp here")] (usize, usize),
+    ),
+    /// The timestamp isn't a number of seconds
+    #[error("the timestamp is not valid")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::signature::error::invalid_timestamp),
+        help("timestamps are the seconds since the Unix epoch, like `1592073120`")
+    )]
+    InvalidTimestamp(
+        #[source_code] String,
+        #[label("this is not a number of seconds")] (usize, usize),
+    ),
+    /// The timezone is missing or isn't like `+0100`
+    #[error("the timezone is not valid")]
+    #[diagnostic(
+        url(docsrs),
+        code(mit_commit::signature::error::invalid_timezone),
+        help("timezones are hours and minutes from UTC, like `+0100` or `-0530`")
+    )]
+    InvalidTimezone(
+        #[source_code] String,
+        #[label("expected a timezone like `+0100` here")] (usize, usize),
+    ),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors_point_at_the_problem() {
+        let cases = [
+            ("Billie <billie@example.com>", (27, 0)),
+            ("Billie <billie@example.com> soon +0100", (28, 4)),
+            ("Billie <billie@example.com> 1592073120", (38, 0)),
+            ("Billie <billie@example.com> 1592073120 +01:00", (39, 6)),
+            ("Billie <billie@example.com> 1592073120 +0175", (39, 5)),
+        ];
+
+        for (text, expected) in cases {
+            let label = match Signature::try_from(text).expect_err("this isn't a signature") {
+                Error::MissingTimestamp(_, label)
+                | Error::InvalidTimestamp(_, label)
+                | Error::InvalidTimezone(_, label) => label,
+                Error::Identity(error) => panic!("Unexpected error {error:?}"),
+            };
+
+            assert_eq!(
+                label, expected,
+                "The label should be on the problem in {text:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_round_trips() {
+        let text = "Billie Thompson <billie@example.com> 1592073120 -0000";
+
+        assert_eq!(
+            Signature::try_from(text).map(String::from).ok(),
+            Some(text.to_string()),
+            "The signature should be written back the way git wrote it"
+        );
+    }
+}
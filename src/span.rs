@@ -0,0 +1,390 @@
+use std::{borrow::Cow, ops::Range};
+
+use miette::SourceSpan;
+
+/// Where a parsed item came from in the text of a [`crate::CommitMessage`]
+///
+/// Offsets and lengths are in bytes, lines and columns are zero-based, and
+/// columns are counted in characters, matching [`miette`].
+///
+/// # Examples
+///
+/// ```
+/// use miette::SourceSpan;
+/// use mit_commit::{CommitMessage, Span};
+///
+/// let message = CommitMessage::from("Subject\n\nRelates-to: #128\n");
+/// let span = message.get_trailers().iter().next().unwrap().get_span().unwrap();
+///
+/// assert_eq!(span.get_offset(), 9);
+/// assert_eq!(span.get_length(), 16);
+/// assert_eq!(span.get_line(), 2);
+/// assert_eq!(span.get_column(), 0);
+/// assert_eq!(SourceSpan::from(span), SourceSpan::from((9, 16)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Span {
+    offset: usize,
+    length: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Span {
+    /// Create a new [`Span`]
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - Byte offset of the start of the span
+    /// * `length` - Length of the span in bytes
+    /// * `line` - Zero-based line the span starts on
+    /// * `column` - Zero-based column, in characters, the span starts on
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::Span;
+    ///
+    /// let span = Span::new(9, 16, 2, 0);
+    ///
+    /// assert_eq!(span.get_offset(), 9);
+    /// assert_eq!(span.get_length(), 16);
+    /// ```
+    #[must_use]
+    pub const fn new(offset: usize, length: usize, line: usize, column: usize) -> Self {
+        Self {
+            offset,
+            length,
+            line,
+            column,
+        }
+    }
+
+    /// Byte offset of the start of the [`Span`]
+    #[must_use]
+    pub const fn get_offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Length of the [`Span`] in bytes
+    #[must_use]
+    pub const fn get_length(&self) -> usize {
+        self.length
+    }
+
+    /// Zero-based line the [`Span`] starts on
+    #[must_use]
+    pub const fn get_line(&self) -> usize {
+        self.line
+    }
+
+    /// Zero-based column, in characters, the [`Span`] starts on
+    #[must_use]
+    pub const fn get_column(&self) -> usize {
+        self.column
+    }
+
+    /// Byte offset just past the end of the [`Span`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::Span;
+    ///
+    /// assert_eq!(Span::new(9, 16, 2, 0).get_end(), 25);
+    /// ```
+    #[must_use]
+    pub const fn get_end(&self) -> usize {
+        self.offset + self.length
+    }
+
+    /// A [`Span`] covering this span and another that comes after it
+    ///
+    /// The line and column are taken from the earlier span.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The later span to extend this one to
+    ///
+    /// # Returns
+    ///
+    /// A span from the start of this span to the end of the other
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::Span;
+    ///
+    /// assert_eq!(
+    ///     Span::new(0, 7, 0, 0).extend(Span::new(8, 4, 1, 0)),
+    ///     Span::new(0, 12, 0, 0)
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn extend(self, other: Self) -> Self {
+        let end = if other.get_end() > self.get_end() {
+            other.get_end()
+        } else {
+            self.get_end()
+        };
+
+        Self {
+            offset: self.offset,
+            length: end - self.offset,
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    /// The part of this span covering a range of the text it spans
+    ///
+    /// The line and column are moved on to where the range starts.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text this span covers
+    /// * `range` - The byte range within that text
+    pub(crate) fn within(self, text: &str, range: Range<usize>) -> Self {
+        let before = &text[..range.start];
+        let (line, column) = before.rfind('\n').map_or_else(
+            || (self.line, self.column + before.chars().count()),
+            |newline| {
+                (
+                    self.line + before.matches('\n').count(),
+                    before[newline + 1..].chars().count(),
+                )
+            },
+        );
+
+        Self {
+            offset: self.offset + range.start,
+            length: range.len(),
+            line,
+            column,
+        }
+    }
+}
+
+impl From<Span> for SourceSpan {
+    /// Convert a [`Span`] into a [`SourceSpan`] for use in a
+    /// [`miette::Diagnostic`] label
+    fn from(span: Span) -> Self {
+        (span.offset, span.length).into()
+    }
+}
+
+/// Spans for each line of a message, not including the line endings
+pub fn line_spans(message: &str) -> Vec<Span> {
+    spans_of_lines(message).collect()
+}
+
+/// Spans for the first few lines of a message, so the rest of it doesn't
+/// have to be read
+pub fn leading_line_spans(message: &str, count: usize) -> Vec<Span> {
+    spans_of_lines(message).take(count).collect()
+}
+
+fn spans_of_lines(message: &str) -> impl Iterator<Item = Span> + '_ {
+    message
+        .split_inclusive('\n')
+        .scan(0, |offset, line| {
+            let start = *offset;
+            *offset += line.len();
+            let content = line
+                .strip_suffix('\n')
+                .map_or(line, |line| line.strip_suffix('\r').unwrap_or(line));
+
+            Some(start..start + content.len())
+        })
+        .enumerate()
+        .map(|(line, range)| Span::new(range.start, range.len(), line, 0))
+}
+
+/// Split grouped text back into its lines, still borrowed from the message
+/// if the text was
+pub fn split_lines<'a>(text: &Cow<'a, str>) -> Vec<Cow<'a, str>> {
+    match text {
+        Cow::Borrowed(text) => text.split('\n').map(Cow::Borrowed).collect(),
+        Cow::Owned(text) => text
+            .split('\n')
+            .map(|line| Cow::Owned(line.to_string()))
+            .collect(),
+    }
+}
+
+/// Part of some text, borrowed from the message if the text is
+pub fn slice<'a>(text: &Cow<'a, str>, range: Range<usize>) -> Cow<'a, str> {
+    match text {
+        Cow::Borrowed(text) => Cow::Borrowed(&text[range]),
+        Cow::Owned(text) => Cow::Owned(text[range].to_string()),
+    }
+}
+
+/// A span of part of a message, with the line and column worked out from the
+/// offset
+pub fn in_text(text: &str, offset: usize, length: usize) -> Span {
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+
+    Span::new(
+        offset,
+        length,
+        before.matches('\n').count(),
+        before[line_start..].chars().count(),
+    )
+}
+
+/// The text of two spans and the line feed between them, borrowed from the
+/// message, if that is all there is between them
+pub fn joined(message: &str, first: Span, second: Span) -> Option<&str> {
+    if message.get(first.get_end()..second.get_offset()) != Some("\n") {
+        return None;
+    }
+
+    message.get(first.get_offset()..second.get_end())
+}
+
+/// The offset just past the line ending at the given offset, or the offset
+/// itself if there is no line ending there
+pub fn end_of_line(text: &str, offset: usize) -> usize {
+    let rest = &text[offset..];
+
+    rest.strip_prefix("\r\n")
+        .or_else(|| rest.strip_prefix('\n'))
+        .map_or(offset, |after| text.len() - after.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_spans_exclude_line_endings() {
+        assert_eq!(
+            line_spans("Subject\r\n\nBody"),
+            vec![
+                Span::new(0, 7, 0, 0),
+                Span::new(9, 0, 1, 0),
+                Span::new(10, 4, 2, 0),
+            ],
+            "Each line should be spanned without its line ending"
+        );
+    }
+
+    #[test]
+    fn test_line_spans_of_empty_message() {
+        assert_eq!(
+            line_spans(""),
+            Vec::<Span>::new(),
+            "An empty message has no lines"
+        );
+    }
+
+    #[test]
+    fn test_leading_line_spans_stop_after_the_count() {
+        assert_eq!(
+            leading_line_spans("Subject\n\nBody\n", 2),
+            vec![Span::new(0, 7, 0, 0), Span::new(8, 0, 1, 0)],
+            "Only the lines asked for should be spanned"
+        );
+    }
+
+    #[test]
+    fn test_split_lines_keeps_borrowing() {
+        let lines = split_lines(&Cow::Borrowed("One\nTwo"));
+
+        assert_eq!(lines, vec!["One", "Two"], "Each line should be split out");
+        assert!(
+            matches!(lines[1], Cow::Borrowed(_)),
+            "Borrowed text should stay borrowed"
+        );
+    }
+
+    #[test]
+    fn test_in_text_works_out_line_and_column() {
+        assert_eq!(
+            in_text("Sübject\n\nBody", 12, 2),
+            Span::new(12, 2, 2, 2),
+            "The line and column should be where the offset is"
+        );
+        assert_eq!(
+            in_text("Sübject", 3, 1),
+            Span::new(3, 1, 0, 2),
+            "Columns should be counted in characters"
+        );
+    }
+
+    #[test]
+    fn test_end_of_line_skips_the_line_ending() {
+        assert_eq!(
+            end_of_line("Subject\r\nBody", 7),
+            9,
+            "CRLF should be skipped"
+        );
+        assert_eq!(end_of_line("Subject\nBody", 7), 8, "LF should be skipped");
+        assert_eq!(
+            end_of_line("Subject", 7),
+            7,
+            "Without a line ending the offset is unchanged"
+        );
+    }
+
+    #[test]
+    fn test_joined_needs_only_a_line_feed_between() {
+        let message = "One\nTwo\r\nThree";
+
+        assert_eq!(
+            joined(message, Span::new(0, 3, 0, 0), Span::new(4, 3, 1, 0)),
+            Some("One\nTwo"),
+            "Lines separated by a line feed should be joined"
+        );
+        assert_eq!(
+            joined(message, Span::new(4, 3, 1, 0), Span::new(9, 5, 2, 0)),
+            None,
+            "Joining across a carriage return would change the text"
+        );
+    }
+
+    #[test]
+    fn test_extend_covers_both_spans() {
+        assert_eq!(
+            Span::new(4, 2, 1, 4).extend(Span::new(10, 5, 2, 0)),
+            Span::new(4, 11, 1, 4),
+            "Extending should run from the first start to the last end"
+        );
+    }
+
+    #[test]
+    fn test_extend_with_contained_span_keeps_the_end() {
+        assert_eq!(
+            Span::new(0, 10, 0, 0).extend(Span::new(2, 2, 0, 2)),
+            Span::new(0, 10, 0, 0),
+            "Extending with a span inside this one should not shrink it"
+        );
+    }
+
+    #[test]
+    fn test_within_moves_the_line_and_column() {
+        let span = Span::new(10, 13, 1, 2);
+
+        assert_eq!(
+            span.within("Sübject\nBody", 3..7),
+            Span::new(13, 4, 1, 4),
+            "Columns should be counted in characters"
+        );
+        assert_eq!(
+            span.within("Sübject\nBody", 9..13),
+            Span::new(19, 4, 2, 0),
+            "Ranges on later lines should start from the first column"
+        );
+    }
+
+    #[test]
+    fn test_converts_to_source_span() {
+        assert_eq!(
+            SourceSpan::from(Span::new(3, 4, 0, 3)),
+            SourceSpan::from((3, 4)),
+            "Should convert to a miette SourceSpan with the same offset and length"
+        );
+    }
+}
@@ -5,15 +5,36 @@ use std::{
     str::Chars,
 };
 
-use crate::{body::Body, fragment::Fragment};
+use crate::{Span, body::Body, fragment::Fragment};
 
 /// The [`Subject`] from the [`crate::CommitMessage`]
-#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Subject<'a> {
     text: Cow<'a, str>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    span: Option<Span>,
 }
 
 impl Subject<'_> {
+    /// Give you a [`Subject`] that owns its text, so it can outlive the message it
+    /// was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`Subject`], with any borrowed text copied
+    #[must_use]
+    pub fn into_owned(self) -> Subject<'static> {
+        Subject {
+            text: Cow::Owned(self.text.into_owned()),
+            span: self.span,
+        }
+    }
+
     /// Count characters in [`Self`]
     ///
     /// # Examples
@@ -63,12 +84,42 @@ impl Subject<'_> {
     pub fn chars(&self) -> Chars<'_> {
         self.text.chars()
     }
+
+    /// Where this [`Self`] came from in the parsed [`crate::CommitMessage`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{CommitMessage, Span, Subject};
+    ///
+    /// let message = CommitMessage::from("# Comment\nSubject\n\nBody");
+    ///
+    /// assert_eq!(
+    ///     message.get_subject().get_span(),
+    ///     Some(Span::new(10, 7, 1, 0))
+    /// );
+    /// assert_eq!(Subject::from("Not parsed").get_span(), None);
+    /// ```
+    #[must_use]
+    pub const fn get_span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
+impl PartialEq for Subject<'_> {
+    /// Subjects are equal if their text is, wherever they came from
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for Subject<'_> {}
+
 impl<'a> From<&'a str> for Subject<'a> {
     fn from(subject: &'a str) -> Self {
         Self {
             text: subject.into(),
+            span: None,
         }
     }
 }
@@ -92,13 +143,17 @@ impl From<String> for Subject<'_> {
     fn from(subject: String) -> Self {
         Self {
             text: subject.into(),
+            span: None,
         }
     }
 }
 
 impl<'a> From<Cow<'a, str>> for Subject<'a> {
     fn from(subject: Cow<'a, str>) -> Self {
-        Self { text: subject }
+        Self {
+            text: subject,
+            span: None,
+        }
     }
 }
 
@@ -116,12 +171,21 @@ impl Display for Subject<'_> {
 
 impl<'a> From<Body<'a>> for Subject<'a> {
     fn from(body: Body<'_>) -> Self {
-        Self::from(String::from(body))
+        Self {
+            span: body.get_span(),
+            text: String::from(body).into(),
+        }
     }
 }
 
 impl<'a> From<Vec<Fragment<'a>>> for Subject<'a> {
     fn from(ast: Vec<Fragment<'a>>) -> Self {
+        Self::from(ast.as_slice())
+    }
+}
+
+impl<'a> From<&[Fragment<'a>]> for Subject<'a> {
+    fn from(ast: &[Fragment<'a>]) -> Self {
         ast.iter()
             .find_map(|values| {
                 if let Fragment::Body(body) = values {
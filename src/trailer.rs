@@ -7,20 +7,39 @@ use std::{
 use miette::Diagnostic;
 use thiserror::Error;
 
-use crate::{Fragment, body::Body};
+use crate::{Fragment, Span, body::Body, span};
 
 /// A [`Trailer`] you might see a in a [`CommitMessage`], for example
 /// 'Co-authored-by: Billie Thompson <billie@example.com>'
 #[derive(Debug, Clone, Eq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Trailer<'a> {
     key: Cow<'a, str>,
     value: Cow<'a, str>,
     /// The separator between key and value — typically `": "` but `"#"` is
     /// also supported by `git-interpret-trailers` (e.g. `Fix #42`).
     separator: Cow<'a, str>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    span: Option<Span>,
 }
 
 impl<'a> Trailer<'a> {
+    /// Give you a [`Trailer`] that owns its text, so it can outlive the message it
+    /// was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`Trailer`], with any borrowed text copied
+    #[must_use]
+    pub fn into_owned(self) -> Trailer<'static> {
+        Trailer {
+            key: Cow::Owned(self.key.into_owned()),
+            value: Cow::Owned(self.value.into_owned()),
+            separator: Cow::Owned(self.separator.into_owned()),
+            span: self.span,
+        }
+    }
+
     /// Create a new [`Trailer`]
     ///
     /// This creates a new element that represents the sort of [`Trailers`] you
@@ -46,6 +65,7 @@ impl<'a> Trailer<'a> {
             key,
             value,
             separator: Cow::Borrowed(": "),
+            span: None,
         }
     }
 
@@ -76,6 +96,7 @@ impl<'a> Trailer<'a> {
             key,
             value,
             separator,
+            span: None,
         }
     }
 
@@ -114,6 +135,135 @@ impl<'a> Trailer<'a> {
     pub fn get_value(&self) -> String {
         self.value.to_string()
     }
+
+    /// Get the value of the [`Trailer`] with any folding removed
+    ///
+    /// Long values can be folded onto continuation lines that start with
+    /// whitespace. This joins them back into a single line, the way
+    /// `git interpret-trailers --parse` does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    ///
+    /// use mit_commit::{Body, Trailer};
+    /// let trailer = Trailer::try_from(Body::from(
+    ///     "Co-authored-by: Billie Thompson\n  <billie@example.com>",
+    /// ))
+    /// .expect("There should have been a trailer in that body component");
+    ///
+    /// assert_eq!(trailer.get_value(), "Billie Thompson\n  <billie@example.com>");
+    /// assert_eq!(
+    ///     trailer.get_unfolded_value(),
+    ///     "Billie Thompson <billie@example.com>"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn get_unfolded_value(&self) -> String {
+        self.value
+            .split('\n')
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Does the [`Trailer`] have this key, ignoring case like git does
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mit_commit::Trailer;
+    ///
+    /// let trailer = Trailer::new("Co-authored-by".into(), "Someone <someone@example.com>".into());
+    ///
+    /// assert!(trailer.has_key("co-authored-by"));
+    /// assert!(!trailer.has_key("Signed-off-by"));
+    /// ```
+    #[must_use]
+    pub fn has_key(&self, key: &str) -> bool {
+        self.key.eq_ignore_ascii_case(key)
+    }
+
+    /// Give you a new [`Trailer`] with the key replaced
+    ///
+    /// The value and separator are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The new key
+    ///
+    /// # Returns
+    ///
+    /// The trailer with the new key
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mit_commit::Trailer;
+    ///
+    /// let trailer = Trailer::new("Co-Authored-By".into(), "Someone <someone@example.com>".into());
+    ///
+    /// assert_eq!(
+    ///     String::from(trailer.with_key("Co-authored-by".into())),
+    ///     "Co-authored-by: Someone <someone@example.com>"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_key(self, key: Cow<'a, str>) -> Self {
+        Self { key, ..self }
+    }
+
+    /// Give you a new [`Trailer`] with the value replaced
+    ///
+    /// The key and separator are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The new value
+    ///
+    /// # Returns
+    ///
+    /// The trailer with the new value
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mit_commit::Trailer;
+    ///
+    /// let trailer = Trailer::new_with_separator("Fix".into(), "42".into(), " #".into());
+    ///
+    /// assert_eq!(String::from(trailer.with_value("43".into())), "Fix #43");
+    /// ```
+    #[must_use]
+    pub fn with_value(self, value: Cow<'a, str>) -> Self {
+        Self { value, ..self }
+    }
+
+    /// Where this [`Trailer`] came from in the parsed [`crate::CommitMessage`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mit_commit::{CommitMessage, Span, Trailer};
+    ///
+    /// let message = CommitMessage::from("Subject\n\nRelates-to: #128\nRelates-to: #129\n");
+    ///
+    /// assert_eq!(
+    ///     message
+    ///         .get_trailers()
+    ///         .iter()
+    ///         .map(Trailer::get_span)
+    ///         .collect::<Vec<_>>(),
+    ///     vec![Some(Span::new(9, 16, 2, 0)), Some(Span::new(26, 16, 3, 0))]
+    /// );
+    /// assert_eq!(Trailer::new("Relates-to".into(), "#128".into()).get_span(), None);
+    /// ```
+    #[must_use]
+    pub const fn get_span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 impl PartialEq for Trailer<'_> {
@@ -146,47 +296,200 @@ impl<'a> TryFrom<Body<'a>> for Trailer<'a> {
     type Error = Error;
 
     fn try_from(body: Body<'a>) -> Result<Self, Self::Error> {
-        let content: String = body.into();
+        Self::parse(body, None)
+    }
+}
+
+impl<'a> Trailer<'a> {
+    /// Parse a [`Trailer`] from a line, and any folded lines after it
+    ///
+    /// # Arguments
+    ///
+    /// * `body` - The line to parse
+    /// * `separators` - The characters that may separate the key from the
+    ///   value, as in git's `trailer.separators`, or None to look for `: ` and
+    ///   ` #`
+    ///
+    /// # Errors
+    ///
+    /// If the line is not a trailer
+    pub(crate) fn parse(body: Body<'a>, separators: Option<&str>) -> Result<Self, Error> {
+        let span = body.get_span();
+        let content: Cow<'a, str> = body.into();
+
+        // Values can be folded onto following lines, as long as each of them
+        // starts with whitespace. The folding is kept in the value so that it
+        // survives a round-trip.
+        let (first_line, folded) = content
+            .find('\n')
+            .map_or_else(|| (content.as_ref(), ""), |index| content.split_at(index));
+        if is_continuation(first_line)
+            || !folded
+                .get(1..)
+                .unwrap_or_default()
+                .lines()
+                .all(is_continuation)
+        {
+            return Err(Error::new_not_a_trailer(&content));
+        }
+
+        let trailer = separators.map_or_else(
+            || {
+                // The canonical trailer form is "Key: value" (colon-space),
+                // which covers Signed-off-by, Co-authored-by, Acked-by,
+                // See-also, etc.
+                //
+                // git-interpret-trailers also supports "#" as a separator,
+                // producing trailers like "Fix #42".  This is less common and
+                // more prone to false positives (any line containing "#"
+                // could match), so we restrict it to short, word-like keys.
+                //
+                // See: https://git-scm.com/docs/git-interpret-trailers
+                parse_colon_trailer(&content, first_line)
+                    .or_else(|| parse_hash_trailer(&content, first_line))
+            },
+            // When the separators are configured, find them the same way git
+            // does, so "Fixes=123" or "Key:value" are trailers too
+            |separators| {
+                find_separator(first_line, separators)
+                    .map(|position| parse_separated_trailer(&content, position))
+            },
+        );
+
+        trailer
+            .map(|trailer| Self { span, ..trailer })
+            .ok_or_else(|| Error::new_not_a_trailer(&content))
+    }
+}
+
+impl<'a> Trailer<'a> {
+    /// The value of this [`Trailer`], and where the value came from in the
+    /// message
+    pub(crate) fn value_with_span(&self) -> (&Cow<'a, str>, Option<Span>) {
+        let span = self.span.map(|span| {
+            let text = String::from(self.clone());
+            span.within(&text, self.key.len() + self.separator.len()..text.len())
+        });
+
+        (&self.value, span)
+    }
+
+    /// Set where this [`Trailer`] came from
+    #[must_use]
+    pub(crate) fn with_span(self, span: Span) -> Self {
+        Self {
+            span: Some(span),
+            ..self
+        }
+    }
+
+    /// Is this the same [`Trailer`] as another, ignoring case in the key and
+    /// value and whitespace around the value, like git does
+    pub(crate) fn is_same_trailer(&self, other: &Trailer<'_>) -> bool {
+        let whitespace = [' ', '\t', '\n', '\r'];
+
+        self.has_key(&other.key)
+            && self
+                .value
+                .trim_matches(whitespace)
+                .eq_ignore_ascii_case(other.value.trim_matches(whitespace))
+    }
+
+    /// Parse a trailer the way `git interpret-trailers` does, given the
+    /// position of the separator found by [`find_separator`]
+    pub(crate) fn from_git_line(line: &Body<'a>, separator_position: usize) -> Self {
+        Self {
+            span: line.get_span(),
+            ..parse_separated_trailer(&Cow::from(line.clone()), separator_position)
+        }
+    }
+}
 
-        // The canonical trailer form is "Key: value" (colon-space), which
-        // covers Signed-off-by, Co-authored-by, Acked-by, See-also, etc.
-        if let Some(trailer) = parse_colon_trailer(&content) {
-            return Ok(trailer);
+/// Split a line into a trailer at the separator found by [`find_separator`]
+///
+/// Whitespace around the separator is kept as part of the separator, so the
+/// trailer still round-trips. Everything after the separator, including
+/// any folded lines, is the value.
+fn parse_separated_trailer<'a>(content: &Cow<'a, str>, separator_position: usize) -> Trailer<'a> {
+    let (token, rest) = content.split_at(separator_position);
+    let key_end = token.trim_end().len();
+    let separator_length = rest.chars().next().map_or(0, char::len_utf8);
+    let value_start = content.len()
+        - rest[separator_length..]
+            .trim_start_matches([' ', '\t'])
+            .len();
+
+    Trailer::new_with_separator(
+        span::slice(content, 0..key_end),
+        span::slice(content, value_start..content.len()),
+        span::slice(content, key_end..value_start),
+    )
+}
+
+/// Find the separator in a potential trailer line, the way git does
+///
+/// The line must start with a token made of letters, digits and hyphens,
+/// optionally followed by whitespace, and then one of the separators.
+///
+/// # Returns
+///
+/// The byte position of the separator, or None if this isn't a trailer line
+pub fn find_separator(line: &str, separators: &str) -> Option<usize> {
+    let mut whitespace_found = false;
+
+    for (position, character) in line.char_indices() {
+        if separators.contains(character) {
+            return (position >= 1).then_some(position);
         }
 
-        // git-interpret-trailers also supports "#" as a separator, producing
-        // trailers like "Fix #42".  This is less common and more prone to
-        // false positives (any line containing "#" could match), so we
-        // restrict it to short, word-like keys.
-        //
-        // See: https://git-scm.com/docs/git-interpret-trailers
-        if let Some(trailer) = parse_hash_trailer(&content) {
-            return Ok(trailer);
+        if !whitespace_found && (character.is_ascii_alphanumeric() || character == '-') {
+            continue;
         }
 
-        Err(Error::new_not_a_trailer(&content))
+        if position != 0 && (character == ' ' || character == '\t') {
+            whitespace_found = true;
+            continue;
+        }
+
+        break;
     }
+
+    None
+}
+
+/// Is this line a continuation of the value of the trailer above it
+///
+/// Like `git interpret-trailers`, any line that starts with whitespace
+/// continues the previous trailer.
+pub fn is_continuation(line: &str) -> bool {
+    line.starts_with([' ', '\t'])
 }
 
 /// Parse a colon-separated trailer: "Key: value".
-fn parse_colon_trailer<'a>(content: &str) -> Option<Trailer<'a>> {
-    let (key, value) = content.split_once(": ")?;
+///
+/// The value runs to the end of the content, so folded lines are kept.
+fn parse_colon_trailer<'a>(content: &Cow<'a, str>, first_line: &str) -> Option<Trailer<'a>> {
+    let key_end = first_line.find(": ")?;
 
-    if key.is_empty() {
+    if key_end == 0 {
         return None;
     }
 
-    Some(Trailer::new(key.to_owned().into(), value.to_owned().into()))
+    Some(Trailer::new(
+        span::slice(content, 0..key_end),
+        span::slice(content, key_end + 2..content.len()),
+    ))
 }
 
 /// Parse a hash-separated trailer: "Fix #42".
 ///
 /// The key must be a short token (letters, digits, hyphens) with no spaces,
 /// to avoid matching arbitrary body text that happens to contain "#".
-fn parse_hash_trailer<'a>(content: &str) -> Option<Trailer<'a>> {
-    let mut parts = content.splitn(2, '#');
-    let key = parts.next()?.trim();
-    let value = parts.next()?;
+fn parse_hash_trailer<'a>(content: &Cow<'a, str>, first_line: &str) -> Option<Trailer<'a>> {
+    let hash = first_line.find('#')?;
+    let untrimmed = &first_line[..hash];
+    let key_start = untrimmed.len() - untrimmed.trim_start().len();
+    let key = untrimmed.trim();
 
     if key.is_empty() {
         return None;
@@ -199,8 +502,8 @@ fn parse_hash_trailer<'a>(content: &str) -> Option<Trailer<'a>> {
     }
 
     Some(Trailer::new_with_separator(
-        key.to_owned().into(),
-        value.to_owned().into(),
+        span::slice(content, key_start..key_start + key.len()),
+        span::slice(content, hash + 1..content.len()),
         " #".into(),
     ))
 }
@@ -226,6 +529,7 @@ impl Error {
 #[cfg(test)]
 mod tests {
     use std::{
+        borrow::Cow,
         collections::hash_map::DefaultHasher,
         convert::TryFrom,
         hash::{Hash, Hasher},
@@ -234,6 +538,30 @@ mod tests {
     use super::Trailer;
     use crate::{Fragment, body::Body};
 
+    #[test]
+    fn it_borrows_from_the_line_it_was_parsed_from() {
+        for (line, separators) in [
+            ("Relates-to: #128\n  and #129", None),
+            ("Fixes #128", None),
+            ("Fixes=128", Some("=")),
+        ] {
+            let trailer = Trailer::parse(Body::from(line), separators).expect("is a trailer");
+
+            assert!(
+                matches!(
+                    (&trailer.key, &trailer.value, &trailer.separator),
+                    (Cow::Borrowed(_), Cow::Borrowed(_), _)
+                ),
+                "The key and value of {line:?} should be borrowed"
+            );
+            assert_eq!(
+                String::from(trailer),
+                line,
+                "Borrowing shouldn't change the text"
+            );
+        }
+    }
+
     #[test]
     fn it_can_tell_me_its_key() {
         let trailer = Trailer::new("Relates-to".into(), "#128".into());
@@ -437,4 +765,138 @@ mod tests {
 
         assert_eq!(hash_trailer, colon_trailer);
     }
+
+    #[test]
+    fn it_parses_folded_values() {
+        let trailer = Trailer::try_from(Body::from(
+            "Signed-off-by: Billie Thompson\n\t<billie@example.com>",
+        ))
+        .expect("Should parse as a trailer");
+
+        assert_eq!(trailer.get_key(), "Signed-off-by");
+        assert_eq!(
+            trailer.get_value(),
+            "Billie Thompson\n\t<billie@example.com>",
+            "The folding should be kept in the value"
+        );
+        assert_eq!(
+            trailer.get_unfolded_value(),
+            "Billie Thompson <billie@example.com>",
+            "Unfolding should join the continuation lines with a space"
+        );
+    }
+
+    #[test]
+    fn it_round_trips_folded_values() {
+        let trailer = Trailer::try_from(Body::from(
+            "Co-authored-by: Billie Thompson\n   <billie@example.com>\n",
+        ))
+        .expect("Should parse as a trailer");
+
+        assert_eq!(
+            String::from(trailer),
+            String::from("Co-authored-by: Billie Thompson\n   <billie@example.com>\n"),
+            "Folded trailers should round-trip exactly"
+        );
+    }
+
+    #[test]
+    fn it_does_not_parse_unindented_second_lines_as_part_of_the_value() {
+        let result = Trailer::try_from(Body::from(
+            "Co-authored-by: Billie Thompson <billie@example.com>\nRelates-to: #128",
+        ));
+
+        assert!(
+            result.is_err(),
+            "Lines that don't start with whitespace are not continuations"
+        );
+    }
+
+    #[test]
+    fn it_does_not_parse_a_lone_continuation_line() {
+        let result = Trailer::try_from(Body::from("    indented: code"));
+
+        assert!(
+            result.is_err(),
+            "A line starting with whitespace has no key to continue"
+        );
+    }
+
+    #[test]
+    fn it_keeps_the_configured_separator() {
+        let trailer =
+            Trailer::parse(Body::from("Fixes=123"), Some(":=")).expect("Should parse as a trailer");
+
+        assert_eq!(
+            trailer.get_key(),
+            "Fixes",
+            "Key should be before the separator"
+        );
+        assert_eq!(
+            trailer.get_value(),
+            "123",
+            "Value should be after the separator"
+        );
+        assert_eq!(
+            String::from(trailer),
+            "Fixes=123",
+            "The separator should be kept so the trailer round-trips"
+        );
+    }
+
+    #[test]
+    fn it_allows_no_space_after_a_configured_separator() {
+        let trailer =
+            Trailer::parse(Body::from("Key:value"), Some(":")).expect("Should parse as a trailer");
+
+        assert_eq!(
+            trailer,
+            Trailer::new_with_separator("Key".into(), "value".into(), ":".into()),
+            "git accepts trailers without a space after the separator"
+        );
+        assert_eq!(String::from(trailer), "Key:value");
+    }
+
+    #[test]
+    fn it_keeps_whitespace_around_a_configured_separator() {
+        let trailer =
+            Trailer::parse(Body::from("Fix  #42"), Some(":#")).expect("Should parse as a trailer");
+
+        assert_eq!(
+            trailer.get_key(),
+            "Fix",
+            "Whitespace is not part of the key"
+        );
+        assert_eq!(
+            String::from(trailer),
+            "Fix  #42",
+            "Whitespace before the separator should be kept"
+        );
+    }
+
+    #[test]
+    fn it_rejects_separators_that_are_not_configured() {
+        assert!(
+            Trailer::parse(Body::from("Fixes=123"), Some(":")).is_err(),
+            "Only configured separators should be used"
+        );
+        assert!(
+            Trailer::parse(Body::from("Co authored by: Someone"), Some(":")).is_err(),
+            "Keys with configured separators must be a single token, like in git"
+        );
+    }
+
+    #[test]
+    fn it_compares_keys_ignoring_case() {
+        let trailer = Trailer::new("Co-authored-by".into(), "Someone".into());
+
+        assert!(
+            trailer.has_key("CO-AUTHORED-BY"),
+            "Keys should match whatever their case"
+        );
+        assert!(
+            !trailer.has_key("Co-authored"),
+            "Only whole keys should match"
+        );
+    }
 }
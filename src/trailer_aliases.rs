@@ -0,0 +1,117 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+/// A table of other spellings of [`crate::Trailer`] keys, and the canonical
+/// key each of them should be written as
+///
+/// Keys are looked up ignoring case, like git does, so a canonical key also
+/// fixes the case of any trailer that uses it.
+///
+/// # Examples
+///
+/// ```
+/// use mit_commit::TrailerAliases;
+///
+/// let aliases = TrailerAliases::default()
+///     .with_alias("Coauthored-by", "Co-authored-by")
+///     .with_alias("Co-Authored-By", "Co-authored-by");
+///
+/// assert_eq!(aliases.get_canonical_key("coauthored-by"), "Co-authored-by");
+/// assert_eq!(aliases.get_canonical_key("CO-AUTHORED-BY"), "Co-authored-by");
+/// assert_eq!(aliases.get_canonical_key("Relates-to"), "Relates-to");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TrailerAliases {
+    canonical_keys: BTreeMap<String, String>,
+}
+
+impl TrailerAliases {
+    /// Give you new [`TrailerAliases`] with another alias added
+    ///
+    /// # Arguments
+    ///
+    /// * `alias` - Another spelling of the key, like `Coauthored-by`
+    /// * `canonical` - The key it should be written as, like `Co-authored-by`
+    ///
+    /// # Returns
+    ///
+    /// The aliases with the new alias added
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::TrailerAliases;
+    ///
+    /// let aliases = TrailerAliases::default().with_alias("Signed-Off-By", "Signed-off-by");
+    ///
+    /// assert_eq!(aliases.get_canonical_key("Signed-Off-By"), "Signed-off-by");
+    /// ```
+    #[must_use]
+    pub fn with_alias(self, alias: &str, canonical: &str) -> Self {
+        let mut canonical_keys = self.canonical_keys;
+        canonical_keys.insert(canonical.to_ascii_lowercase(), canonical.to_string());
+        canonical_keys.insert(alias.to_ascii_lowercase(), canonical.to_string());
+
+        Self { canonical_keys }
+    }
+
+    /// Get the canonical spelling of a key
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key as it was written in the trailer
+    ///
+    /// # Returns
+    ///
+    /// The canonical key, or the key as it was if it has no alias
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::TrailerAliases;
+    ///
+    /// let aliases = TrailerAliases::default().with_alias("Coauthored-by", "Co-authored-by");
+    ///
+    /// assert_eq!(aliases.get_canonical_key("co-authored-by"), "Co-authored-by");
+    /// assert_eq!(aliases.get_canonical_key("Relates-to"), "Relates-to");
+    /// ```
+    #[must_use]
+    pub fn get_canonical_key<'b>(&self, key: &'b str) -> Cow<'b, str> {
+        self.canonical_keys
+            .get(&key.to_ascii_lowercase())
+            .map_or(Cow::Borrowed(key), |canonical| {
+                Cow::Owned(canonical.clone())
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_has_no_aliases() {
+        assert_eq!(
+            TrailerAliases::default().get_canonical_key("co-authored-by"),
+            "co-authored-by",
+            "Without aliases keys should be left alone"
+        );
+    }
+
+    #[test]
+    fn test_later_aliases_win() {
+        let aliases = TrailerAliases::default()
+            .with_alias("Reviewer", "Reviewed-by")
+            .with_alias("Reviewer", "Acked-by");
+
+        assert_eq!(
+            aliases.get_canonical_key("reviewer"),
+            "Acked-by",
+            "Adding an alias again should replace it"
+        );
+        assert_eq!(
+            aliases.get_canonical_key("REVIEWED-BY"),
+            "Reviewed-by",
+            "The earlier canonical key should still fix its case"
+        );
+    }
+}
@@ -0,0 +1,401 @@
+use crate::{
+    Fragment, IfExists, IfMissing, Trailer, Where, span,
+    trailers::{end_of_git_message, find_git_trailer_block, fold_git_trailer_lines},
+};
+
+/// How to add a [`Trailer`] to a [`crate::CommitMessage`]
+///
+/// This mirrors git's `trailer.where`, `trailer.ifExists` and
+/// `trailer.ifMissing` config options, and the defaults are the same as
+/// git's.
+///
+/// # Examples
+///
+/// ```
+/// use mit_commit::{CommitMessage, IfExists, Trailer, TrailerPolicy, Where};
+///
+/// let commit = CommitMessage::from("Subject\n\nSigned-off-by: Someone <someone@example.com>\n");
+/// let policy = TrailerPolicy::default()
+///     .with_where(Where::Start)
+///     .with_if_exists(IfExists::DoNothing);
+///
+/// assert_eq!(
+///     String::from(
+///         commit.add_trailer_with_policy(Trailer::new("Relates-to".into(), "#128".into()), policy)
+///     ),
+///     "Subject\n\nRelates-to: #128\nSigned-off-by: Someone <someone@example.com>\n"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TrailerPolicy {
+    location: Where,
+    if_exists: IfExists,
+    if_missing: IfMissing,
+}
+
+impl TrailerPolicy {
+    /// Give you a new [`TrailerPolicy`] with the provided [`Where`]
+    ///
+    /// # Arguments
+    ///
+    /// * `location` - Where in the trailer block to add the trailer
+    ///
+    /// # Returns
+    ///
+    /// The policy with the location replaced
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{TrailerPolicy, Where};
+    ///
+    /// assert_eq!(
+    ///     TrailerPolicy::default().with_where(Where::Before).get_where(),
+    ///     Where::Before
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn with_where(self, location: Where) -> Self {
+        Self { location, ..self }
+    }
+
+    /// Give you a new [`TrailerPolicy`] with the provided [`IfExists`]
+    ///
+    /// # Arguments
+    ///
+    /// * `if_exists` - What to do when a trailer with the key is present
+    ///
+    /// # Returns
+    ///
+    /// The policy with the action replaced
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{IfExists, TrailerPolicy};
+    ///
+    /// assert_eq!(
+    ///     TrailerPolicy::default()
+    ///         .with_if_exists(IfExists::Replace)
+    ///         .get_if_exists(),
+    ///     IfExists::Replace
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn with_if_exists(self, if_exists: IfExists) -> Self {
+        Self { if_exists, ..self }
+    }
+
+    /// Give you a new [`TrailerPolicy`] with the provided [`IfMissing`]
+    ///
+    /// # Arguments
+    ///
+    /// * `if_missing` - What to do when no trailer with the key is present
+    ///
+    /// # Returns
+    ///
+    /// The policy with the action replaced
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{IfMissing, TrailerPolicy};
+    ///
+    /// assert_eq!(
+    ///     TrailerPolicy::default()
+    ///         .with_if_missing(IfMissing::DoNothing)
+    ///         .get_if_missing(),
+    ///     IfMissing::DoNothing
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn with_if_missing(self, if_missing: IfMissing) -> Self {
+        Self { if_missing, ..self }
+    }
+
+    /// Get where in the trailer block trailers are added
+    #[must_use]
+    pub const fn get_where(&self) -> Where {
+        self.location
+    }
+
+    /// Get what is done when a trailer with the same key is present
+    #[must_use]
+    pub const fn get_if_exists(&self) -> IfExists {
+        self.if_exists
+    }
+
+    /// Get what is done when no trailer with the same key is present
+    #[must_use]
+    pub const fn get_if_missing(&self) -> IfMissing {
+        self.if_missing
+    }
+
+    /// Add a trailer to a message the way `git interpret-trailers --trailer`
+    /// does
+    ///
+    /// This follows `process_trailers` in git's `trailer.c`. Like git, every
+    /// trailer in the block is printed again as `Key: value`, comments in
+    /// the block are dropped, and an empty line is put before the block if
+    /// there isn't one.
+    pub(crate) fn apply(
+        self,
+        message: &str,
+        per_line_ast: &[Fragment<'_>],
+        separators: &str,
+        trailer: Trailer<'_>,
+    ) -> String {
+        let line_end = |fragment: &Fragment<'_>| {
+            fragment
+                .get_span()
+                .map_or(0, |span| span::end_of_line(message, span.get_end()))
+        };
+        let trailer_end = per_line_ast[..end_of_git_message(per_line_ast)]
+            .last()
+            .map_or(0, line_end);
+        let block = find_git_trailer_block(per_line_ast, separators).unwrap_or_default();
+        let trailer_start = block
+            .first()
+            .and_then(Fragment::get_span)
+            .map_or(trailer_end, |span| span.get_offset());
+
+        let mut items: Vec<Item> = fold_git_trailer_lines(block, separators)
+            .into_iter()
+            .map(|(line, separator_position)| {
+                separator_position.map_or_else(
+                    || Item::Other(line.as_str().to_string()),
+                    |position| Item::from_line(line.as_str(), position),
+                )
+            })
+            .collect();
+        self.insert(&mut items, trailer.into());
+
+        let before = &message[..trailer_start];
+        let mut output = String::from(before);
+        if !ends_with_blank_line(before) {
+            output.push('\n');
+        }
+        for item in items {
+            item.print(separators, &mut output);
+        }
+        output.push_str(&message[trailer_end..]);
+
+        output
+    }
+
+    /// Put a new trailer into the block, following `find_same_and_apply_arg`
+    /// in git's `trailer.c`
+    fn insert(self, items: &mut Vec<Item>, new: Item) {
+        let backwards = self.location.is_after_or_end();
+        let middle = matches!(self.location, Where::After | Where::Before);
+        let existing = if backwards {
+            items.iter().rposition(|item| item.has_same_key(&new))
+        } else {
+            items.iter().position(|item| item.has_same_key(&new))
+        };
+
+        let Some(existing) = existing else {
+            match (self.if_missing, backwards) {
+                (IfMissing::DoNothing, _) => {}
+                (IfMissing::Add, true) => items.push(new),
+                (IfMissing::Add, false) => items.insert(0, new),
+            }
+            return;
+        };
+
+        let neighbour = match (middle, backwards) {
+            (true, _) => existing,
+            (false, true) => items.len() - 1,
+            (false, false) => 0,
+        };
+        let position = if backwards { neighbour + 1 } else { neighbour };
+
+        match self.if_exists {
+            IfExists::DoNothing => {}
+            IfExists::Add => items.insert(position, new),
+            IfExists::Replace => {
+                items.insert(position, new);
+                items.remove(if position <= existing {
+                    existing + 1
+                } else {
+                    existing
+                });
+            }
+            IfExists::AddIfDifferent => {
+                // Like git, only the trailers between the match and the end
+                // it is being added to are checked
+                let checked = if backwards {
+                    &items[..=existing]
+                } else {
+                    &items[existing..]
+                };
+
+                if !checked.iter().any(|item| item.is_same_trailer(&new)) {
+                    items.insert(position, new);
+                }
+            }
+            IfExists::AddIfDifferentNeighbor => {
+                if !items[neighbour].is_same_trailer(&new) {
+                    items.insert(position, new);
+                }
+            }
+        }
+    }
+}
+
+/// A line in the trailer block, as git sees it when adding trailers
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Item {
+    Trailer { key: String, value: String },
+    Other(String),
+}
+
+impl From<Trailer<'_>> for Item {
+    fn from(trailer: Trailer<'_>) -> Self {
+        Self::Trailer {
+            key: trim_git_space(&trailer.get_key()).to_string(),
+            value: trim_git_space(&trailer.get_value()).to_string(),
+        }
+    }
+}
+
+impl Item {
+    fn from_line(line: &str, separator_position: usize) -> Self {
+        let (key, rest) = line.split_at(separator_position);
+        let separator_length = rest.chars().next().map_or(0, char::len_utf8);
+
+        Self::Trailer {
+            key: trim_git_space(key).to_string(),
+            value: trim_git_space(&rest[separator_length..]).to_string(),
+        }
+    }
+
+    /// Do the keys match, the way git's `same_token` compares them
+    ///
+    /// Git ignores case and anything after the key, and only compares as
+    /// many characters as the shorter key has, so `Fix` matches `Fixes`.
+    fn has_same_key(&self, other: &Self) -> bool {
+        let (Self::Trailer { key: left, .. }, Self::Trailer { key: right, .. }) = (self, other)
+        else {
+            return false;
+        };
+        let left = left.trim_end_matches(|character: char| !character.is_ascii_alphanumeric());
+        let right = right.trim_end_matches(|character: char| !character.is_ascii_alphanumeric());
+        let length = left.len().min(right.len());
+
+        left.as_bytes()[..length].eq_ignore_ascii_case(&right.as_bytes()[..length])
+    }
+
+    fn is_same_trailer(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Trailer { value: left, .. }, Self::Trailer { value: right, .. }) => {
+                self.has_same_key(other) && left.eq_ignore_ascii_case(right)
+            }
+            _ => false,
+        }
+    }
+
+    fn print(&self, separators: &str, output: &mut String) {
+        match self {
+            Self::Trailer { key, value } => {
+                output.push_str(key);
+                if !key.ends_with(|character| separators.contains(character)) {
+                    output.push(separators.chars().next().unwrap_or(':'));
+                    output.push(' ');
+                }
+                output.push_str(value);
+            }
+            Self::Other(line) => output.push_str(line),
+        }
+        output.push('\n');
+    }
+}
+
+/// Trim the characters git's `isspace` matches
+fn trim_git_space(text: &str) -> &str {
+    text.trim_matches([' ', '\t', '\n', '\r'])
+}
+
+/// Is the last line of the text blank, the way git's `ends_with_blank_line`
+/// works it out
+fn ends_with_blank_line(text: &str) -> bool {
+    let Some(without_last) = text.as_bytes().split_last().map(|(_, rest)| rest) else {
+        return false;
+    };
+    let start = without_last
+        .iter()
+        .rposition(|&byte| byte == b'\n')
+        .map_or(0, |position| position + 1);
+
+    text[start..]
+        .split('\n')
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .all(|character| matches!(character, ' ' | '\t' | '\r'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_matches_git() {
+        let policy = TrailerPolicy::default();
+
+        assert_eq!(
+            policy.get_where(),
+            Where::End,
+            "git adds trailers at the end"
+        );
+        assert_eq!(
+            policy.get_if_exists(),
+            IfExists::AddIfDifferentNeighbor,
+            "git adds trailers unless the neighbour is the same"
+        );
+        assert_eq!(
+            policy.get_if_missing(),
+            IfMissing::Add,
+            "git adds missing trailers"
+        );
+    }
+
+    #[test]
+    fn test_keys_match_like_git() {
+        let trailer = |key: &str| Item::Trailer {
+            key: key.to_string(),
+            value: String::new(),
+        };
+
+        assert!(
+            trailer("Fixes").has_same_key(&trailer("fixes")),
+            "Keys should be compared ignoring case"
+        );
+        assert!(
+            trailer("Fix").has_same_key(&trailer("Fixes")),
+            "git only compares as much as the shorter key"
+        );
+        assert!(
+            !trailer("Fixes").has_same_key(&trailer("Closes")),
+            "Different keys should not match"
+        );
+        assert!(
+            !Item::Other("Fixes".into()).has_same_key(&trailer("Fixes")),
+            "Lines that aren't trailers have no key"
+        );
+    }
+
+    #[test]
+    fn test_ends_with_blank_line() {
+        assert!(
+            ends_with_blank_line("Subject\n\n"),
+            "An empty last line is blank"
+        );
+        assert!(
+            ends_with_blank_line("Subject\n \t\n"),
+            "A whitespace only line is blank"
+        );
+        assert!(!ends_with_blank_line("Subject\n"), "A subject is not blank");
+        assert!(!ends_with_blank_line(""), "Nothing has no blank line");
+    }
+}
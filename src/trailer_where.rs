@@ -0,0 +1,24 @@
+/// Where a new [`crate::Trailer`] is put in the trailer block
+///
+/// These mirror the values of git's `trailer.where` config option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Where {
+    /// After the last trailer
+    #[default]
+    End,
+    /// Before the first trailer
+    Start,
+    /// After the last trailer with the same key, or at the end if there is
+    /// none
+    After,
+    /// Before the first trailer with the same key, or at the start if there
+    /// is none
+    Before,
+}
+
+impl Where {
+    /// Is the new trailer put after an existing one, rather than before it
+    pub(crate) const fn is_after_or_end(self) -> bool {
+        matches!(self, Self::After | Self::End)
+    }
+}
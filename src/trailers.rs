@@ -1,14 +1,36 @@
-use std::{convert::TryFrom, slice::Iter};
+use std::slice::Iter;
 
-use crate::{body::Body, fragment::Fragment, trailer::Trailer};
+use crate::{
+    body::Body,
+    fragment::Fragment,
+    trailer::{Trailer, find_separator, is_continuation},
+};
 
 /// A Collection of `Trailer`
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Trailers<'a> {
     trailers: Vec<Trailer<'a>>,
 }
 
 impl Trailers<'_> {
+    /// Give you a [`Trailers`] that owns its text, so it can outlive the message it
+    /// was parsed from
+    ///
+    /// # Returns
+    ///
+    /// The same [`Trailers`], with any borrowed text copied
+    #[must_use]
+    pub fn into_owned(self) -> Trailers<'static> {
+        Trailers {
+            trailers: self.trailers.into_iter().map(Trailer::into_owned).collect(),
+        }
+    }
+
     /// Iterate over the [`Trailers`]
     ///
     /// # Examples
@@ -104,6 +126,76 @@ impl Trailers<'_> {
     pub const fn is_empty(&self) -> bool {
         self.trailers.is_empty()
     }
+
+    /// Get the first [`Trailer`] with a key, ignoring case like git does
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to look for, like `Co-authored-by`
+    ///
+    /// # Returns
+    ///
+    /// The first trailer with the key, or None if there isn't one
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{Trailer, Trailers};
+    /// let trailers = Trailers::from(vec![
+    ///     Trailer::new("Relates-to".into(), "#124".into()),
+    ///     Trailer::new("Co-Authored-By".into(), "Billie Thompson <billie@example.com>".into()),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     trailers.get("co-authored-by").map(Trailer::get_value),
+    ///     Some("Billie Thompson <billie@example.com>".into())
+    /// );
+    /// assert_eq!(trailers.get("Signed-off-by"), None);
+    /// ```
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&Trailer<'_>> {
+        self.trailers.iter().find(|trailer| trailer.has_key(key))
+    }
+
+    /// Get every [`Trailer`] with a key, ignoring case like git does
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to look for, like `Co-authored-by`
+    ///
+    /// # Returns
+    ///
+    /// The trailers with the key, in the order they appear
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_commit::{Trailer, Trailers};
+    /// let trailers = Trailers::from(vec![
+    ///     Trailer::new("Co-authored-by".into(), "Billie Thompson <billie@example.com>".into()),
+    ///     Trailer::new("Relates-to".into(), "#124".into()),
+    ///     Trailer::new("co-authored-by".into(), "Someone Else <someone@example.com>".into()),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     trailers
+    ///         .get_all("Co-authored-by")
+    ///         .into_iter()
+    ///         .map(Trailer::get_value)
+    ///         .collect::<Vec<_>>(),
+    ///     vec![
+    ///         "Billie Thompson <billie@example.com>",
+    ///         "Someone Else <someone@example.com>"
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn get_all(&self, key: &str) -> Vec<&Trailer<'_>> {
+        self.trailers
+            .iter()
+            .filter(|trailer| trailer.has_key(key))
+            .collect()
+    }
 }
 
 impl<'a> IntoIterator for Trailers<'a> {
@@ -223,42 +315,253 @@ impl<'a> From<Trailers<'a>> for String {
 
 impl<'a> From<Vec<Fragment<'a>>> for Trailers<'a> {
     fn from(ast: Vec<Fragment<'a>>) -> Self {
-        let mut bodies: Vec<Body<'a>> = ast
-            .into_iter()
+        Self::from_fragments_with_separators(&ast, None)
+    }
+}
+
+impl<'a> Trailers<'a> {
+    /// Find the trailers at the end of a per-line ast, splitting them on the
+    /// given separators, or on `: ` and ` #` if there are none
+    pub(crate) fn from_fragments_with_separators(
+        ast: &[Fragment<'a>],
+        separators: Option<&str>,
+    ) -> Self {
+        // Skip the subject line (first Body fragment) so conventional
+        // commit subjects like "feat: add login" are not mistaken for trailers.
+        let bodies: Vec<Body<'a>> = ast
+            .iter()
             .filter_map(|values| {
                 if let Fragment::Body(body) = values {
-                    Some(body)
+                    Some(body.clone())
                 } else {
                     None
                 }
             })
+            .skip(1)
             .collect();
 
-        // Remove the subject line (first Body fragment) so conventional
-        // commit subjects like "feat: add login" are not mistaken for trailers.
-        if bodies.is_empty() {
+        let (trailers, _) = parse_trailing_lines(bodies, separators);
+        trailers.into()
+    }
+}
+
+impl Trailers<'_> {
+    /// Is every line of this paragraph part of a [`Trailer`]
+    pub(crate) fn is_trailer_paragraph(paragraph: &Body<'_>, separators: Option<&str>) -> bool {
+        let lines = paragraph
+            .as_str()
+            .split('\n')
+            .map(Body::from)
+            .collect::<Vec<_>>();
+
+        parse_trailing_lines(lines, separators).1
+    }
+}
+
+/// Trailers that git adds itself, which make a paragraph count as trailers
+/// even if only a quarter of its lines are trailers
+const GIT_GENERATED_PREFIXES: [&str; 2] = ["Signed-off-by: ", "(cherry picked from commit "];
+
+impl<'a> Trailers<'a> {
+    /// Find the trailers in a per-line ast the way `git interpret-trailers`
+    /// does
+    ///
+    /// This follows `find_trailer_block_start` and `parse_trailers` in git's
+    /// `trailer.c`. Lines in the block that aren't trailers are skipped.
+    pub(crate) fn from_git_trailer_block(per_line_ast: &[Fragment<'a>], separators: &str) -> Self {
+        let Some(block) = find_git_trailer_block(per_line_ast, separators) else {
             return Self::default();
-        }
-        bodies.remove(0);
+        };
 
-        bodies
-            .into_iter()
-            .rev()
-            .filter_map(|body| {
-                if body.is_empty() {
-                    None
-                } else {
-                    Some(Trailer::try_from(body))
-                }
+        fold_git_trailer_lines(block, separators)
+            .iter()
+            .filter_map(|(line, separator_position)| {
+                separator_position.map(|position| Trailer::from_git_line(line, position))
             })
-            .take_while(Result::is_ok)
-            .flatten()
-            .collect::<Vec<Trailer<'_>>>()
+            .collect::<Vec<_>>()
+            .into()
+    }
+}
+
+/// Join continuation lines in a git trailer block onto the trailer they
+/// continue
+///
+/// Comments are dropped, like git does. Continuation lines only continue a
+/// line that has a separator, otherwise they are lines of their own.
+///
+/// # Returns
+///
+/// Each line, with the position of its separator if it is a trailer
+pub fn fold_git_trailer_lines<'a>(
+    block: &[Fragment<'a>],
+    separators: &str,
+) -> Vec<(Body<'a>, Option<usize>)> {
+    let mut lines: Vec<(Body<'a>, Option<usize>)> = Vec::new();
+    let mut continues_trailer = false;
+
+    for fragment in block {
+        match fragment {
+            Fragment::Body(line) if continues_trailer && is_continuation(line.as_str()) => {
+                if let Some((last, _)) = lines.last_mut() {
+                    *last = last.append(line);
+                }
+            }
+            Fragment::Body(line) => {
+                let separator_position = find_separator(line.as_str(), separators);
+                continues_trailer = separator_position.is_some();
+                lines.push((line.clone(), separator_position));
+            }
+            Fragment::Comment(_) => continues_trailer = false,
+        }
+    }
+
+    lines
+}
+
+/// How many of the lines are part of the message, the way git sees it
+///
+/// Everything from a `---` divider onwards is a patch, and trailing comments
+/// and empty lines are ignored. Like git's `ignored_log_message_bytes`, the
+/// first line is never ignored, even when it is empty or a comment.
+pub fn end_of_git_message(lines: &[Fragment<'_>]) -> usize {
+    let end = lines
+        .iter()
+        .position(|fragment| match fragment {
+            Fragment::Body(body) => body
+                .as_str()
+                .strip_prefix("---")
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace)),
+            Fragment::Comment(_) => false,
+        })
+        .unwrap_or(lines.len());
+
+    lines[..end]
+        .iter()
+        .rposition(|fragment| match fragment {
+            Fragment::Body(body) => !body.is_empty(),
+            Fragment::Comment(_) => false,
+        })
+        .map_or_else(|| end.min(1), |position| position + 1)
+}
+
+/// Find the lines of the trailer block the way git does
+///
+/// The last paragraph is the trailer block if all of its lines are
+/// trailers, or if it has a git generated trailer in it and at least a
+/// quarter of its lines are trailers.
+pub fn find_git_trailer_block<'b, 'a>(
+    lines: &'b [Fragment<'a>],
+    separators: &str,
+) -> Option<&'b [Fragment<'a>]> {
+    let is_blank = |fragment: &Fragment<'_>| match fragment {
+        Fragment::Body(body) => body
+            .as_str()
+            .trim_start_matches([' ', '\t', '\r'])
+            .is_empty(),
+        Fragment::Comment(_) => false,
+    };
+
+    let lines = &lines[..end_of_git_message(lines)];
+
+    // The first paragraph is the subject, which can't be trailers
+    let end_of_title = lines.iter().position(is_blank)?;
+
+    let mut only_spaces = true;
+    let mut recognized_prefix = false;
+    let mut trailer_lines = 0_usize;
+    let mut non_trailer_lines = 0_usize;
+    let mut possible_continuation_lines = 0_usize;
+
+    for (index, fragment) in lines.iter().enumerate().skip(end_of_title).rev() {
+        let Fragment::Body(line) = fragment else {
+            non_trailer_lines += possible_continuation_lines;
+            possible_continuation_lines = 0;
+            continue;
+        };
+
+        if is_blank(fragment) {
+            if only_spaces {
+                continue;
+            }
+
+            non_trailer_lines += possible_continuation_lines;
+            let is_trailer_block = (recognized_prefix && trailer_lines * 3 >= non_trailer_lines)
+                || (trailer_lines > 0 && non_trailer_lines == 0);
+
+            return is_trailer_block.then(|| &lines[index + 1..]);
+        }
+        only_spaces = false;
+
+        let line = line.as_str();
+        if GIT_GENERATED_PREFIXES
+            .iter()
+            .any(|prefix| line.starts_with(prefix))
+        {
+            trailer_lines += 1;
+            possible_continuation_lines = 0;
+            recognized_prefix = true;
+        } else if find_separator(line, separators).is_some() {
+            trailer_lines += 1;
+            possible_continuation_lines = 0;
+        } else if is_continuation(line) {
+            possible_continuation_lines += 1;
+        } else {
+            non_trailer_lines += 1 + possible_continuation_lines;
+            possible_continuation_lines = 0;
+        }
+    }
+
+    None
+}
+
+/// Collect the trailers at the end of some lines, working backwards until a
+/// line that isn't part of a trailer
+///
+/// Empty lines are skipped, and folded continuation lines are kept with the
+/// trailer they continue.
+///
+/// # Returns
+///
+/// The trailers in their original order, and whether every line was part of
+/// a trailer
+fn parse_trailing_lines<'a>(
+    lines: Vec<Body<'a>>,
+    separators: Option<&str>,
+) -> (Vec<Trailer<'a>>, bool) {
+    let mut trailers = Vec::new();
+    let mut continuation: Vec<Body<'_>> = Vec::new();
+    let mut complete = true;
+
+    for line in lines.into_iter().rev() {
+        if line.is_empty() {
+            if continuation.is_empty() {
+                continue;
+            }
+
+            complete = false;
+            break;
+        }
+
+        if is_continuation(line.as_str()) {
+            continuation.push(line);
+            continue;
+        }
+
+        let folded = std::mem::take(&mut continuation)
             .into_iter()
             .rev()
-            .collect::<Vec<Trailer<'_>>>()
-            .into()
+            .fold(line, |folded, next| folded.append(&next));
+
+        if let Ok(trailer) = Trailer::parse(folded, separators) {
+            trailers.push(trailer);
+        } else {
+            complete = false;
+            break;
+        }
     }
+
+    trailers.reverse();
+    (trailers, complete && continuation.is_empty())
 }
 
 #[cfg(test)]
@@ -443,4 +746,136 @@ mod tests {
 
         assert_eq!(Trailers::from(trailers), expected);
     }
+
+    #[test]
+    fn it_keeps_folded_lines_with_their_trailer() {
+        let ast = vec![
+            Fragment::Body(Body::from("Example Commit")),
+            Fragment::Body(Body::default()),
+            Fragment::Body(Body::from("Signed-off-by: Billie Thompson")),
+            Fragment::Body(Body::from("  <billie@example.com>")),
+            Fragment::Body(Body::from("Relates-to: #128")),
+        ];
+
+        let expected: Trailers<'_> = vec![
+            Trailer::new(
+                "Signed-off-by".into(),
+                "Billie Thompson\n  <billie@example.com>".into(),
+            ),
+            Trailer::new("Relates-to".into(), "#128".into()),
+        ]
+        .into();
+
+        assert_eq!(
+            Trailers::from(ast),
+            expected,
+            "Continuation lines should be folded into the trailer above them"
+        );
+    }
+
+    #[test]
+    fn it_stops_at_continuation_lines_without_a_trailer() {
+        let ast = vec![
+            Fragment::Body(Body::from("Example Commit")),
+            Fragment::Body(Body::default()),
+            Fragment::Body(Body::from("Some prose")),
+            Fragment::Body(Body::from("    indented code")),
+            Fragment::Body(Body::from("Relates-to: #128")),
+        ];
+
+        let expected: Trailers<'_> = vec![Trailer::new("Relates-to".into(), "#128".into())].into();
+
+        assert_eq!(
+            Trailers::from(ast),
+            expected,
+            "Indented lines under prose are not part of a trailer"
+        );
+    }
+
+    #[test]
+    fn it_knows_a_folded_paragraph_is_all_trailers() {
+        assert!(
+            Trailers::is_trailer_paragraph(
+                &Body::from(
+                    "Signed-off-by: Billie Thompson\n  <billie@example.com>\nRelates-to: #128"
+                ),
+                None
+            ),
+            "A paragraph of folded trailers should be recognised as trailers"
+        );
+        assert!(
+            !Trailers::is_trailer_paragraph(
+                &Body::from("  <billie@example.com>\nRelates-to: #128"),
+                None
+            ),
+            "A paragraph starting with a continuation line is not all trailers"
+        );
+    }
+
+    #[test]
+    fn it_finds_a_git_trailer_block_with_a_quarter_trailers() {
+        let ast = vec![
+            Fragment::Body(Body::from("Example Commit")),
+            Fragment::Body(Body::default()),
+            Fragment::Body(Body::from("Some prose")),
+            Fragment::Body(Body::from("more prose")),
+            Fragment::Body(Body::from("and more prose")),
+            Fragment::Body(Body::from(
+                "Signed-off-by: Billie Thompson <billie@example.com>",
+            )),
+        ];
+
+        let expected: Trailers<'_> = vec![Trailer::new(
+            "Signed-off-by".into(),
+            "Billie Thompson <billie@example.com>".into(),
+        )]
+        .into();
+
+        assert_eq!(
+            Trailers::from_git_trailer_block(&ast, ":"),
+            expected,
+            "A git generated trailer should let a quarter trailers be a block"
+        );
+    }
+
+    #[test]
+    fn it_needs_all_trailers_in_a_git_block_without_a_signoff() {
+        let ast = vec![
+            Fragment::Body(Body::from("Example Commit")),
+            Fragment::Body(Body::default()),
+            Fragment::Body(Body::from("Some prose")),
+            Fragment::Body(Body::from("Relates-to: #128")),
+        ];
+
+        assert_eq!(
+            Trailers::from_git_trailer_block(&ast, ":"),
+            Trailers::default(),
+            "Without a git generated trailer every line must be a trailer"
+        );
+    }
+
+    #[test]
+    fn it_skips_comments_in_a_git_block() {
+        let ast = vec![
+            Fragment::Body(Body::from("Example Commit")),
+            Fragment::Body(Body::default()),
+            Fragment::Body(Body::from("Relates-to: #128")),
+            Fragment::Comment(Comment::from("# A comment")),
+            Fragment::Body(Body::from("Fixes : #129")),
+            Fragment::Body(Body::default()),
+            Fragment::Comment(Comment::from("# Another comment")),
+        ];
+
+        let expected: Trailers<'_> = vec![
+            Trailer::new("Relates-to".into(), "#128".into()),
+            Trailer::new("Fixes".into(), "#129".into()),
+        ]
+        .into();
+
+        assert_eq!(
+            Trailers::from_git_trailer_block(&ast, ":"),
+            expected,
+            "Comments should not stop a block being found, or end up in it"
+        );
+    }
 }
@@ -0,0 +1,161 @@
+use indoc::indoc;
+use miette::Diagnostic;
+use mit_commit::{
+    CommitMessage, CommitMessageError, Fix, Span,
+    lint::{BodyLineLength, Registry},
+};
+
+const COMMIT_WITH_PROBLEMS: &str = indoc!(
+    "
+    update bashrc to include kubernetes completions.
+    # Please enter the commit message for your changes. Lines starting
+    # with '#' will be ignored, and an empty message aborts the commit.
+    This should make it easier to deploy things for the developers, as they no longer need to remember every flag.
+
+    Relates-to: #128
+    # ------------------------ >8 ------------------------
+    # Do not modify or remove the line above.
+    # Everything below it will be ignored.
+    diff --git a/.bashrc b/.bashrc
+    "
+);
+
+fn fixes_for(registry: &Registry, commit: &CommitMessage<'_>) -> Vec<Fix> {
+    registry
+        .run(commit)
+        .iter()
+        .flat_map(|problem| problem.get_fixes().to_vec())
+        .collect()
+}
+
+#[test]
+fn applying_every_fix_solves_every_problem() {
+    let registry = Registry::default()
+        .with_subject_lints()
+        .with_lint(BodyLineLength::default());
+    let commit = CommitMessage::from(COMMIT_WITH_PROBLEMS);
+    let fixed = commit
+        .apply_fixes(&fixes_for(&registry, &commit))
+        .expect("fixes should not conflict");
+
+    assert_eq!(
+        String::from(&fixed),
+        indoc!(
+            "
+            Update bashrc to include kubernetes completions
+
+            # Please enter the commit message for your changes. Lines starting
+            # with '#' will be ignored, and an empty message aborts the commit.
+            This should make it easier to deploy things for the developers, as they
+            no longer need to remember every flag.
+
+            Relates-to: #128
+            # ------------------------ >8 ------------------------
+            # Do not modify or remove the line above.
+            # Everything below it will be ignored.
+            diff --git a/.bashrc b/.bashrc
+            "
+        )
+    );
+    assert_eq!(registry.run(&fixed), vec![]);
+    assert_eq!(fixed.get_scissors(), commit.get_scissors());
+    assert_eq!(fixed.get_comments(), commit.get_comments());
+}
+
+#[test]
+fn fixes_apply_to_messages_with_crlf_line_endings() {
+    let registry = Registry::default()
+        .with_subject_lints()
+        .with_lint(BodyLineLength::default());
+    let commit = CommitMessage::from(COMMIT_WITH_PROBLEMS.replace('\n', "\r\n"));
+    let fixed = commit
+        .apply_fixes(&fixes_for(&registry, &commit))
+        .expect("fixes should be inside the message");
+    let lf_commit = CommitMessage::from(COMMIT_WITH_PROBLEMS);
+    let lf_fixed = lf_commit
+        .apply_fixes(&fixes_for(&registry, &lf_commit))
+        .expect("fixes should not conflict");
+
+    assert_eq!(String::from(&fixed), String::from(&lf_fixed));
+    assert_eq!(registry.run(&fixed), vec![]);
+}
+
+#[test]
+fn conflicting_fixes_are_all_reported() {
+    let commit = CommitMessage::from(COMMIT_WITH_PROBLEMS);
+    let fixes = vec![
+        Fix::new(Span::new(0, 6, 0, 0), "Change".into()),
+        Fix::new(Span::new(0, 1, 0, 0), "U".into()),
+        Fix::new(Span::new(47, 1, 0, 47), String::new()),
+    ];
+    let error = commit
+        .apply_fixes(&fixes)
+        .expect_err("the first two fixes overlap");
+
+    assert!(
+        matches!(error, CommitMessageError::ConflictingFixes(..)),
+        "Unexpected error {error:?}"
+    );
+    assert_eq!(
+        error.labels().map(|labels| labels
+            .map(|label| (label.offset(), label.len()))
+            .collect::<Vec<_>>()),
+        Some(vec![(0, 1), (0, 6)])
+    );
+}
+
+#[test]
+fn fixes_cannot_change_comments_or_scissors() {
+    let commit = CommitMessage::from(COMMIT_WITH_PROBLEMS);
+    let end = COMMIT_WITH_PROBLEMS.len();
+    let comment_start = COMMIT_WITH_PROBLEMS
+        .find("# Please")
+        .expect("has a comment");
+
+    for fix in [
+        Fix::new(Span::new(comment_start + 2, 6, 1, 2), "Kindly".into()),
+        Fix::new(Span::new(end - 5, 5, 10, 25), "zshrc".into()),
+        Fix::new(Span::new(end, 0, 11, 0), "More diff\n".into()),
+    ] {
+        let error = commit
+            .apply_fixes(std::slice::from_ref(&fix))
+            .expect_err("should not be able to change comments");
+
+        assert!(
+            matches!(error, CommitMessageError::FixChangesComments(..)),
+            "Unexpected error {error:?} for {fix:?}"
+        );
+    }
+}
+
+#[test]
+fn fixes_outside_the_message_are_errors() {
+    let commit = CommitMessage::from("Subject\n");
+
+    assert!(matches!(
+        commit.apply_fixes(&[Fix::new(Span::new(7, 5, 0, 7), String::new())]),
+        Err(CommitMessageError::FixOutOfBounds(7, 12))
+    ));
+
+    let commit = CommitMessage::from("Sübject\n");
+
+    assert!(matches!(
+        commit.apply_fixes(&[Fix::new(Span::new(2, 1, 0, 2), String::new())]),
+        Err(CommitMessageError::FixOutOfBounds(2, 3))
+    ));
+}
+
+#[test]
+fn the_same_fix_twice_is_applied_once() {
+    let commit = CommitMessage::from("Subject.\n");
+    let fix = Fix::new(Span::new(7, 1, 0, 7), String::new());
+
+    assert_eq!(
+        String::from(
+            commit
+                .apply_fixes(&[fix.clone(), fix])
+                .expect("identical fixes don't conflict")
+        ),
+        "Subject\n"
+    );
+}
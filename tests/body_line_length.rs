@@ -0,0 +1,84 @@
+use indoc::indoc;
+use mit_commit::{
+    CommitMessage,
+    lint::{BodyLineLength, Lint},
+};
+
+const COMMIT_WITH_LONG_BODY: &str = indoc!(
+    "
+    Update bashrc to include kubernetes completions
+
+    This should make it easier to deploy things for the developers, as they no longer need to remember every flag.
+    Benchmarked with Hyperfine, no noticeable performance decrease.
+
+    ```
+    kubectl completion bash > /etc/bash_completion.d/kubectl && echo 'source <(kubectl completion bash)' >> ~/.bashrc
+    ```
+
+    > The kubectl completion script for Bash can be generated with the command kubectl completion bash.
+
+    - Only Bash is supported for now, but zsh and fish could follow in a later change if anyone asks for them
+    - See https://kubernetes.io/docs/tasks/tools/included/optional-kubectl-configs-bash-linux/
+
+    https://kubernetes.io/docs/tasks/tools/included/optional-kubectl-configs-bash-linux/
+
+    Co-authored-by: Billie Thompson <billie@example.com>
+    Relates-to: https://example.com/an/issue/tracker/with/a/very/long/path/to/the/issue/1234
+
+    # Please enter the commit message for your changes. Lines starting with '#' will be ignored.
+    "
+);
+
+#[test]
+fn only_wrappable_lines_are_problems() {
+    let commit = CommitMessage::from(COMMIT_WITH_LONG_BODY);
+    let lines: Vec<usize> = BodyLineLength::default()
+        .check(&commit)
+        .iter()
+        .map(|problem| problem.get_labels()[0].1.get_line())
+        .collect();
+
+    assert_eq!(lines, vec![2, 11, 12]);
+}
+
+#[test]
+fn rewrapping_fixes_every_problem() {
+    let lint = BodyLineLength::default();
+    let rewrapped = lint.rewrap(&CommitMessage::from(COMMIT_WITH_LONG_BODY));
+
+    assert_eq!(
+        String::from(&rewrapped),
+        indoc!(
+            "
+            Update bashrc to include kubernetes completions
+
+            This should make it easier to deploy things for the developers, as they
+            no longer need to remember every flag. Benchmarked with Hyperfine, no
+            noticeable performance decrease.
+
+            ```
+            kubectl completion bash > /etc/bash_completion.d/kubectl && echo 'source <(kubectl completion bash)' >> ~/.bashrc
+            ```
+
+            > The kubectl completion script for Bash can be generated with the command kubectl completion bash.
+
+            - Only Bash is supported for now, but zsh and fish could follow in a
+              later change if anyone asks for them
+            - See
+              https://kubernetes.io/docs/tasks/tools/included/optional-kubectl-configs-bash-linux/
+
+            https://kubernetes.io/docs/tasks/tools/included/optional-kubectl-configs-bash-linux/
+
+            Co-authored-by: Billie Thompson <billie@example.com>
+            Relates-to: https://example.com/an/issue/tracker/with/a/very/long/path/to/the/issue/1234
+
+            # Please enter the commit message for your changes. Lines starting with '#' will be ignored.
+            "
+        )
+    );
+    assert_eq!(lint.check(&rewrapped), vec![]);
+    assert_eq!(
+        rewrapped.get_trailers(),
+        CommitMessage::from(COMMIT_WITH_LONG_BODY).get_trailers()
+    );
+}
@@ -0,0 +1,111 @@
+//! The expected messages in this file were generated with git itself, by
+//! running `git -c core.commentChar=<char> stripspace` for
+//! [`CleanupMode::Whitespace`] and `git -c core.commentChar=<char> stripspace
+//! --strip-comments` for [`CleanupMode::Strip`] on each input, after cutting
+//! the input at the scissors line where there is one.
+
+use mit_commit::{CleanupMode, CommitMessage};
+
+const GERMAN_TEMPLATE: &str = "Update bashrc to include kubernetes completions\n\nThis should make it easier to deploy things for the developers.\nBenchmarked with Hyperfine, no noticable performance decrease.\n\nCo-authored-by: Billie Thomposon <billie@example.com>\nCo-authored-by: Somebody Else <somebody@example.com>\n\n# Bitte geben Sie eine Commit-Beschreibung f\u{00FC}r Ihre \u{00E4}nderungen ein. Zeilen,\n# die mit '#' beginnen, werden ignoriert, und eine leere Beschreibung\n# bricht den Commit ab.\n#\n# Auf Branch master\n#\n# Zum Commit vorgemerkte \u{00E4}nderungen:\n#\tneue Datei:     .bashrc\n#\n";
+const GERMAN_TEMPLATE_STRIPPED: &str = "Update bashrc to include kubernetes completions\n\nThis should make it easier to deploy things for the developers.\nBenchmarked with Hyperfine, no noticable performance decrease.\n\nCo-authored-by: Billie Thomposon <billie@example.com>\nCo-authored-by: Somebody Else <somebody@example.com>\n";
+const GERMAN_TEMPLATE_WHITESPACE: &str = "Update bashrc to include kubernetes completions\n\nThis should make it easier to deploy things for the developers.\nBenchmarked with Hyperfine, no noticable performance decrease.\n\nCo-authored-by: Billie Thomposon <billie@example.com>\nCo-authored-by: Somebody Else <somebody@example.com>\n\n# Bitte geben Sie eine Commit-Beschreibung f\u{00FC}r Ihre \u{00E4}nderungen ein. Zeilen,\n# die mit '#' beginnen, werden ignoriert, und eine leere Beschreibung\n# bricht den Commit ab.\n#\n# Auf Branch master\n#\n# Zum Commit vorgemerkte \u{00E4}nderungen:\n#\tneue Datei:     .bashrc\n#\n";
+
+const MESSY_WHITESPACE: &str = "\n\n  \nSubject with trailing space  \t\n\n\n\nBody line\t \n    indented code   \n\n\n\n; comment between paragraphs\n\nSecond paragraph\n\n\n; Bitte geben Sie eine Commit-Beschreibung ein.\n;\n";
+const MESSY_WHITESPACE_STRIPPED: &str =
+    "Subject with trailing space\n\nBody line\n    indented code\n\nSecond paragraph\n";
+const MESSY_WHITESPACE_WHITESPACE: &str = "Subject with trailing space\n\nBody line\n    indented code\n\n; comment between paragraphs\n\nSecond paragraph\n\n; Bitte geben Sie eine Commit-Beschreibung ein.\n;\n";
+
+const INTERLEAVED_COMMENTS: &str = "Subject\n# comment directly after subject\nBody without gutter\n\n# comment\n\n\n# comment\nTrailer: value\n";
+const INTERLEAVED_COMMENTS_STRIPPED: &str = "Subject\nBody without gutter\n\nTrailer: value\n";
+const INTERLEAVED_COMMENTS_WHITESPACE: &str = "Subject\n# comment directly after subject\nBody without gutter\n\n# comment\n\n# comment\nTrailer: value\n";
+
+const ONLY_COMMENTS: &str =
+    "\n# Please enter the commit message for your changes.\n#\n# On branch main\n";
+const ONLY_COMMENTS_STRIPPED: &str = "";
+const ONLY_COMMENTS_WHITESPACE: &str =
+    "# Please enter the commit message for your changes.\n#\n# On branch main\n";
+
+const VERBOSE: &str = "Add file\n\nThis adds a file   \n\n\n# Please enter the commit message for your changes.\n#\n# On branch main\n# ------------------------ >8 ------------------------\n# Do not modify or remove the line above.\n# Everything below it will be ignored.\ndiff --git a/file b/file\nnew file mode 100644\n+hello   \n";
+const VERBOSE_STRIPPED: &str = "Add file\n\nThis adds a file\n";
+const VERBOSE_WHITESPACE: &str = "Add file\n\nThis adds a file\n\n# Please enter the commit message for your changes.\n#\n# On branch main\n";
+const VERBOSE_VERBATIM: &str = "Add file\n\nThis adds a file   \n\n\n# Please enter the commit message for your changes.\n#\n# On branch main\n";
+
+fn assert_cleans_up_like_git(input: &str, stripped: &str, whitespace: &str, verbatim: &str) {
+    let message = CommitMessage::from(input);
+
+    assert_eq!(
+        message.cleanup(CleanupMode::Strip),
+        stripped,
+        "Strip should match git stripspace --strip-comments"
+    );
+    assert_eq!(
+        message.cleanup(CleanupMode::Default),
+        stripped,
+        "Default should match git stripspace --strip-comments"
+    );
+    assert_eq!(
+        message.cleanup(CleanupMode::Whitespace),
+        whitespace,
+        "Whitespace should match git stripspace"
+    );
+    assert_eq!(
+        message.cleanup(CleanupMode::Scissors),
+        whitespace,
+        "Scissors should match git stripspace"
+    );
+    assert_eq!(
+        message.cleanup(CleanupMode::Verbatim),
+        verbatim,
+        "Verbatim should leave the message before the scissors untouched"
+    );
+}
+
+#[test]
+fn german_template_is_cleaned_up_like_git() {
+    assert_cleans_up_like_git(
+        GERMAN_TEMPLATE,
+        GERMAN_TEMPLATE_STRIPPED,
+        GERMAN_TEMPLATE_WHITESPACE,
+        GERMAN_TEMPLATE,
+    );
+}
+
+#[test]
+fn messy_whitespace_is_cleaned_up_like_git() {
+    assert_cleans_up_like_git(
+        MESSY_WHITESPACE,
+        MESSY_WHITESPACE_STRIPPED,
+        MESSY_WHITESPACE_WHITESPACE,
+        MESSY_WHITESPACE,
+    );
+}
+
+#[test]
+fn interleaved_comments_are_cleaned_up_like_git() {
+    assert_cleans_up_like_git(
+        INTERLEAVED_COMMENTS,
+        INTERLEAVED_COMMENTS_STRIPPED,
+        INTERLEAVED_COMMENTS_WHITESPACE,
+        INTERLEAVED_COMMENTS,
+    );
+}
+
+#[test]
+fn only_comments_are_cleaned_up_like_git() {
+    assert_cleans_up_like_git(
+        ONLY_COMMENTS,
+        ONLY_COMMENTS_STRIPPED,
+        ONLY_COMMENTS_WHITESPACE,
+        ONLY_COMMENTS,
+    );
+}
+
+#[test]
+fn verbose_commit_is_cut_at_the_scissors_like_git() {
+    assert_cleans_up_like_git(
+        VERBOSE,
+        VERBOSE_STRIPPED,
+        VERBOSE_WHITESPACE,
+        VERBOSE_VERBATIM,
+    );
+}
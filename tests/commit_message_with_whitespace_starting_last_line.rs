@@ -94,16 +94,22 @@ fn can_get_comments_from_a_commit_message_with_trailing_whitespace() {
 #[test]
 fn can_get_trailers_from_a_commit_message_with_trailing_whitespace() {
     let message = CommitMessage::from(COMMIT_MESSAGE_WITH_WHITESPACE_STARTING_LAST_LINE);
-    let trailers: Vec<Trailer> = vec![
-        Trailer::new(
-            "Co-authored-by".into(),
-            "Billie Thomposon <billie@example.com>".into(),
-        ),
-        Trailer::new(
-            " Co-authored-by".into(),
-            "Somebody Else <somebody@example.com>".into(),
-        ),
-    ];
+    let trailers: Vec<Trailer> = vec![Trailer::new(
+        "Co-authored-by".into(),
+        "Billie Thomposon <billie@example.com>\n Co-authored-by: Somebody Else <somebody@example.com>"
+            .into(),
+    )];
 
     assert_eq!(message.get_trailers(), Trailers::from(trailers));
+    assert_eq!(
+        message
+            .get_trailers()
+            .iter()
+            .map(Trailer::get_unfolded_value)
+            .collect::<Vec<_>>(),
+        vec![
+            "Billie Thomposon <billie@example.com> Co-authored-by: Somebody Else <somebody@example.com>"
+        ],
+        "A line starting with whitespace continues the trailer above it, as in git interpret-trailers"
+    );
 }
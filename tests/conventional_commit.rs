@@ -0,0 +1,168 @@
+use std::convert::TryFrom;
+
+use indoc::indoc;
+use mit_commit::{CommitMessage, ConventionalCommit, ConventionalCommitError, Trailer};
+
+const CONVENTIONAL_COMMIT: &str = indoc!(
+    "
+    feat(lang)!: add Polish language
+
+    Polish is now available in the language picker.
+
+    The translations were reviewed by a native speaker.
+
+    BREAKING CHANGE: the language codes are now
+    ISO 639-1, so existing settings need migrating
+    Refs #133
+    Reviewed-by: Z
+
+    # Please enter the commit message for your changes. Lines starting
+    # with '#' will be ignored, and an empty message aborts the commit.
+    "
+);
+
+#[test]
+fn it_reads_the_header() {
+    let commit = CommitMessage::from(CONVENTIONAL_COMMIT);
+    let conventional = ConventionalCommit::try_from(&commit).expect("should be conventional");
+
+    assert_eq!(conventional.get_type(), "feat");
+    assert_eq!(conventional.get_scope(), Some("lang"));
+    assert!(conventional.has_breaking_marker());
+    assert_eq!(conventional.get_description(), "add Polish language");
+}
+
+#[test]
+fn it_reads_the_body_paragraphs() {
+    let commit = CommitMessage::from(CONVENTIONAL_COMMIT);
+    let conventional = ConventionalCommit::try_from(&commit).expect("should be conventional");
+
+    assert_eq!(
+        conventional
+            .get_body()
+            .iter()
+            .map(|body| body.to_string())
+            .collect::<Vec<_>>(),
+        vec![
+            "Polish is now available in the language picker.",
+            "The translations were reviewed by a native speaker.",
+        ]
+    );
+}
+
+#[test]
+fn it_reads_multi_line_and_hash_footers() {
+    let commit = CommitMessage::from(CONVENTIONAL_COMMIT);
+    let conventional = ConventionalCommit::try_from(&commit).expect("should be conventional");
+
+    assert_eq!(
+        conventional.get_footers(),
+        vec![
+            Trailer::new(
+                "BREAKING CHANGE".into(),
+                "the language codes are now\nISO 639-1, so existing settings need migrating".into(),
+            ),
+            Trailer::new_with_separator("Refs".into(), "133".into(), " #".into()),
+            Trailer::new("Reviewed-by".into(), "Z".into()),
+        ]
+        .into()
+    );
+    assert_eq!(
+        conventional.get_breaking_change(),
+        Some("the language codes are now\nISO 639-1, so existing settings need migrating".into())
+    );
+}
+
+#[test]
+fn footer_spans_cover_continuation_lines() {
+    let commit = CommitMessage::from(CONVENTIONAL_COMMIT);
+    let conventional = ConventionalCommit::try_from(&commit).expect("should be conventional");
+    let footers = conventional.get_footers();
+    let span = footers
+        .iter()
+        .next()
+        .and_then(Trailer::get_span)
+        .expect("footer should have a span");
+
+    assert_eq!(
+        &CONVENTIONAL_COMMIT[span.get_offset()..span.get_end()],
+        "BREAKING CHANGE: the language codes are now\nISO 639-1, so existing settings need migrating"
+    );
+}
+
+#[test]
+fn breaking_change_can_be_a_footer_only() {
+    let commit = CommitMessage::from("fix: use the new API\n\nBREAKING-CHANGE: needs v2\n");
+    let conventional = ConventionalCommit::try_from(&commit).expect("should be conventional");
+
+    assert!(!conventional.has_breaking_marker());
+    assert!(conventional.is_breaking_change());
+    assert_eq!(conventional.get_breaking_change(), Some("needs v2".into()));
+}
+
+#[test]
+fn a_final_paragraph_that_is_not_footers_is_body() {
+    let commit = CommitMessage::from("docs: explain things\n\nSee: the docs\nfor more\n");
+    let conventional = ConventionalCommit::try_from(&commit).expect("should be conventional");
+
+    assert_eq!(conventional.get_footers().len(), 1);
+
+    let commit = CommitMessage::from("docs: explain things\n\nThis is prose: with a colon\n");
+    let conventional = ConventionalCommit::try_from(&commit).expect("should be conventional");
+
+    assert!(conventional.get_footers().is_empty());
+    assert_eq!(conventional.get_body().iter().count(), 1);
+    assert!(!conventional.is_breaking_change());
+    assert_eq!(conventional.get_breaking_change(), None);
+}
+
+#[test]
+fn malformed_headers_are_errors_pointing_at_the_problem() {
+    let commit = CommitMessage::from("feat(parser: add a thing\n\nBody\n");
+    let error = ConventionalCommit::try_from(&commit).expect_err("should not be conventional");
+
+    assert!(
+        matches!(error, ConventionalCommitError::UnclosedScope(_, (4, 20))),
+        "Unexpected error {error:?}"
+    );
+
+    let commit = CommitMessage::from("# A comment\nfeat add a thing\n");
+    let error = ConventionalCommit::try_from(&commit).expect_err("should not be conventional");
+
+    assert!(
+        matches!(error, ConventionalCommitError::MissingSeparator(_, (16, 1))),
+        "Offsets should be from the start of the message, got {error:?}"
+    );
+
+    let commit = CommitMessage::from("Add a thing\n");
+    let error = ConventionalCommit::try_from(&commit).expect_err("should not be conventional");
+
+    assert!(
+        matches!(error, ConventionalCommitError::MissingSeparator(_, (3, 1))),
+        "Unexpected error {error:?}"
+    );
+
+    let commit = CommitMessage::from("fix(): \n");
+    let error = ConventionalCommit::try_from(&commit).expect_err("should not be conventional");
+
+    assert!(
+        matches!(error, ConventionalCommitError::EmptyScope(_, (3, 2))),
+        "Unexpected error {error:?}"
+    );
+
+    let commit = CommitMessage::from("feat: add a thing\nthat runs on\n\nBody\n");
+    let error = ConventionalCommit::try_from(&commit).expect_err("should not be conventional");
+
+    assert!(
+        matches!(error, ConventionalCommitError::ExtraLines(_, (18, 12))),
+        "Lines after the header should not be dropped, got {error:?}"
+    );
+
+    let commit = CommitMessage::from("# Only a comment\n");
+    let error = ConventionalCommit::try_from(&commit).expect_err("should not be conventional");
+
+    assert!(
+        matches!(error, ConventionalCommitError::MissingType(_, (17, 0))),
+        "Unexpected error {error:?}"
+    );
+}
@@ -0,0 +1,120 @@
+use indoc::indoc;
+use mit_commit::{Bodies, Body, CommitMessage, Span, Trailer, Trailers};
+
+const FOLDED_TRAILERS: &str = indoc!(
+    "
+    Update bashrc to include kubernetes completions
+
+    This should make it easier to deploy things for the developers.
+    Benchmarked with Hyperfine, no noticable performance decrease.
+
+    Co-authored-by: Billie Thomposon
+      <billie@example.com>
+    Signed-off-by: Somebody Else with a really quite long name that
+    \tgoes on <somebody@example.com>
+
+    # Bitte geben Sie eine Commit-Beschreibung f\u{00FC}r Ihre \u{00E4}nderungen ein. Zeilen,
+    # die mit '#' beginnen, werden ignoriert, und eine leere Beschreibung
+    # bricht den Commit ab.
+    "
+);
+
+#[test]
+fn can_reliably_parse_from_folded_trailers() {
+    let first_commit_message = CommitMessage::from(FOLDED_TRAILERS);
+    let string_version_of_commit = String::from(first_commit_message.clone());
+    let second_commit_message = CommitMessage::from(string_version_of_commit.clone());
+
+    assert_eq!(string_version_of_commit, FOLDED_TRAILERS);
+    assert_eq!(first_commit_message, second_commit_message);
+}
+
+#[test]
+fn can_get_trailers_from_folded_trailers() {
+    let message = CommitMessage::from(FOLDED_TRAILERS);
+    let trailers: Vec<Trailer> = vec![
+        Trailer::new(
+            "Co-authored-by".into(),
+            "Billie Thomposon\n  <billie@example.com>".into(),
+        ),
+        Trailer::new(
+            "Signed-off-by".into(),
+            "Somebody Else with a really quite long name that\n\tgoes on <somebody@example.com>"
+                .into(),
+        ),
+    ];
+
+    assert_eq!(message.get_trailers(), Trailers::from(trailers));
+}
+
+#[test]
+fn can_get_unfolded_values_from_folded_trailers() {
+    let message = CommitMessage::from(FOLDED_TRAILERS);
+
+    assert_eq!(
+        message
+            .get_trailers()
+            .iter()
+            .map(Trailer::get_unfolded_value)
+            .collect::<Vec<_>>(),
+        vec![
+            "Billie Thomposon <billie@example.com>",
+            "Somebody Else with a really quite long name that goes on <somebody@example.com>",
+        ]
+    );
+}
+
+#[test]
+fn folded_trailers_span_all_their_lines() {
+    let message = CommitMessage::from(FOLDED_TRAILERS);
+    let trailers = message.get_trailers();
+
+    assert_eq!(
+        trailers.iter().map(Trailer::get_span).collect::<Vec<_>>(),
+        vec![
+            Some(Span::new(177, 55, 5, 0)),
+            Some(Span::new(233, 95, 7, 0))
+        ]
+    );
+    assert_eq!(
+        trailers
+            .iter()
+            .filter_map(Trailer::get_span)
+            .map(|span| &FOLDED_TRAILERS[span.get_offset()..span.get_end()])
+            .collect::<Vec<_>>(),
+        trailers
+            .iter()
+            .cloned()
+            .map(String::from)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn can_get_bodies_from_folded_trailers() {
+    let message = CommitMessage::from(FOLDED_TRAILERS);
+    let bodies = vec![
+        Body::default(),
+        Body::from(indoc!(
+            "
+            This should make it easier to deploy things for the developers.
+            Benchmarked with Hyperfine, no noticable performance decrease."
+        )),
+    ];
+
+    assert_eq!(message.get_body(), Bodies::from(bodies));
+}
+
+#[test]
+fn adding_a_trailer_keeps_the_folding() {
+    let message = CommitMessage::from(FOLDED_TRAILERS)
+        .add_trailer(Trailer::new("Relates-to".into(), "#128".into()));
+
+    assert_eq!(
+        String::from(message),
+        FOLDED_TRAILERS.replace(
+            "<somebody@example.com>\n",
+            "<somebody@example.com>\nRelates-to: #128\n"
+        )
+    );
+}
@@ -0,0 +1,172 @@
+use mit_commit::{
+    CommentMarker, CommitMessage, ParseOptions,
+    git_status::{Change, Entry, Head, InProgress, Tracking, Upstream},
+};
+use quickcheck_macros::quickcheck;
+
+const REBASE_TEMPLATE: &str = concat!(
+    "Read the config from the repository\n",
+    "\n",
+    "# Please enter the commit message for your changes. Lines starting\n",
+    "# with '#' will be ignored, and an empty message aborts the commit.\n",
+    "#\n",
+    "# interactive rebase in progress; onto 5f3c0a1\n",
+    "# Last command done (1 command done):\n",
+    "#    pick 0e8d6c4 Read the config\n",
+    "# No commands remaining.\n",
+    "# You are currently editing a commit while rebasing branch 'config' on '5f3c0a1'.\n",
+    "#\n",
+    "# Changes to be committed:\n",
+    "#\trenamed:    config.toml -> .mit-commit.toml\n",
+    "#\tnew file:   src/config.rs\n",
+    "#\tdeleted:    src/settings.rs\n",
+    "#\n",
+    "# Changes not staged for commit:\n",
+    "#\tmodified:   README.md\n",
+    "#\ttypechange: scripts/run\n",
+    "#\n",
+    "# Untracked files:\n",
+    "#\t\"caf\\303\\251.txt\"\n",
+    "#\tnotes/\n",
+    "#\n",
+);
+
+#[test]
+fn a_rebase_template_is_read() {
+    let commit = CommitMessage::from(REBASE_TEMPLATE);
+    let comments = commit.get_comments();
+    let status = comments.git_status();
+
+    assert_eq!(status.get_head(), None);
+    assert_eq!(
+        status.get_in_progress(),
+        Some(&InProgress::Rebase {
+            branch: Some("config".into()),
+            onto: Some("5f3c0a1".into())
+        })
+    );
+    assert_eq!(
+        status.get_staged(),
+        &[
+            Entry::new(
+                Change::Renamed,
+                ".mit-commit.toml".into(),
+                Some("config.toml".into())
+            ),
+            Entry::new(Change::Added, "src/config.rs".into(), None),
+            Entry::new(Change::Deleted, "src/settings.rs".into(), None),
+        ]
+    );
+    assert_eq!(
+        status.get_unstaged(),
+        &[
+            Entry::new(Change::Modified, "README.md".into(), None),
+            Entry::new(Change::TypeChanged, "scripts/run".into(), None),
+        ]
+    );
+    assert_eq!(status.get_untracked(), &["café.txt", "notes/"]);
+}
+
+#[test]
+fn a_merge_with_conflicts_is_read() {
+    let commit = CommitMessage::from(concat!(
+        "Merge branch 'config'\n",
+        "\n",
+        "# Conflicts:\n",
+        "#\tsrc/config.rs\n",
+        "#\n",
+        "# It looks like you may be committing a merge.\n",
+        "# If this is not correct, please run\n",
+        "#\tgit update-ref -d MERGE_HEAD\n",
+        "# and try again.\n",
+        "#\n",
+        "#\n",
+        "# Please enter the commit message for your changes. Lines starting\n",
+        "# with '#' will be ignored, and an empty message aborts the commit.\n",
+        "#\n",
+        "# On branch main\n",
+        "# Your branch is behind 'origin/main' by 1 commit, and can be fast-forwarded.\n",
+        "#\n",
+        "# All conflicts fixed but you are still merging.\n",
+        "#\n",
+        "# Unmerged paths:\n",
+        "#\tboth modified:   src/config.rs\n",
+        "#\tdeleted by them: src/settings.rs\n",
+        "#\n",
+    ));
+    let comments = commit.get_comments();
+    let status = comments.git_status();
+
+    assert_eq!(status.get_branch(), Some("main"));
+    assert_eq!(
+        status.get_upstream(),
+        Some(&Upstream::new("origin/main".into(), Tracking::Behind(1)))
+    );
+    assert_eq!(status.get_in_progress(), Some(&InProgress::Merge));
+    assert_eq!(
+        status.get_unmerged(),
+        &[
+            Entry::new(Change::BothModified, "src/config.rs".into(), None),
+            Entry::new(Change::DeletedByThem, "src/settings.rs".into(), None),
+        ]
+    );
+    assert!(
+        status.get_staged().is_empty(),
+        "Files under other headings aren't staged"
+    );
+}
+
+#[test]
+fn any_comment_marker_can_be_used() {
+    let commit = CommitMessage::parse_with(
+        concat!(
+            "Add a config file\n",
+            "\n",
+            "// On branch main\n",
+            "// Your branch is based on 'origin/main', but the upstream is gone.\n",
+            "//\n",
+            "// Changes to be committed:\n",
+            "//\tnew file:   .mit-commit.toml\n",
+            "//\n",
+        ),
+        ParseOptions::default().with_comment_marker(CommentMarker::from("//")),
+    );
+    let comments = commit.get_comments();
+    let status = comments.git_status();
+
+    assert_eq!(status.get_head(), Some(&Head::Branch("main".into())));
+    assert_eq!(
+        status.get_upstream().map(Upstream::get_tracking),
+        Some(Tracking::Gone)
+    );
+    assert_eq!(
+        status.get_staged(),
+        &[Entry::new(Change::Added, ".mit-commit.toml".into(), None)]
+    );
+}
+
+#[test]
+fn messages_without_a_status_have_an_empty_one() {
+    let commit = CommitMessage::from("Add a config file\n\n# Just a note\n");
+    let comments = commit.get_comments();
+
+    assert_eq!(comments.git_status(), Default::default());
+}
+
+#[test]
+fn statuses_can_outlive_the_message() {
+    let status = {
+        let commit = CommitMessage::from(REBASE_TEMPLATE);
+        commit.get_comments().git_status().into_owned()
+    };
+
+    assert_eq!(status.get_staged().len(), 3);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn never_panic(input: String) -> bool {
+    let commit = CommitMessage::from(input.as_str());
+    let _ = commit.get_comments().git_status();
+    true
+}
@@ -0,0 +1,128 @@
+//! The expected trailers in these tests were generated by running
+//! `git interpret-trailers --parse` on each message
+
+use mit_commit::{CommitMessage, ParseOptions, TrailerDetection};
+
+fn assert_parses_trailers_like_git(message: &str, expected: &[(&str, &str)]) {
+    let options = ParseOptions::default().with_trailer_detection(TrailerDetection::Git);
+    let commit = CommitMessage::parse_with(message, options);
+    let actual: Vec<(String, String)> = commit
+        .get_trailers()
+        .iter()
+        .map(|trailer| (trailer.get_key(), trailer.get_unfolded_value()))
+        .collect();
+    let expected: Vec<(String, String)> = expected
+        .iter()
+        .map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+        .collect();
+
+    assert_eq!(actual, expected, "Trailers differ from git for {message:?}");
+    assert_eq!(
+        String::from(commit),
+        message,
+        "Parsing with git trailer detection should not change the message"
+    );
+}
+
+#[test]
+fn a_quarter_trailers_with_a_signoff_is_a_trailer_block() {
+    assert_parses_trailers_like_git(
+        "Subject\n\nSome body text\nthat is not a trailer\nat all, for three lines\nSigned-off-by: A U Thor <author@example.com>\n",
+        &[("Signed-off-by", "A U Thor <author@example.com>")],
+    );
+}
+
+#[test]
+fn less_than_a_quarter_trailers_is_not_a_trailer_block() {
+    assert_parses_trailers_like_git(
+        "Subject\n\nnot a trailer\nnor this\nnor this one\nnor this either\nSigned-off-by: A U Thor <author@example.com>\n",
+        &[],
+    );
+}
+
+#[test]
+fn cherry_picked_line_counts_as_git_generated() {
+    assert_parses_trailers_like_git(
+        "Subject\n\nBody text\nmore body\n(cherry picked from commit 1234567890abcdef)\nFixes: #123\n",
+        &[("Fixes", "#123")],
+    );
+}
+
+#[test]
+fn cherry_picked_line_is_not_itself_a_trailer() {
+    assert_parses_trailers_like_git(
+        "Subject\n\nBody text\n(cherry picked from commit 1234567890abcdef)\n",
+        &[],
+    );
+}
+
+#[test]
+fn a_paragraph_of_only_trailers_is_a_trailer_block() {
+    assert_parses_trailers_like_git(
+        "Subject\n\nReviewed-by: Someone <someone@example.com>\nFixes: #123\n",
+        &[
+            ("Reviewed-by", "Someone <someone@example.com>"),
+            ("Fixes", "#123"),
+        ],
+    );
+}
+
+#[test]
+fn mixed_paragraph_without_git_generated_trailer_is_not_a_trailer_block() {
+    assert_parses_trailers_like_git(
+        "Subject\n\nSome body text\nReviewed-by: Someone <someone@example.com>\n",
+        &[],
+    );
+    assert_parses_trailers_like_git("Subject\n\nBody text\nmore body\nFixes: #123\n", &[]);
+}
+
+#[test]
+fn lines_after_a_divider_are_ignored() {
+    assert_parses_trailers_like_git(
+        "Subject\n\nReviewed-by: Someone <someone@example.com>\n---\n file.txt | 1 +\n",
+        &[("Reviewed-by", "Someone <someone@example.com>")],
+    );
+}
+
+#[test]
+fn continuation_lines_are_folded_into_the_trailer() {
+    assert_parses_trailers_like_git(
+        "Subject\n\nReviewed-by: Someone\n  <someone@example.com>\nFixes: #123\n",
+        &[
+            ("Reviewed-by", "Someone <someone@example.com>"),
+            ("Fixes", "#123"),
+        ],
+    );
+}
+
+#[test]
+fn comments_in_and_after_the_block_are_skipped() {
+    assert_parses_trailers_like_git(
+        "Subject\n\nReviewed-by: Someone <someone@example.com>\n# A comment\nFixes: #123\n\n# More comments\n",
+        &[
+            ("Reviewed-by", "Someone <someone@example.com>"),
+            ("Fixes", "#123"),
+        ],
+    );
+}
+
+#[test]
+fn trailers_in_the_subject_paragraph_are_ignored() {
+    assert_parses_trailers_like_git("Subject\nReviewed-by: Someone <someone@example.com>\n", &[]);
+}
+
+#[test]
+fn whitespace_around_the_separator_is_allowed() {
+    assert_parses_trailers_like_git(
+        "Subject\n\nReviewed-by : Someone <someone@example.com>\nFixes:#123\n",
+        &[
+            ("Reviewed-by", "Someone <someone@example.com>"),
+            ("Fixes", "#123"),
+        ],
+    );
+}
+
+#[test]
+fn only_colon_is_a_separator_by_default() {
+    assert_parses_trailers_like_git("Subject\n\nBody\n\nFixes=123\n", &[]);
+}
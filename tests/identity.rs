@@ -0,0 +1,131 @@
+use std::convert::TryFrom;
+
+use indoc::indoc;
+use miette::Diagnostic;
+use mit_commit::{
+    CommitMessage, Identity, IdentityError, RawCommit, Signature, SignatureError, Span,
+};
+
+const COMMIT_WITH_CO_AUTHORS: &str = indoc!(
+    "
+    Pair on the parser
+
+    Co-authored-by: Billie Thompson <billie@example.com>
+    Co-authored-by: Someone Else <someone@example>
+    Signed-off-by: Billie Thompson <billie@example.com>
+    "
+);
+
+const OBJECT: &str = concat!(
+    "tree 4b825dc642cb6eb9a060e54bf8d69288fbee4904\n",
+    "author Billie Thompson <billie@example.com> 1592073120 +0100\n",
+    "committer Someone Else <someone@example.com> 1592073180 -0000\n",
+    "\n",
+    "Subject\n",
+);
+
+#[test]
+fn co_authors_are_identities_pointing_into_the_message() {
+    let commit = CommitMessage::from(COMMIT_WITH_CO_AUTHORS);
+    let trailers = commit.get_trailers();
+    let co_authors = trailers
+        .get_all("Co-authored-by")
+        .into_iter()
+        .map(Identity::try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .expect("the co-authors are valid");
+
+    assert_eq!(
+        co_authors,
+        vec![
+            Identity::new("Billie Thompson".into(), "billie@example.com".into()),
+            Identity::new("Someone Else".into(), "someone@example".into()),
+        ]
+    );
+    assert_eq!(co_authors[1].get_span(), Some(Span::new(89, 30, 3, 16)));
+    assert_eq!(
+        &COMMIT_WITH_CO_AUTHORS[89..119],
+        "Someone Else <someone@example>"
+    );
+}
+
+#[test]
+fn malformed_co_authors_are_errors_pointing_at_the_email() {
+    let commit = CommitMessage::from("Subject\n\nCo-authored-by: Someone <someone at example>\n");
+    let trailers = commit.get_trailers();
+    let error = trailers
+        .iter()
+        .next()
+        .map(Identity::try_from)
+        .expect("there is a trailer")
+        .expect_err("the email is not valid");
+
+    assert!(
+        matches!(error, IdentityError::InvalidEmail(..)),
+        "Unexpected error {error:?}"
+    );
+    assert_eq!(
+        error
+            .labels()
+            .into_iter()
+            .flatten()
+            .map(|label| (label.offset(), label.len()))
+            .collect::<Vec<_>>(),
+        vec![(9, 18)]
+    );
+}
+
+#[test]
+fn author_and_committer_are_signatures_pointing_into_the_object() {
+    let commit = RawCommit::try_from(OBJECT).expect("the object is valid");
+    let author = commit
+        .get_header("author")
+        .map(Signature::try_from)
+        .expect("there is an author")
+        .expect("the author is valid");
+    let committer = commit
+        .get_header("committer")
+        .map(Signature::try_from)
+        .expect("there is a committer")
+        .expect("the committer is valid");
+
+    assert_eq!(
+        author.get_identity(),
+        &Identity::new("Billie Thompson".into(), "billie@example.com".into())
+    );
+    assert_eq!(author.get_timestamp(), 1_592_073_120);
+    assert_eq!(committer.get_offset_minutes(), 0);
+
+    let span = committer.get_span().expect("the committer was parsed");
+    assert_eq!(
+        &OBJECT[span.get_offset()..span.get_end()],
+        "Someone Else <someone@example.com> 1592073180 -0000"
+    );
+    assert_eq!(String::from(committer), &OBJECT[117..168]);
+}
+
+#[test]
+fn malformed_signatures_are_errors() {
+    let error = Signature::try_from("Billie Thompson <billie@example.com> yesterday +0100")
+        .expect_err("the timestamp is not valid");
+
+    assert!(
+        matches!(error, SignatureError::InvalidTimestamp(..)),
+        "Unexpected error {error:?}"
+    );
+
+    let error =
+        Signature::try_from("<billie@example.com> 1592073120 +0100").expect_err("there is no name");
+
+    assert!(
+        matches!(
+            error,
+            SignatureError::Identity(IdentityError::MissingName(..))
+        ),
+        "Unexpected error {error:?}"
+    );
+    assert_eq!(
+        error.code().map(|code| code.to_string()),
+        Some("mit_commit::identity::error::missing_name".into())
+    );
+}
@@ -0,0 +1,100 @@
+use indoc::indoc;
+use miette::Diagnostic;
+use mit_commit::{
+    CommitMessage,
+    lint::{Lint, Problem, Registry},
+};
+
+const COMMIT_WITH_TRAILERS: &str = indoc!(
+    "
+    Update bashrc to include kubernetes completions
+
+    This should make it easier to deploy things for the developers.
+
+    Co-authored-by: Billie Thompson <billie@example.com>
+    Relates-to: #128
+
+    # Please enter the commit message for your changes. Lines starting
+    # with '#' will be ignored, and an empty message aborts the commit.
+    "
+);
+
+#[derive(Debug)]
+struct NoRelatesTo;
+
+impl Lint for NoRelatesTo {
+    fn get_code(&self) -> &str {
+        "no-relates-to"
+    }
+
+    fn check(&self, commit: &CommitMessage<'_>) -> Vec<Problem> {
+        commit
+            .get_trailers()
+            .get_all("relates-to")
+            .into_iter()
+            .map(|trailer| {
+                Problem::new(self.get_code(), "issues should be linked with Fixes")
+                    .with_label("this trailer", trailer.get_span().unwrap_or_default())
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+struct SubjectTooLong;
+
+impl Lint for SubjectTooLong {
+    fn get_code(&self) -> &str {
+        "subject-too-long"
+    }
+
+    fn check(&self, commit: &CommitMessage<'_>) -> Vec<Problem> {
+        if commit.get_subject().len() > 40 {
+            vec![Problem::new(self.get_code(), "the subject is too long")]
+        } else {
+            vec![]
+        }
+    }
+}
+
+#[test]
+fn the_runner_collects_problems_from_every_enabled_lint() {
+    let registry = Registry::default()
+        .with_lint(NoRelatesTo)
+        .with_lint(SubjectTooLong);
+    let problems = registry.run(&CommitMessage::from(COMMIT_WITH_TRAILERS));
+
+    assert_eq!(
+        problems.iter().map(Problem::get_code).collect::<Vec<_>>(),
+        vec!["no-relates-to", "subject-too-long"]
+    );
+
+    let problems = registry
+        .with_disabled("subject-too-long")
+        .run(&CommitMessage::from(COMMIT_WITH_TRAILERS));
+
+    assert_eq!(
+        problems.iter().map(Problem::get_code).collect::<Vec<_>>(),
+        vec!["no-relates-to"]
+    );
+}
+
+#[test]
+fn problem_labels_point_into_the_message() {
+    let registry = Registry::default().with_lint(NoRelatesTo);
+    let problems = registry.run(&CommitMessage::from(COMMIT_WITH_TRAILERS));
+    let label = problems[0]
+        .labels()
+        .and_then(|mut labels| labels.next())
+        .expect("should have a label");
+
+    assert_eq!(
+        &COMMIT_WITH_TRAILERS[label.offset()..label.offset() + label.len()],
+        "Relates-to: #128"
+    );
+    assert_eq!(
+        problems[0].get_source_code(),
+        Some(COMMIT_WITH_TRAILERS),
+        "The message should be attached so miette can show it"
+    );
+}
@@ -0,0 +1,210 @@
+#![cfg(feature = "config")]
+
+use std::fs;
+
+use indoc::indoc;
+use miette::{Diagnostic, Severity};
+use mit_commit::{
+    CommitMessage,
+    lint::{CONFIG_FILE_NAME, ConfigError, Registry},
+};
+
+const CONFIG: &str = indoc!(
+    r#"
+    [lints.subject-length]
+    soft-limit = 40
+    hard-limit = 60
+
+    [lints.subject-capitalised]
+    enabled = false
+
+    [lints.body-line-length]
+    severity = "advice"
+    limit = 60
+
+    [lints.required-trailers]
+    keys = ["Signed-off-by", "Relates-to"]
+
+    [lints.conventional-commit]
+    severity = "warning"
+    types = ["feat", "fix"]
+    "#
+);
+
+const COMMIT: &str = indoc!(
+    "
+    chore: update bashrc to include kubernetes completions
+
+    This should make it easier to deploy things for the developers, as they
+    no longer need to remember every flag.
+
+    Relates-to: #128
+    "
+);
+
+fn labelled(error: &ConfigError, config: &str) -> Vec<String> {
+    error
+        .labels()
+        .into_iter()
+        .flatten()
+        .map(|label| config[label.offset()..label.offset() + label.len()].to_string())
+        .collect()
+}
+
+#[test]
+fn config_sets_rules_severities_and_parameters() {
+    let registry = Registry::from_toml(CONFIG).expect("the config is valid");
+    let problems = registry.run(&CommitMessage::from(COMMIT));
+
+    assert_eq!(
+        problems
+            .iter()
+            .map(|problem| (problem.get_code(), problem.get_severity()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("subject-length", Severity::Warning),
+            ("body-line-length", Severity::Advice),
+            ("required-trailers", Severity::Error),
+            ("conventional-commit", Severity::Warning),
+        ]
+    );
+    assert!(!registry.is_enabled("subject-capitalised"));
+    assert_eq!(
+        problems[2].get_error(),
+        "the Signed-off-by trailer is missing"
+    );
+}
+
+#[test]
+fn an_empty_config_gives_the_stock_lints() {
+    let registry = Registry::from_toml("").expect("an empty config is valid");
+
+    assert_eq!(
+        registry.get_codes(),
+        vec![
+            "subject-length",
+            "subject-trailing-period",
+            "subject-capitalised",
+            "subject-blank-line",
+            "subject-no-gutter",
+            "body-line-length",
+        ]
+    );
+}
+
+#[test]
+fn unknown_lints_point_at_the_key() {
+    let config = indoc!(
+        "
+        [lints.subject-length]
+        soft-limit = 50
+
+        [lints.subject-lenght]
+        hard-limit = 72
+        "
+    );
+    let error = Registry::from_toml(config).expect_err("there is no subject-lenght lint");
+
+    assert!(
+        matches!(error, ConfigError::Invalid(..)),
+        "Unexpected error {error:?}"
+    );
+    assert_eq!(labelled(&error, config), vec!["subject-lenght"]);
+    assert!(error.to_string().contains("unknown field `subject-lenght`"));
+}
+
+#[test]
+fn unknown_settings_point_at_the_key() {
+    let config = indoc!(
+        "
+        [lints.body-line-length]
+        max = 72
+        "
+    );
+    let error = Registry::from_toml(config).expect_err("there is no max setting");
+
+    assert_eq!(labelled(&error, config), vec!["max"]);
+}
+
+#[test]
+fn invalid_values_point_at_the_key() {
+    let config = indoc!(
+        "
+        [lints.subject-length]
+        soft-limit = 80
+        hard-limit = 72
+        "
+    );
+    let error = Registry::from_toml(config).expect_err("the limits are the wrong way round");
+
+    assert!(
+        matches!(error, ConfigError::InvalidValue(..)),
+        "Unexpected error {error:?}"
+    );
+    assert_eq!(labelled(&error, config), vec!["soft-limit"]);
+    assert_eq!(
+        error.to_string(),
+        "the soft limit of 80 is more than the hard limit of 72"
+    );
+    assert!(error.help().is_some());
+
+    let config = "[lints.body-line-length]\nlimit = 0\n";
+    let error = Registry::from_toml(config).expect_err("a limit of 0 can't be met");
+
+    assert_eq!(labelled(&error, config), vec!["limit"]);
+}
+
+#[test]
+fn invalid_trailer_keys_and_types_point_at_the_entry() {
+    let config = "[lints.required-trailers]\nkeys = [\"Signed-off-by\", \"Relates to\"]\n";
+    let error = Registry::from_toml(config).expect_err("trailer keys can't have spaces");
+
+    assert_eq!(labelled(&error, config), vec!["\"Relates to\""]);
+
+    let config = "[lints.conventional-commit]\ntypes = [\"feat:\"]\n";
+    let error = Registry::from_toml(config).expect_err("types can't have a colon");
+
+    assert_eq!(labelled(&error, config), vec!["\"feat:\""]);
+}
+
+#[test]
+fn invalid_severities_are_errors() {
+    let config = "[lints.subject-no-gutter]\nseverity = \"fatal\"\n";
+    let error = Registry::from_toml(config).expect_err("there is no fatal severity");
+
+    assert_eq!(labelled(&error, config), vec!["\"fatal\""]);
+}
+
+#[test]
+fn config_is_read_from_the_repository() {
+    let directory = tempfile::tempdir().expect("can make a directory");
+
+    assert_eq!(
+        Registry::from_repository(directory.path())
+            .expect("a missing config is the stock lints")
+            .get_codes()
+            .len(),
+        6
+    );
+
+    fs::write(directory.path().join(CONFIG_FILE_NAME), CONFIG).expect("can write the config");
+
+    assert!(
+        Registry::from_repository(directory.path())
+            .expect("the config is valid")
+            .is_enabled("conventional-commit")
+    );
+}
+
+#[test]
+fn read_errors_name_the_file() {
+    let directory = tempfile::tempdir().expect("can make a directory");
+    let path = directory.path().join("missing.toml");
+    let error = Registry::from_toml_file(&path).expect_err("the file doesn't exist");
+
+    assert!(
+        matches!(&error, ConfigError::Io { path: error_path, .. } if *error_path == path),
+        "Unexpected error {error:?}"
+    );
+    assert!(error.to_string().contains(&path.display().to_string()));
+}
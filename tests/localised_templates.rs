@@ -0,0 +1,241 @@
+use mit_commit::{
+    CommitMessage,
+    git_status::{Change, Entry, Tracking, Upstream},
+};
+
+// Written by git 2.39 with LANGUAGE set, for a commit that adds a file and
+// renames another, with a change and a file that aren't staged
+const GERMAN: &str = concat!(
+    "Add a file\n",
+    "\n",
+    "# Bitte geben Sie eine Commit-Beschreibung für Ihre Änderungen ein. Zeilen,\n",
+    "# die mit '#' beginnen, werden ignoriert, und eine leere Beschreibung\n",
+    "# bricht den Commit ab.\n",
+    "#\n",
+    "# Auf Branch main\n",
+    "# Ihr Branch ist 1 Commit vor 'origin/main'.\n",
+    "#   (benutzen Sie \"git push\", um lokale Commits zu publizieren)\n",
+    "#\n",
+    "# Zum Commit vorgemerkte Änderungen:\n",
+    "#\tneue Datei:     c.txt\n",
+    "#\tumbenannt:      old.txt -> new.txt\n",
+    "#\n",
+    "# Änderungen, die nicht zum Commit vorgemerkt sind:\n",
+    "#\tgeändert:       a.txt\n",
+    "#\n",
+    "# Unversionierte Dateien:\n",
+    "#\tuntracked.txt\n",
+    "#\n",
+);
+
+const FRENCH: &str = concat!(
+    "Add a file\n",
+    "\n",
+    "# Veuillez saisir le message de validation pour vos modifications. Les lignes\n",
+    "# commençant par '#' seront ignorées, et un message vide abandonne la validation.\n",
+    "#\n",
+    "# Sur la branche main\n",
+    "# Votre branche est en avance sur 'origin/main' de 1 commit.\n",
+    "#   (utilisez \"git push\" pour publier vos commits locaux)\n",
+    "#\n",
+    "# Modifications qui seront validées :\n",
+    "#\tnouveau fichier\u{a0}: c.txt\n",
+    "#\trenommé\u{a0}:         old.txt -> new.txt\n",
+    "#\n",
+    "# Modifications qui ne seront pas validées :\n",
+    "#\tmodifié\u{a0}:         a.txt\n",
+    "#\n",
+    "# Fichiers non suivis:\n",
+    "#\tuntracked.txt\n",
+    "#\n",
+);
+
+const SPANISH: &str = concat!(
+    "Add a file\n",
+    "\n",
+    "# Por favor ingresa el mensaje del commit para tus cambios. Las\n",
+    "#  líneas que comiencen con '#' serán ignoradas, y un mensaje\n",
+    "#  vacío aborta el commit.\n",
+    "#\n",
+    "# En la rama main\n",
+    "# Tu rama está adelantada a 'origin/main' por 1 commit.\n",
+    "#   (usa \"git push\" para publicar tus commits locales)\n",
+    "#\n",
+    "# Cambios a ser confirmados:\n",
+    "#\tnuevos archivos: c.txt\n",
+    "#\trenombrados:     old.txt -> new.txt\n",
+    "#\n",
+    "# Cambios no rastreados para el commit:\n",
+    "#\tmodificados:     a.txt\n",
+    "#\n",
+    "# Archivos sin seguimiento:\n",
+    "#\tuntracked.txt\n",
+    "#\n",
+);
+
+const SIMPLIFIED_CHINESE: &str = concat!(
+    "Add a file\n",
+    "\n",
+    "# 请为您的变更输入提交说明。以 '#' 开始的行将被忽略，而一个空的提交\n",
+    "# 说明将会终止提交。\n",
+    "#\n",
+    "# 位于分支 main\n",
+    "# 您的分支领先 'origin/main' 共 1 个提交。\n",
+    "#   （使用 \"git push\" 来发布您的本地提交）\n",
+    "#\n",
+    "# 要提交的变更：\n",
+    "#\t新文件：   c.txt\n",
+    "#\t重命名：   old.txt -> new.txt\n",
+    "#\n",
+    "# 尚未暂存以备提交的变更：\n",
+    "#\t修改：     a.txt\n",
+    "#\n",
+    "# 未跟踪的文件:\n",
+    "#\tuntracked.txt\n",
+    "#\n",
+);
+
+const PORTUGUESE: &str = concat!(
+    "Add a file\n",
+    "\n",
+    "# Por favor, introduz a mensagem de memória das tuas alterações.\n",
+    "# Linhas começadas com '#' serão ignoradas, e uma mensagem vazia\n",
+    "# aborta a memorização.\n",
+    "#\n",
+    "# Em ramo main\n",
+    "# Teu ramo está à frente de 'origin/main' por 1 memória.\n",
+    "#   (usa \"git push\" para publicar tuas memórias locais)\n",
+    "#\n",
+    "# Alterações para serem memorizadas:\n",
+    "#\tnovo ficheiro: c.txt\n",
+    "#\trenomeado:     old.txt -> new.txt\n",
+    "#\n",
+    "# Alterações por encenar para memória:\n",
+    "#\tmodificado:    a.txt\n",
+    "#\n",
+    "# Ficheiros desmonitorizados:\n",
+    "#\tuntracked.txt\n",
+    "#\n",
+);
+
+const JAPANESE: &str = concat!(
+    "Add a file\n",
+    "\n",
+    "# Please enter the commit message for your changes. Lines starting\n",
+    "# with '#' will be ignored, and an empty message aborts the commit.\n",
+    "#\n",
+    "# On branch main\n",
+    "# Your branch is ahead of 'origin/main' by 1 commit.\n",
+    "#   (use \"git push\" to publish your local commits)\n",
+    "#\n",
+    "# Changes to be committed:\n",
+    "#\tnew file:   c.txt\n",
+    "#\trenamed:    old.txt -> new.txt\n",
+    "#\n",
+    "# Changes not staged for commit:\n",
+    "#\tmodified:   a.txt\n",
+    "#\n",
+    "# Untracked files:\n",
+    "#\tuntracked.txt\n",
+    "#\n",
+);
+
+fn assert_status(template: &str, locale: &str) {
+    let commit = CommitMessage::from(template);
+    let comments = commit.get_comments();
+    let status = comments.git_status();
+
+    assert_eq!(comments.get_locale(), Some(locale));
+    assert_eq!(status.get_branch(), Some("main"), "{locale}");
+    assert_eq!(
+        status.get_upstream(),
+        Some(&Upstream::new("origin/main".into(), Tracking::Ahead(1))),
+        "{locale}"
+    );
+    assert_eq!(
+        status.get_staged(),
+        &[
+            Entry::new(Change::Added, "c.txt".into(), None),
+            Entry::new(Change::Renamed, "new.txt".into(), Some("old.txt".into())),
+        ],
+        "{locale}"
+    );
+    assert_eq!(
+        status.get_unstaged(),
+        &[Entry::new(Change::Modified, "a.txt".into(), None)],
+        "{locale}"
+    );
+    assert_eq!(status.get_untracked(), &["untracked.txt"], "{locale}");
+}
+
+#[test]
+fn german_templates_are_read() {
+    assert_status(GERMAN, "de");
+}
+
+#[test]
+fn french_templates_are_read() {
+    assert_status(FRENCH, "fr");
+}
+
+#[test]
+fn spanish_templates_are_read() {
+    assert_status(SPANISH, "es");
+}
+
+#[test]
+fn simplified_chinese_templates_are_read() {
+    assert_status(SIMPLIFIED_CHINESE, "zh_CN");
+}
+
+#[test]
+fn portuguese_templates_are_read() {
+    assert_status(PORTUGUESE, "pt_PT");
+}
+
+#[test]
+fn languages_git_has_no_translation_for_are_english() {
+    assert_status(JAPANESE, "en");
+}
+
+#[test]
+fn lower_case_german_templates_are_read() {
+    let commit = CommitMessage::from(concat!(
+        "Initial Commit\n",
+        "\n",
+        "; Bitte geben Sie eine Commit-Beschreibung f\u{fc}r Ihre \u{e4}nderungen ein. Zeilen,\n",
+        "; die mit ';' beginnen, werden ignoriert, und eine leere Beschreibung\n",
+        "; bricht den Commit ab.\n",
+        ";\n",
+        "; Auf Branch master\n",
+        ";\n",
+        "; Initialer Commit\n",
+        ";\n",
+        "; Zum Commit vorgemerkte \u{e4}nderungen:\n",
+        ";\tneue Datei:     src/lib.rs\n",
+        ";\n",
+        "; \u{e4}nderungen, die nicht zum Commit vorgemerkt sind:\n",
+        ";\tge\u{e4}ndert:       src/lib.rs\n",
+        ";\n",
+    ));
+    let comments = commit.get_comments();
+    let status = comments.git_status();
+
+    assert_eq!(comments.get_locale(), Some("de"));
+    assert_eq!(status.get_branch(), Some("master"));
+    assert_eq!(
+        status.get_staged(),
+        &[Entry::new(Change::Added, "src/lib.rs".into(), None)]
+    );
+    assert_eq!(
+        status.get_unstaged(),
+        &[Entry::new(Change::Modified, "src/lib.rs".into(), None)]
+    );
+}
+
+#[test]
+fn messages_without_a_template_have_no_locale() {
+    let commit = CommitMessage::from("Add a file\n\n# A note of my own\n");
+
+    assert_eq!(commit.get_comments().get_locale(), None);
+}
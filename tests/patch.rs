@@ -0,0 +1,366 @@
+use std::convert::TryFrom;
+
+use mit_commit::{
+    CommentMarker, CommitMessage, ParseOptions, RawCommit, Signature, Subject,
+    lint::Registry,
+    patch::{Mbox, Patch},
+};
+use quickcheck_macros::quickcheck;
+
+const SERIES: &str = concat!(
+    "From 0000000000000000000000000000000000000000 Mon Sep 17 00:00:00 2001\n",
+    "From: Billie Thompson <billie@example.com>\n",
+    "Date: Sat, 13 Jun 2020 19:30:00 +0100\n",
+    "Subject: [PATCH v2 0/2] Read config from the repository\n",
+    "\n",
+    "This moves the config into the repository.\n",
+    "\n",
+    "Billie Thompson (2):\n",
+    "  Add a config file\n",
+    "  read the config.\n",
+    "\n",
+    "-- \n",
+    "2.39.0\n",
+    "\n",
+    "From 5f3c0a1e6d2b4a8f9c7e1d3b5a7c9e0f2d4b6a81 Mon Sep 17 00:00:00 2001\n",
+    "From: =?UTF-8?q?Bj=C3=B6rn_Ek?= <bjorn@example.com>\n",
+    "Date: Sat, 13 Jun 2020 19:31:00 +0100\n",
+    "Subject: [PATCH v2 1/2] Add a config file\n",
+    "MIME-Version: 1.0\n",
+    "Content-Type: text/plain; charset=UTF-8\n",
+    "Content-Transfer-Encoding: 8bit\n",
+    "\n",
+    "From now on the config is kept with the code.\n",
+    "\n",
+    "Co-authored-by: Billie Thompson <billie@example.com>\n",
+    "---\n",
+    " .mit-commit.toml | 1 +\n",
+    " 1 file changed, 1 insertion(+)\n",
+    "\n",
+    "diff --git a/.mit-commit.toml b/.mit-commit.toml\n",
+    "new file mode 100644\n",
+    "--- /dev/null\n",
+    "+++ b/.mit-commit.toml\n",
+    "@@ -0,0 +1 @@\n",
+    "+[lint]\n",
+    "-- \n",
+    "2.39.0\n",
+    "\n",
+    "From 0e8d6c4b2a0f8e6d4c2b0a9f7e5d3c1b9a7f5e3d Mon Sep 17 00:00:00 2001\n",
+    "From: Billie Thompson <billie@example.com>\n",
+    "Date: Sat, 13 Jun 2020 19:32:00 +0100\n",
+    "Subject: [PATCH v2 2/2] read the config.\n",
+    "\n",
+    "---\n",
+    "diff --git a/src/main.rs b/src/main.rs\n",
+    "--- a/src/main.rs\n",
+    "+++ b/src/main.rs\n",
+    "@@ -1 +1 @@\n",
+    "-fn main() {}\n",
+    "+fn main() { config(); }\n",
+    "-- \n",
+    "2.39.0\n",
+    "\n",
+);
+
+#[test]
+fn a_series_is_split_into_patches() {
+    let mbox = Mbox::try_from(SERIES).expect("the series is valid");
+
+    assert_eq!(
+        mbox.iter()
+            .map(|patch| (patch.get_number(), patch.get_total(), patch.get_version()))
+            .collect::<Vec<_>>(),
+        vec![
+            (Some(0), Some(2), Some(2)),
+            (Some(1), Some(2), Some(2)),
+            (Some(2), Some(2), Some(2))
+        ]
+    );
+    assert_eq!(
+        mbox.get_patches()[0].get_diff(),
+        None,
+        "The cover letter has no diff"
+    );
+    assert!(
+        !String::from(mbox.get_patches()[0].get_message()).contains("2.39.0"),
+        "The signature isn't part of the message"
+    );
+}
+
+#[test]
+fn the_commit_message_is_put_back_together() {
+    let mbox = Mbox::try_from(SERIES).expect("the series is valid");
+    let patch = &mbox.get_patches()[1];
+
+    assert_eq!(
+        String::from(patch.get_message()),
+        concat!(
+            "Add a config file\n",
+            "\n",
+            "From now on the config is kept with the code.\n",
+            "\n",
+            "Co-authored-by: Billie Thompson <billie@example.com>\n",
+        )
+    );
+    assert_eq!(
+        patch.get_from().map(|from| from.get_name()),
+        Some("Björn Ek")
+    );
+    assert_eq!(
+        patch.get_header("content-type"),
+        Some("text/plain; charset=UTF-8")
+    );
+    assert_eq!(
+        patch.get_notes(),
+        Some(" .mit-commit.toml | 1 +\n 1 file changed, 1 insertion(+)\n\n")
+    );
+    assert_eq!(
+        patch.get_diff().map(|diff| diff.lines().last()),
+        Some(Some("+[lint]")),
+        "The signature isn't part of the diff"
+    );
+}
+
+#[test]
+fn lints_run_on_emailed_patches() {
+    let mbox = Mbox::try_from(SERIES).expect("the series is valid");
+    let registry = Registry::default().with_subject_lints();
+
+    assert_eq!(
+        mbox.iter()
+            .map(|patch| {
+                registry
+                    .run(patch.get_message())
+                    .iter()
+                    .map(|problem| problem.get_code().to_string())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>(),
+        vec![
+            vec![],
+            vec![],
+            vec![
+                "subject-trailing-period".to_string(),
+                "subject-capitalised".to_string()
+            ]
+        ]
+    );
+}
+
+#[test]
+fn patches_can_outlive_the_email() {
+    let patch = {
+        let email = String::from("Subject: [PATCH] Add a readme\n\nThe body\n");
+        Patch::try_from(email.as_str())
+            .expect("the patch is valid")
+            .into_owned()
+    };
+
+    assert_eq!(
+        patch.get_message().get_subject(),
+        Subject::from("Add a readme")
+    );
+}
+
+#[test]
+fn commits_are_written_as_emails() {
+    let commit = RawCommit::try_from(concat!(
+        "tree 4b825dc642cb6eb9a060e54bf8d69288fbee4904\n",
+        "author Björn Ek <bjorn@example.com> 1592073120 +0100\n",
+        "committer Björn Ek <bjorn@example.com> 1592073120 +0100\n",
+        "\n",
+        "Add a café to the menu\n",
+        "\n",
+        "Everyone wanted coffee.\n",
+    ))
+    .expect("the object is valid");
+    let author = commit
+        .get_header("author")
+        .map(Signature::try_from)
+        .expect("there is an author")
+        .expect("the author is valid");
+    let patch = Patch::new(&author, commit.get_message())
+        .with_number(1, 2)
+        .with_diff("diff --git a/menu b/menu\n".into());
+
+    assert_eq!(
+        String::from(&patch),
+        concat!(
+            "From 0000000000000000000000000000000000000000 Mon Sep 17 00:00:00 2001\n",
+            "From: =?UTF-8?q?Bj=C3=B6rn=20Ek?= <bjorn@example.com>\n",
+            "Date: Sat, 13 Jun 2020 19:32:00 +0100\n",
+            "Subject: [PATCH 1/2] =?UTF-8?q?Add=20a=20caf=C3=A9=20to=20the=20menu?=\n",
+            "MIME-Version: 1.0\n",
+            "Content-Type: text/plain; charset=UTF-8\n",
+            "Content-Transfer-Encoding: 8bit\n",
+            "\n",
+            "Everyone wanted coffee.\n",
+            "---\n",
+            "diff --git a/menu b/menu\n",
+        )
+    );
+}
+
+#[test]
+fn emails_match_git_format_patch() {
+    // From `git format-patch -2 --stdout --zero-commit --no-signature`, with
+    // git 2.39
+    let expected = concat!(
+        "From 0000000000000000000000000000000000000000 Mon Sep 17 00:00:00 2001\n",
+        "From: =?UTF-8?q?Bj=C3=B6rn=20Ek?= <bjorn@example.com>\n",
+        "Date: Sat, 13 Jun 2020 19:32:00 +0100\n",
+        "Subject: [PATCH 1/2] =?UTF-8?q?Add=20a=20caf=C3=A9=20to=20the=20menu?=\n",
+        "MIME-Version: 1.0\n",
+        "Content-Type: text/plain; charset=UTF-8\n",
+        "Content-Transfer-Encoding: 8bit\n",
+        "\n",
+        "Everyone wanted coffee.\n",
+        "---\n",
+        " menu | 1 +\n",
+        " 1 file changed, 1 insertion(+)\n",
+        "\n",
+        "diff --git a/menu b/menu\n",
+        "index a01ebc3..4ce6411 100644\n",
+        "--- a/menu\n",
+        "+++ b/menu\n",
+        "@@ -1 +1,2 @@\n",
+        " tea\n",
+        "+coffee\n",
+        "\n",
+        "From 0000000000000000000000000000000000000000 Mon Sep 17 00:00:00 2001\n",
+        "From: =?UTF-8?q?Bj=C3=B6rn=20Ek=2C=20Head=20of=20Coffee=20=28the=5Fboss=29?=\n",
+        " <bjorn@example.com>\n",
+        "Date: Sat, 13 Jun 2020 14:02:00 -0530\n",
+        "Subject: [PATCH 2/2] =?UTF-8?q?Serve=20the=20caf=C3=A9's=20coffee=20in=20t?=\n",
+        " =?UTF-8?q?he=20mugs=20that=20came=20with=20the=20new=20espresso=20machine?=\n",
+        " =?UTF-8?q?=3F?=\n",
+        "MIME-Version: 1.0\n",
+        "Content-Type: text/plain; charset=UTF-8\n",
+        "Content-Transfer-Encoding: 8bit\n",
+        "\n",
+        "That is what they're for, and a=b.\n",
+        "---\n",
+        " menu | 2 +-\n",
+        " 1 file changed, 1 insertion(+), 1 deletion(-)\n",
+        "\n",
+        "diff --git a/menu b/menu\n",
+        "index 4ce6411..7dc033f 100644\n",
+        "--- a/menu\n",
+        "+++ b/menu\n",
+        "@@ -1,2 +1,2 @@\n",
+        " tea\n",
+        "-coffee\n",
+        "+coffee in mugs\n",
+    );
+    let commits = [
+        (
+            "Björn Ek <bjorn@example.com> 1592073120 +0100",
+            "Add a café to the menu\n\nEveryone wanted coffee.\n",
+            " menu | 1 +\n 1 file changed, 1 insertion(+)\n\n",
+            concat!(
+                "diff --git a/menu b/menu\n",
+                "index a01ebc3..4ce6411 100644\n",
+                "--- a/menu\n",
+                "+++ b/menu\n",
+                "@@ -1 +1,2 @@\n",
+                " tea\n",
+                "+coffee\n",
+            ),
+        ),
+        (
+            "Björn Ek, Head of Coffee (the_boss) <bjorn@example.com> 1592076720 -0530",
+            concat!(
+                "Serve the café's coffee in the mugs that came with the new espresso machine?\n",
+                "\n",
+                "That is what they're for, and a=b.\n",
+            ),
+            " menu | 2 +-\n 1 file changed, 1 insertion(+), 1 deletion(-)\n\n",
+            concat!(
+                "diff --git a/menu b/menu\n",
+                "index 4ce6411..7dc033f 100644\n",
+                "--- a/menu\n",
+                "+++ b/menu\n",
+                "@@ -1,2 +1,2 @@\n",
+                " tea\n",
+                "-coffee\n",
+                "+coffee in mugs\n",
+            ),
+        ),
+    ];
+    let emails = (1..)
+        .zip(commits)
+        .map(|(number, (author, message, notes, diff))| {
+            let author = Signature::try_from(author).expect("the author is valid");
+
+            String::from(
+                Patch::new(&author, CommitMessage::from(message))
+                    .with_number(number, 2)
+                    .with_notes(notes.into())
+                    .with_diff(diff.into()),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    // git puts an empty line between the patches in a series
+    assert_eq!(emails.join("\n"), expected);
+}
+
+#[test]
+fn emails_read_back_the_same() {
+    let mbox = Mbox::try_from(SERIES).expect("the series is valid");
+
+    for patch in &mbox {
+        let email = String::from(patch);
+        let read = Patch::try_from(email.as_str())
+            .expect("the email is valid")
+            .into_owned();
+
+        assert_eq!(read.get_message(), patch.get_message());
+        assert_eq!(read.get_from(), patch.get_from());
+        assert_eq!(read.get_date(), patch.get_date());
+        assert_eq!(read.get_subject_prefix(), patch.get_subject_prefix());
+        assert_eq!(read.get_notes(), patch.get_notes());
+        assert_eq!(read.get_diff(), patch.get_diff());
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn subjects_and_names_read_back_the_same(subject: String, name: String) -> bool {
+    let subject = subject.split_whitespace().collect::<Vec<_>>().join(" ");
+    let name = name.split_whitespace().collect::<Vec<_>>().join(" ");
+    if subject.is_empty()
+        || name.is_empty()
+        || subject.starts_with('[')
+        || subject
+            .get(..3)
+            .is_some_and(|start| start.eq_ignore_ascii_case("re:"))
+        || name.contains(['<', '>'])
+    {
+        return true;
+    }
+
+    let author = format!("{name} <billie@example.com> 1592073120 +0100");
+    let Ok(author) = Signature::try_from(author.as_str()) else {
+        return true;
+    };
+    let message = format!("{subject}\n");
+    let message = CommitMessage::parse_with(
+        &message,
+        ParseOptions::default().with_comment_marker(CommentMarker::Disabled),
+    );
+    let email = String::from(Patch::new(&author, message));
+
+    Patch::try_from(email.as_str()).is_ok_and(|patch| {
+        patch.get_message().get_subject().to_string() == subject
+            && patch.get_from().map(|from| from.get_name()) == Some(name.as_str())
+    })
+}
+
+#[allow(unused_must_use)]
+#[quickcheck]
+fn never_panic(input: String) -> bool {
+    Mbox::try_from(input.as_str());
+    true
+}
@@ -0,0 +1,133 @@
+use std::convert::TryFrom;
+
+use mit_commit::{CommitHeader, Fragment, RawCommit, RawCommitError, Subject};
+
+const SIGNED_MERGE: &str = concat!(
+    "tree 9bedf67800b2923982bdf60c89c57ce6bda2bb39\n",
+    "parent 5f3c0a1e6d2b4a8f9c7e1d3b5a7c9e0f2d4b6a81\n",
+    "parent 0e8d6c4b2a0f8e6d4c2b0a9f7e5d3c1b9a7f5e3d\n",
+    "author Billie Thompson <billie@example.com> 1592073120 +0100\n",
+    "committer Someone Else <someone@example.com> 1592073180 +0000\n",
+    "mergetag object 0e8d6c4b2a0f8e6d4c2b0a9f7e5d3c1b9a7f5e3d\n",
+    " type commit\n",
+    " tag v1.2.0\n",
+    " tagger Billie Thompson <billie@example.com> 1592073000 +0100\n",
+    " \n",
+    " Release v1.2.0\n",
+    " -----BEGIN PGP SIGNATURE-----\n",
+    " \n",
+    " iHUEABYKAB0WIQTtag\n",
+    " -----END PGP SIGNATURE-----\n",
+    "gpgsig -----BEGIN PGP SIGNATURE-----\n",
+    " \n",
+    " iHUEABYKAB0WIQTcommit\n",
+    " =XyZ1\n",
+    " -----END PGP SIGNATURE-----\n",
+    "\n",
+    "Merge tag 'v1.2.0'\n",
+    "\n",
+    "Release v1.2.0\n",
+    "\n",
+    "# gpg: Signature made Sat 13 Jun 2020 19:30:00 BST\n",
+    "Signed-off-by: Someone Else <someone@example.com>\n",
+);
+
+#[test]
+fn headers_are_read_in_order() {
+    let commit = RawCommit::try_from(SIGNED_MERGE).expect("the object is valid");
+
+    assert_eq!(
+        commit
+            .get_headers()
+            .iter()
+            .map(CommitHeader::get_key)
+            .collect::<Vec<_>>(),
+        vec![
+            "tree",
+            "parent",
+            "parent",
+            "author",
+            "committer",
+            "mergetag",
+            "gpgsig"
+        ]
+    );
+    assert_eq!(
+        commit.get_parents(),
+        vec![
+            "5f3c0a1e6d2b4a8f9c7e1d3b5a7c9e0f2d4b6a81",
+            "0e8d6c4b2a0f8e6d4c2b0a9f7e5d3c1b9a7f5e3d"
+        ]
+    );
+    assert_eq!(
+        commit.get_author(),
+        Some("Billie Thompson <billie@example.com> 1592073120 +0100")
+    );
+    assert_eq!(
+        commit.get_committer(),
+        Some("Someone Else <someone@example.com> 1592073180 +0000")
+    );
+    assert_eq!(commit.get_encoding(), None);
+}
+
+#[test]
+fn continuation_lines_are_joined() {
+    let commit = RawCommit::try_from(SIGNED_MERGE).expect("the object is valid");
+
+    assert_eq!(
+        commit.get_gpgsig(),
+        Some(
+            "-----BEGIN PGP SIGNATURE-----\n\niHUEABYKAB0WIQTcommit\n=XyZ1\n-----END PGP SIGNATURE-----"
+        )
+    );
+    assert_eq!(
+        commit.get_mergetags(),
+        vec![concat!(
+            "object 0e8d6c4b2a0f8e6d4c2b0a9f7e5d3c1b9a7f5e3d\n",
+            "type commit\n",
+            "tag v1.2.0\n",
+            "tagger Billie Thompson <billie@example.com> 1592073000 +0100\n",
+            "\n",
+            "Release v1.2.0\n",
+            "-----BEGIN PGP SIGNATURE-----\n",
+            "\n",
+            "iHUEABYKAB0WIQTtag\n",
+            "-----END PGP SIGNATURE-----"
+        )]
+    );
+}
+
+#[test]
+fn the_message_has_no_comments() {
+    let commit = RawCommit::try_from(SIGNED_MERGE).expect("the object is valid");
+    let message = commit.get_message();
+
+    assert_eq!(message.get_subject(), Subject::from("Merge tag 'v1.2.0'"));
+    assert!(
+        message
+            .get_ast()
+            .iter()
+            .all(|fragment| matches!(fragment, Fragment::Body(_))),
+        "Lines starting with # should be part of the message"
+    );
+    assert!(String::from(&message).contains("# gpg: Signature made"));
+}
+
+#[test]
+fn objects_serialise_byte_for_byte() {
+    let commit = RawCommit::try_from(SIGNED_MERGE).expect("the object is valid");
+
+    assert_eq!(String::from(&commit), SIGNED_MERGE);
+    assert_eq!(String::from(commit.into_owned()), SIGNED_MERGE);
+}
+
+#[test]
+fn malformed_headers_are_errors() {
+    let error = RawCommit::try_from("tree 9bedf678\nparent\n\nSubject\n")
+        .expect_err("the parent has no value");
+
+    assert!(
+        matches!(error, RawCommitError::MissingValue(..)),
+        "Unexpected error {error:?}"
+    );
+}
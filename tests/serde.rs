@@ -0,0 +1,155 @@
+#![cfg(feature = "serde")]
+
+use indoc::indoc;
+use mit_commit::{
+    Bodies, Body, Comment, Comments, CommitMessage, Fragment, ParseOptions, Scissors, Subject,
+    Trailer, Trailers,
+};
+use quickcheck_macros::quickcheck;
+use serde_json::json;
+
+const COMMIT_WITH_ALL_FEATURES: &str = indoc!(
+    "
+    Add a feature
+
+    It does a thing.
+
+    Relates-to: #128
+    # A comment
+    # ------------------------ >8 ------------------------
+    # Do not modify or remove the line above.
+    # Everything below it will be ignored.
+    diff --git a/.bashrc b/.bashrc
+    "
+);
+
+#[test]
+fn commit_messages_have_a_stable_shape() {
+    let commit = CommitMessage::from(COMMIT_WITH_ALL_FEATURES);
+
+    assert_eq!(
+        serde_json::to_value(&commit).expect("can serialise"),
+        json!({
+            "ast": [
+                { "body": "Add a feature" },
+                { "body": "" },
+                { "body": "It does a thing." },
+                { "body": "" },
+                { "body": "Relates-to: #128" },
+                { "comment": "# A comment" }
+            ],
+            "scissors": "# ------------------------ >8 ------------------------\n# Do not modify or remove the line above.\n# Everything below it will be ignored.\ndiff --git a/.bashrc b/.bashrc\n",
+            "options": {
+                "comment_marker": "guess",
+                "trailer_detection": "strict",
+                "trailer_separators": null
+            },
+            "subject": "Add a feature",
+            "bodies": ["", "It does a thing."],
+            "trailers": [{ "key": "Relates-to", "value": "#128", "separator": ": " }],
+            "comments": ["# A comment"]
+        })
+    );
+}
+
+#[test]
+fn commit_messages_round_trip() {
+    let commit = CommitMessage::from(COMMIT_WITH_ALL_FEATURES);
+    let json = serde_json::to_string(&commit).expect("can serialise");
+    let deserialised: CommitMessage<'_> = serde_json::from_str(&json).expect("can deserialise");
+
+    assert_eq!(String::from(&deserialised), COMMIT_WITH_ALL_FEATURES);
+    assert_eq!(deserialised, commit);
+    assert_eq!(
+        deserialised
+            .get_trailers()
+            .iter()
+            .next()
+            .and_then(Trailer::get_span),
+        commit
+            .get_trailers()
+            .iter()
+            .next()
+            .and_then(Trailer::get_span),
+        "Spans should be worked out again"
+    );
+}
+
+#[test]
+fn parse_options_are_kept() {
+    let options = ParseOptions::default()
+        .with_comment_marker("//".into())
+        .with_trailer_separators("#");
+    let commit = CommitMessage::parse_with("Subject\n\nFixes #1\n// A comment\n", options);
+    let deserialised: CommitMessage<'_> =
+        serde_json::from_str(&serde_json::to_string(&commit).expect("can serialise"))
+            .expect("can deserialise");
+
+    assert_eq!(deserialised.get_options(), commit.get_options());
+    assert_eq!(deserialised.get_comments(), commit.get_comments());
+    assert_eq!(deserialised.get_trailers(), commit.get_trailers());
+}
+
+#[test]
+fn derived_parts_are_worked_out_again() {
+    let deserialised: CommitMessage<'_> = serde_json::from_value(json!({
+        "ast": [{ "body": "Subject" }, { "body": "" }, { "body": "Signed-off-by: Someone" }],
+        "subject": "Something else",
+        "trailers": []
+    }))
+    .expect("can deserialise");
+
+    assert_eq!(deserialised.get_subject(), Subject::from("Subject"));
+    assert_eq!(
+        deserialised.get_trailers(),
+        Trailers::from(vec![Trailer::new("Signed-off-by".into(), "Someone".into())])
+    );
+}
+
+#[test]
+fn parts_serialise_on_their_own() {
+    assert_eq!(
+        serde_json::to_value(Trailer::new_with_separator(
+            "Fixes".into(),
+            "42".into(),
+            " #".into()
+        ))
+        .expect("can serialise"),
+        json!({ "key": "Fixes", "value": "42", "separator": " #" })
+    );
+    assert_eq!(
+        serde_json::to_value(Fragment::from(Comment::from("# A comment"))).expect("can serialise"),
+        json!({ "comment": "# A comment" })
+    );
+    assert_eq!(
+        serde_json::to_value(Bodies::from(vec![Body::from("One"), Body::from("Two")]))
+            .expect("can serialise"),
+        json!(["One", "Two"])
+    );
+    assert_eq!(
+        serde_json::to_value(Comments::from(vec![Comment::from("# One")])).expect("can serialise"),
+        json!(["# One"])
+    );
+    assert_eq!(
+        serde_json::from_value::<Scissors<'_>>(json!("# --- >8 ---\n")).expect("can deserialise"),
+        Scissors::from("# --- >8 ---\n")
+    );
+    assert_eq!(
+        serde_json::from_value::<Trailer<'_>>(
+            json!({ "key": "Relates-to", "value": "#128", "separator": ": " })
+        )
+        .expect("can deserialise"),
+        Trailer::new("Relates-to".into(), "#128".into())
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn any_message_round_trips(message: String) -> bool {
+    let commit = CommitMessage::from(message.as_str());
+    let deserialised: CommitMessage<'_> =
+        serde_json::from_str(&serde_json::to_string(&commit).expect("can serialise"))
+            .expect("can deserialise");
+
+    String::from(&deserialised) == String::from(&commit)
+}
@@ -0,0 +1,83 @@
+use indoc::indoc;
+use miette::Severity;
+use mit_commit::{
+    CommitMessage,
+    lint::{Problem, Registry, SubjectLength},
+};
+
+const GOOD_COMMIT: &str = indoc!(
+    "
+    Update bashrc to include kubernetes completions
+
+    This should make it easier to deploy things for the developers.
+
+    Co-authored-by: Billie Thompson <billie@example.com>
+
+    # Please enter the commit message for your changes. Lines starting
+    # with '#' will be ignored, and an empty message aborts the commit.
+    "
+);
+
+const BAD_COMMIT: &str = indoc!(
+    "
+    update bashrc to include kubernetes completions, helm and some other bits.
+    # Please enter the commit message for your changes. Lines starting
+    # with '#' will be ignored, and an empty message aborts the commit.
+    This should make it easier to deploy things for the developers.
+    "
+);
+
+fn labelled<'a>(message: &'a str, problem: &Problem) -> Vec<&'a str> {
+    problem
+        .get_labels()
+        .into_iter()
+        .map(|(_, span)| &message[span.get_offset()..span.get_end()])
+        .collect()
+}
+
+#[test]
+fn a_good_subject_has_no_problems() {
+    let registry = Registry::default().with_subject_lints();
+
+    assert_eq!(registry.run(&CommitMessage::from(GOOD_COMMIT)), vec![]);
+}
+
+#[test]
+fn each_problem_points_at_the_offending_characters() {
+    let registry = Registry::default().with_subject_lints();
+    let problems = registry.run(&CommitMessage::from(BAD_COMMIT));
+
+    assert_eq!(
+        problems
+            .iter()
+            .map(|problem| (problem.get_code(), labelled(BAD_COMMIT, problem)))
+            .collect::<Vec<_>>(),
+        vec![
+            ("subject-length", vec!["s."]),
+            ("subject-trailing-period", vec!["."]),
+            ("subject-capitalised", vec!["u"]),
+            (
+                "subject-blank-line",
+                vec![
+                    "# Please enter the commit message for your changes. Lines starting\n# with '#' will be ignored, and an empty message aborts the commit."
+                ]
+            ),
+        ]
+    );
+    assert_eq!(problems[0].get_severity(), Severity::Error);
+}
+
+#[test]
+fn limits_can_be_configured() {
+    let registry = Registry::default()
+        .with_subject_lints()
+        .with_lint(SubjectLength::default().with_hard_limit(100));
+    let problems = registry.run(&CommitMessage::from(BAD_COMMIT));
+
+    assert_eq!(problems[0].get_code(), "subject-length");
+    assert_eq!(problems[0].get_severity(), Severity::Warning);
+    assert_eq!(
+        labelled(BAD_COMMIT, &problems[0]),
+        vec!["elm and some other bits."]
+    );
+}
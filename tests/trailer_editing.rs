@@ -0,0 +1,244 @@
+use indoc::indoc;
+use mit_commit::{CommitMessage, IfExists, Trailer};
+
+const COMMIT_WITH_TRAILERS: &str = indoc!(
+    "
+    Update bashrc to include kubernetes completions
+
+    This should make it easier to deploy things for the developers.
+
+    Co-authored-by: Stale Pair <stale@example.com>
+    Relates-to: #128
+    Co-authored-by: Someone Else
+      <someone@example.com>
+
+    # Please enter the commit message for your changes. Lines starting
+    # with '#' will be ignored, and an empty message aborts the commit.
+    #
+    # On branch main
+    # ------------------------ >8 ------------------------
+    # Do not modify or remove the line above.
+    # Everything below it will be ignored.
+    diff --git a/.bashrc b/.bashrc
+    "
+);
+
+#[test]
+fn removing_trailers_keeps_the_rest_of_the_message() {
+    let message = CommitMessage::from(COMMIT_WITH_TRAILERS).remove_trailers("Co-authored-by");
+
+    assert_eq!(
+        String::from(message),
+        indoc!(
+            "
+            Update bashrc to include kubernetes completions
+
+            This should make it easier to deploy things for the developers.
+
+            Relates-to: #128
+
+            # Please enter the commit message for your changes. Lines starting
+            # with '#' will be ignored, and an empty message aborts the commit.
+            #
+            # On branch main
+            # ------------------------ >8 ------------------------
+            # Do not modify or remove the line above.
+            # Everything below it will be ignored.
+            diff --git a/.bashrc b/.bashrc
+            "
+        )
+    );
+}
+
+#[test]
+fn removing_every_trailer_removes_the_empty_line_before_them() {
+    let message = CommitMessage::from(COMMIT_WITH_TRAILERS).retain_trailers(|_| false);
+
+    assert_eq!(
+        String::from(message.clone()),
+        indoc!(
+            "
+            Update bashrc to include kubernetes completions
+
+            This should make it easier to deploy things for the developers.
+
+            # Please enter the commit message for your changes. Lines starting
+            # with '#' will be ignored, and an empty message aborts the commit.
+            #
+            # On branch main
+            # ------------------------ >8 ------------------------
+            # Do not modify or remove the line above.
+            # Everything below it will be ignored.
+            diff --git a/.bashrc b/.bashrc
+            "
+        )
+    );
+    assert!(message.get_trailers().is_empty());
+}
+
+#[test]
+fn removing_the_last_line_keeps_the_message_without_a_final_newline() {
+    let commit = CommitMessage::from("Subject\n\nBody\n\nRelates-to: #128\nAcked-by: Someone");
+
+    assert_eq!(
+        String::from(commit.remove_trailers("Acked-by")),
+        "Subject\n\nBody\n\nRelates-to: #128"
+    );
+    assert_eq!(
+        String::from(commit.retain_trailers(|_| false)),
+        "Subject\n\nBody"
+    );
+}
+
+#[test]
+fn replacing_a_trailer_keeps_its_place() {
+    let message = CommitMessage::from(COMMIT_WITH_TRAILERS).replace_trailer("Relates-to", "#129");
+
+    assert_eq!(
+        message
+            .get_trailers()
+            .iter()
+            .map(Trailer::get_unfolded_value)
+            .collect::<Vec<_>>(),
+        vec![
+            "Stale Pair <stale@example.com>",
+            "#129",
+            "Someone Else <someone@example.com>",
+        ]
+    );
+    assert_eq!(message.get_comments().iter().count(), 1);
+    assert!(message.get_scissors().is_some());
+}
+
+#[test]
+fn replacing_a_folded_trailer_removes_its_continuation_lines() {
+    let message = CommitMessage::from(COMMIT_WITH_TRAILERS)
+        .replace_trailer("Co-authored-by", "Billie Thompson <billie@example.com>");
+
+    assert_eq!(
+        message
+            .get_trailers()
+            .iter()
+            .map(|trailer| String::from(trailer.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            "Co-authored-by: Billie Thompson <billie@example.com>",
+            "Relates-to: #128",
+        ]
+    );
+}
+
+#[test]
+fn replacing_a_missing_trailer_changes_nothing() {
+    let message = CommitMessage::from(COMMIT_WITH_TRAILERS).replace_trailer("Signed-off-by", "Me");
+
+    assert_eq!(String::from(message), COMMIT_WITH_TRAILERS);
+}
+
+#[test]
+fn upserting_a_missing_trailer_adds_it() {
+    let message = CommitMessage::from(COMMIT_WITH_TRAILERS).upsert_trailer(
+        Trailer::new("Signed-off-by".into(), "Me <me@example.com>".into()),
+        IfExists::DoNothing,
+    );
+
+    assert_eq!(
+        message
+            .get_trailers()
+            .iter()
+            .map(Trailer::get_key)
+            .collect::<Vec<_>>(),
+        vec![
+            "Co-authored-by",
+            "Relates-to",
+            "Co-authored-by",
+            "Signed-off-by"
+        ]
+    );
+}
+
+#[test]
+fn upserting_an_existing_trailer_follows_if_exists() {
+    let commit = CommitMessage::from(COMMIT_WITH_TRAILERS);
+    let trailer = Trailer::new("Relates-to".into(), "#129".into());
+
+    assert_eq!(
+        String::from(commit.upsert_trailer(trailer.clone(), IfExists::DoNothing)),
+        COMMIT_WITH_TRAILERS
+    );
+    assert_eq!(
+        commit
+            .upsert_trailer(trailer.clone(), IfExists::Replace)
+            .get_trailers()
+            .iter()
+            .filter(|existing| existing.get_key() == "Relates-to")
+            .map(Trailer::get_value)
+            .collect::<Vec<_>>(),
+        vec!["#129"]
+    );
+    assert_eq!(
+        commit
+            .upsert_trailer(trailer, IfExists::Add)
+            .get_trailers()
+            .iter()
+            .filter(|existing| existing.get_key() == "Relates-to")
+            .map(Trailer::get_value)
+            .collect::<Vec<_>>(),
+        vec!["#128", "#129"]
+    );
+}
+
+#[test]
+fn upserting_compares_trailers_ignoring_case() {
+    let commit = CommitMessage::from("Subject\n\nco-authored-by: Someone <someone@example.com>\n");
+    let trailer = Trailer::new(
+        "Co-authored-by".into(),
+        "Someone <SOMEONE@example.com>".into(),
+    );
+
+    assert_eq!(
+        String::from(commit.upsert_trailer(trailer.clone(), IfExists::AddIfDifferent)),
+        "Subject\n\nco-authored-by: Someone <someone@example.com>\n"
+    );
+    assert_eq!(
+        String::from(commit.upsert_trailer(trailer, IfExists::AddIfDifferentNeighbor)),
+        "Subject\n\nco-authored-by: Someone <someone@example.com>\n"
+    );
+}
+
+#[test]
+fn editing_in_one_pass_with_chained_calls() {
+    let message = CommitMessage::from(COMMIT_WITH_TRAILERS)
+        .remove_trailers("Co-authored-by")
+        .replace_trailer("Relates-to", "#129")
+        .upsert_trailer(
+            Trailer::new(
+                "Co-authored-by".into(),
+                "Current <current@example.com>".into(),
+            ),
+            IfExists::DoNothing,
+        );
+
+    assert_eq!(
+        String::from(message),
+        indoc!(
+            "
+            Update bashrc to include kubernetes completions
+
+            This should make it easier to deploy things for the developers.
+
+            Relates-to: #129
+            Co-authored-by: Current <current@example.com>
+
+            # Please enter the commit message for your changes. Lines starting
+            # with '#' will be ignored, and an empty message aborts the commit.
+            #
+            # On branch main
+            # ------------------------ >8 ------------------------
+            # Do not modify or remove the line above.
+            # Everything below it will be ignored.
+            diff --git a/.bashrc b/.bashrc
+            "
+        )
+    );
+}
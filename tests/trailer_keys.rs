@@ -0,0 +1,91 @@
+use indoc::indoc;
+use mit_commit::{CommitMessage, Trailer, TrailerAliases};
+
+const COMMIT_WITH_MIXED_CASE_KEYS: &str = indoc!(
+    "
+    Update bashrc to include kubernetes completions
+
+    This should make it easier to deploy things for the developers.
+
+    Co-Authored-By: Billie Thompson <billie@example.com>
+    coauthored-by: Someone Else
+      <someone@example.com>
+    co-authored-by: Another Person <another@example.com>
+    Relates-to: #128
+
+    # Please enter the commit message for your changes. Lines starting
+    # with '#' will be ignored, and an empty message aborts the commit.
+    "
+);
+
+#[test]
+fn it_looks_up_trailers_ignoring_case() {
+    let message = CommitMessage::from(COMMIT_WITH_MIXED_CASE_KEYS);
+    let trailers = message.get_trailers();
+
+    assert_eq!(
+        trailers.get("co-authored-by").map(Trailer::get_value),
+        Some("Billie Thompson <billie@example.com>".into())
+    );
+    assert_eq!(
+        trailers
+            .get_all("CO-AUTHORED-BY")
+            .into_iter()
+            .map(Trailer::get_value)
+            .collect::<Vec<_>>(),
+        vec![
+            "Billie Thompson <billie@example.com>",
+            "Another Person <another@example.com>"
+        ]
+    );
+    assert_eq!(trailers.get("Signed-off-by"), None);
+}
+
+#[test]
+fn it_canonicalises_keys_and_keeps_everything_else() {
+    let aliases = TrailerAliases::default().with_alias("Coauthored-by", "Co-authored-by");
+    let message = CommitMessage::from(COMMIT_WITH_MIXED_CASE_KEYS).canonicalise_trailers(&aliases);
+
+    assert_eq!(
+        String::from(message),
+        indoc!(
+            "
+            Update bashrc to include kubernetes completions
+
+            This should make it easier to deploy things for the developers.
+
+            Co-authored-by: Billie Thompson <billie@example.com>
+            Co-authored-by: Someone Else
+              <someone@example.com>
+            Co-authored-by: Another Person <another@example.com>
+            Relates-to: #128
+
+            # Please enter the commit message for your changes. Lines starting
+            # with '#' will be ignored, and an empty message aborts the commit.
+            "
+        )
+    );
+}
+
+#[test]
+fn it_finds_aliased_trailers_after_canonicalising() {
+    let aliases = TrailerAliases::default().with_alias("Coauthored-by", "Co-authored-by");
+    let message = CommitMessage::from(COMMIT_WITH_MIXED_CASE_KEYS).canonicalise_trailers(&aliases);
+
+    assert_eq!(message.get_trailers().get_all("Co-authored-by").len(), 3);
+}
+
+#[test]
+fn it_removes_trailers_ignoring_case() {
+    let message =
+        CommitMessage::from(COMMIT_WITH_MIXED_CASE_KEYS).remove_trailers("CO-AUTHORED-BY");
+
+    assert_eq!(
+        message
+            .get_trailers()
+            .iter()
+            .map(Trailer::get_key)
+            .collect::<Vec<_>>(),
+        vec!["coauthored-by", "Relates-to"]
+    );
+}
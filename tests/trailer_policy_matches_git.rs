@@ -0,0 +1,622 @@
+//! The expected messages in these tests were generated by running
+//! `git interpret-trailers --where <where> --if-exists <action>
+//! --if-missing <action> --trailer <trailer>` on each message
+
+use mit_commit::{CommitMessage, IfExists, IfMissing, Trailer, TrailerPolicy, Where};
+
+const WITH_TRAILERS: &str =
+    "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n";
+const WITHOUT_TRAILERS: &str = "Subject\n\nBody text\n";
+const UNUSUAL_TRAILERS: &str =
+    "Subject\n\nReviewed-by : X\nfix:2\n# An inner comment\nSigned-off-by: Me\n  continued\n";
+
+type Case = (
+    &'static str,
+    Where,
+    IfExists,
+    IfMissing,
+    (&'static str, &'static str),
+    &'static str,
+);
+
+const CASES: &[Case] = &[
+    (
+        WITH_TRAILERS,
+        Where::End,
+        IfExists::AddIfDifferent,
+        IfMissing::Add,
+        ("Acked-by", "B"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::End,
+        IfExists::AddIfDifferent,
+        IfMissing::Add,
+        ("Acked-by", "C"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\nAcked-by: C\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::End,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::Add,
+        ("Acked-by", "B"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::End,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::Add,
+        ("Acked-by", "C"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\nAcked-by: C\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::End,
+        IfExists::Add,
+        IfMissing::Add,
+        ("Acked-by", "B"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::End,
+        IfExists::Add,
+        IfMissing::Add,
+        ("Acked-by", "C"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\nAcked-by: C\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::End,
+        IfExists::Replace,
+        IfMissing::Add,
+        ("Acked-by", "B"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::End,
+        IfExists::Replace,
+        IfMissing::Add,
+        ("Acked-by", "C"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: C\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::End,
+        IfExists::DoNothing,
+        IfMissing::Add,
+        ("Acked-by", "B"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::End,
+        IfExists::DoNothing,
+        IfMissing::Add,
+        ("Acked-by", "C"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Start,
+        IfExists::AddIfDifferent,
+        IfMissing::Add,
+        ("Acked-by", "B"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Start,
+        IfExists::AddIfDifferent,
+        IfMissing::Add,
+        ("Acked-by", "C"),
+        "Subject\n\nBody text\n\nAcked-by: C\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Start,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::Add,
+        ("Acked-by", "B"),
+        "Subject\n\nBody text\n\nAcked-by: B\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Start,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::Add,
+        ("Acked-by", "C"),
+        "Subject\n\nBody text\n\nAcked-by: C\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Start,
+        IfExists::Add,
+        IfMissing::Add,
+        ("Acked-by", "B"),
+        "Subject\n\nBody text\n\nAcked-by: B\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Start,
+        IfExists::Add,
+        IfMissing::Add,
+        ("Acked-by", "C"),
+        "Subject\n\nBody text\n\nAcked-by: C\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Start,
+        IfExists::Replace,
+        IfMissing::Add,
+        ("Acked-by", "B"),
+        "Subject\n\nBody text\n\nAcked-by: B\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Start,
+        IfExists::Replace,
+        IfMissing::Add,
+        ("Acked-by", "C"),
+        "Subject\n\nBody text\n\nAcked-by: C\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Start,
+        IfExists::DoNothing,
+        IfMissing::Add,
+        ("Acked-by", "B"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Start,
+        IfExists::DoNothing,
+        IfMissing::Add,
+        ("Acked-by", "C"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::After,
+        IfExists::AddIfDifferent,
+        IfMissing::Add,
+        ("Acked-by", "B"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::After,
+        IfExists::AddIfDifferent,
+        IfMissing::Add,
+        ("Acked-by", "C"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\nAcked-by: C\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::After,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::Add,
+        ("Acked-by", "B"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::After,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::Add,
+        ("Acked-by", "C"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\nAcked-by: C\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::After,
+        IfExists::Add,
+        IfMissing::Add,
+        ("Acked-by", "B"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::After,
+        IfExists::Add,
+        IfMissing::Add,
+        ("Acked-by", "C"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\nAcked-by: C\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::After,
+        IfExists::Replace,
+        IfMissing::Add,
+        ("Acked-by", "B"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::After,
+        IfExists::Replace,
+        IfMissing::Add,
+        ("Acked-by", "C"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: C\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::After,
+        IfExists::DoNothing,
+        IfMissing::Add,
+        ("Acked-by", "B"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::After,
+        IfExists::DoNothing,
+        IfMissing::Add,
+        ("Acked-by", "C"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Before,
+        IfExists::AddIfDifferent,
+        IfMissing::Add,
+        ("Acked-by", "B"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Before,
+        IfExists::AddIfDifferent,
+        IfMissing::Add,
+        ("Acked-by", "C"),
+        "Subject\n\nBody text\n\nAcked-by: C\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Before,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::Add,
+        ("Acked-by", "B"),
+        "Subject\n\nBody text\n\nAcked-by: B\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Before,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::Add,
+        ("Acked-by", "C"),
+        "Subject\n\nBody text\n\nAcked-by: C\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Before,
+        IfExists::Add,
+        IfMissing::Add,
+        ("Acked-by", "B"),
+        "Subject\n\nBody text\n\nAcked-by: B\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Before,
+        IfExists::Add,
+        IfMissing::Add,
+        ("Acked-by", "C"),
+        "Subject\n\nBody text\n\nAcked-by: C\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Before,
+        IfExists::Replace,
+        IfMissing::Add,
+        ("Acked-by", "B"),
+        "Subject\n\nBody text\n\nAcked-by: B\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Before,
+        IfExists::Replace,
+        IfMissing::Add,
+        ("Acked-by", "C"),
+        "Subject\n\nBody text\n\nAcked-by: C\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Before,
+        IfExists::DoNothing,
+        IfMissing::Add,
+        ("Acked-by", "B"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Before,
+        IfExists::DoNothing,
+        IfMissing::Add,
+        ("Acked-by", "C"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::End,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::DoNothing,
+        ("Reviewed-by", "Z"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITHOUT_TRAILERS,
+        Where::End,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::DoNothing,
+        ("Reviewed-by", "Z"),
+        "Subject\n\nBody text\n\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::End,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::Add,
+        ("Reviewed-by", "Z"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\nReviewed-by: Z\n\n# A comment\n",
+    ),
+    (
+        WITHOUT_TRAILERS,
+        Where::End,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::Add,
+        ("Reviewed-by", "Z"),
+        "Subject\n\nBody text\n\nReviewed-by: Z\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Start,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::DoNothing,
+        ("Reviewed-by", "Z"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITHOUT_TRAILERS,
+        Where::Start,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::DoNothing,
+        ("Reviewed-by", "Z"),
+        "Subject\n\nBody text\n\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Start,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::Add,
+        ("Reviewed-by", "Z"),
+        "Subject\n\nBody text\n\nReviewed-by: Z\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITHOUT_TRAILERS,
+        Where::Start,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::Add,
+        ("Reviewed-by", "Z"),
+        "Subject\n\nBody text\n\nReviewed-by: Z\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::After,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::DoNothing,
+        ("Reviewed-by", "Z"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITHOUT_TRAILERS,
+        Where::After,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::DoNothing,
+        ("Reviewed-by", "Z"),
+        "Subject\n\nBody text\n\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::After,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::Add,
+        ("Reviewed-by", "Z"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\nReviewed-by: Z\n\n# A comment\n",
+    ),
+    (
+        WITHOUT_TRAILERS,
+        Where::After,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::Add,
+        ("Reviewed-by", "Z"),
+        "Subject\n\nBody text\n\nReviewed-by: Z\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Before,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::DoNothing,
+        ("Reviewed-by", "Z"),
+        "Subject\n\nBody text\n\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITHOUT_TRAILERS,
+        Where::Before,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::DoNothing,
+        ("Reviewed-by", "Z"),
+        "Subject\n\nBody text\n\n",
+    ),
+    (
+        WITH_TRAILERS,
+        Where::Before,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::Add,
+        ("Reviewed-by", "Z"),
+        "Subject\n\nBody text\n\nReviewed-by: Z\nAcked-by: A\nFixes: #1\nAcked-by: B\n\n# A comment\n",
+    ),
+    (
+        WITHOUT_TRAILERS,
+        Where::Before,
+        IfExists::AddIfDifferentNeighbor,
+        IfMissing::Add,
+        ("Reviewed-by", "Z"),
+        "Subject\n\nBody text\n\nReviewed-by: Z\n",
+    ),
+    (
+        UNUSUAL_TRAILERS,
+        Where::End,
+        IfExists::Replace,
+        IfMissing::Add,
+        ("FIX", "3"),
+        "Subject\n\nReviewed-by: X\nSigned-off-by: Me\n  continued\nFIX: 3\n",
+    ),
+    (
+        UNUSUAL_TRAILERS,
+        Where::End,
+        IfExists::AddIfDifferent,
+        IfMissing::Add,
+        ("reviewed-by", "x"),
+        "Subject\n\nReviewed-by: X\nfix: 2\nSigned-off-by: Me\n  continued\n",
+    ),
+    (
+        UNUSUAL_TRAILERS,
+        Where::Start,
+        IfExists::Replace,
+        IfMissing::Add,
+        ("FIX", "3"),
+        "Subject\n\nFIX: 3\nReviewed-by: X\nSigned-off-by: Me\n  continued\n",
+    ),
+    (
+        UNUSUAL_TRAILERS,
+        Where::Start,
+        IfExists::AddIfDifferent,
+        IfMissing::Add,
+        ("reviewed-by", "x"),
+        "Subject\n\nReviewed-by: X\nfix: 2\nSigned-off-by: Me\n  continued\n",
+    ),
+    (
+        UNUSUAL_TRAILERS,
+        Where::After,
+        IfExists::Replace,
+        IfMissing::Add,
+        ("FIX", "3"),
+        "Subject\n\nReviewed-by: X\nFIX: 3\nSigned-off-by: Me\n  continued\n",
+    ),
+    (
+        UNUSUAL_TRAILERS,
+        Where::After,
+        IfExists::AddIfDifferent,
+        IfMissing::Add,
+        ("reviewed-by", "x"),
+        "Subject\n\nReviewed-by: X\nfix: 2\nSigned-off-by: Me\n  continued\n",
+    ),
+    (
+        UNUSUAL_TRAILERS,
+        Where::Before,
+        IfExists::Replace,
+        IfMissing::Add,
+        ("FIX", "3"),
+        "Subject\n\nReviewed-by: X\nFIX: 3\nSigned-off-by: Me\n  continued\n",
+    ),
+    (
+        UNUSUAL_TRAILERS,
+        Where::Before,
+        IfExists::AddIfDifferent,
+        IfMissing::Add,
+        ("reviewed-by", "x"),
+        "Subject\n\nReviewed-by: X\nfix: 2\nSigned-off-by: Me\n  continued\n",
+    ),
+];
+
+fn add_like_git(message: &str, policy: TrailerPolicy, (key, value): (&str, &str)) -> String {
+    String::from(
+        CommitMessage::from(message)
+            .add_trailer_with_policy(Trailer::new(key.into(), value.into()), policy),
+    )
+}
+
+#[test]
+fn adding_trailers_matches_git_interpret_trailers() {
+    for (message, location, if_exists, if_missing, trailer, expected) in CASES {
+        let policy = TrailerPolicy::default()
+            .with_where(*location)
+            .with_if_exists(*if_exists)
+            .with_if_missing(*if_missing);
+
+        assert_eq!(
+            add_like_git(message, policy, *trailer),
+            *expected,
+            "Differs from git adding {trailer:?} with {policy:?} to {message:?}"
+        );
+    }
+}
+
+#[test]
+fn adding_to_an_empty_message_matches_git() {
+    assert_eq!(
+        add_like_git("", TrailerPolicy::default(), ("A", "b")),
+        "\nA: b\n"
+    );
+}
+
+#[test]
+fn adding_to_a_message_of_blank_lines_matches_git() {
+    assert_eq!(
+        add_like_git("\n", TrailerPolicy::default(), ("A", "b")),
+        "\nA: b\n"
+    );
+    assert_eq!(
+        add_like_git("\n\n", TrailerPolicy::default(), ("A", "b")),
+        "\nA: b\n\n"
+    );
+}
+
+#[test]
+fn adding_to_a_message_of_comments_matches_git() {
+    assert_eq!(
+        add_like_git("# A comment\n", TrailerPolicy::default(), ("A", "b")),
+        "# A comment\n\nA: b\n"
+    );
+    assert_eq!(
+        add_like_git("\n# A comment\n", TrailerPolicy::default(), ("A", "b")),
+        "\nA: b\n# A comment\n"
+    );
+}
+
+#[test]
+fn adding_to_a_message_without_a_trailing_newline_matches_git() {
+    assert_eq!(
+        add_like_git("Subject", TrailerPolicy::default(), ("A", "b")),
+        "Subject\nA: b\n"
+    );
+}
+
+#[test]
+fn adding_nothing_still_separates_the_block_like_git() {
+    assert_eq!(
+        add_like_git(
+            "Subject\n",
+            TrailerPolicy::default().with_if_missing(IfMissing::DoNothing),
+            ("A", "b")
+        ),
+        "Subject\n\n"
+    );
+}
+
+#[test]
+fn adding_keeps_the_scissors_section_like_git() {
+    assert_eq!(
+        add_like_git(
+            "Subject\n\nBody\n# ------------------------ >8 ------------------------\n# Do not modify or remove the line above.\ndiff --git a/file b/file\n",
+            TrailerPolicy::default(),
+            ("Relates-to", "#128")
+        ),
+        "Subject\n\nBody\n\nRelates-to: #128\n# ------------------------ >8 ------------------------\n# Do not modify or remove the line above.\ndiff --git a/file b/file\n"
+    );
+}
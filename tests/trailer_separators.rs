@@ -0,0 +1,89 @@
+use indoc::indoc;
+use mit_commit::{Bodies, Body, CommitMessage, ParseOptions, Trailer, Trailers};
+
+const COMMIT_WITH_CUSTOM_SEPARATORS: &str = indoc!(
+    "
+    Fix the login form
+
+    The password field was not being cleared.
+
+    Fixes=123
+    Key:value
+    Reviewed-by: Billie Thompson <billie@example.com>
+    "
+);
+
+fn options() -> ParseOptions {
+    ParseOptions::default().with_trailer_separators(":=")
+}
+
+#[test]
+fn it_round_trips_with_custom_separators() {
+    let message = CommitMessage::parse_with(COMMIT_WITH_CUSTOM_SEPARATORS, options());
+
+    assert_eq!(String::from(message), COMMIT_WITH_CUSTOM_SEPARATORS);
+}
+
+#[test]
+fn it_finds_trailers_with_custom_separators() {
+    let message = CommitMessage::parse_with(COMMIT_WITH_CUSTOM_SEPARATORS, options());
+
+    assert_eq!(
+        message.get_trailers(),
+        Trailers::from(vec![
+            Trailer::new_with_separator("Fixes".into(), "123".into(), "=".into()),
+            Trailer::new_with_separator("Key".into(), "value".into(), ":".into()),
+            Trailer::new(
+                "Reviewed-by".into(),
+                "Billie Thompson <billie@example.com>".into()
+            ),
+        ])
+    );
+}
+
+#[test]
+fn it_does_not_treat_trailers_with_custom_separators_as_body() {
+    let message = CommitMessage::parse_with(COMMIT_WITH_CUSTOM_SEPARATORS, options());
+
+    assert_eq!(
+        message.get_body(),
+        Bodies::from(vec![
+            Body::default(),
+            Body::from("The password field was not being cleared."),
+        ])
+    );
+}
+
+#[test]
+fn it_ignores_custom_separators_by_default() {
+    let message = CommitMessage::from(COMMIT_WITH_CUSTOM_SEPARATORS);
+
+    assert_eq!(
+        message.get_trailers(),
+        Trailers::from(vec![Trailer::new(
+            "Reviewed-by".into(),
+            "Billie Thompson <billie@example.com>".into()
+        )])
+    );
+}
+
+#[test]
+fn adding_a_trailer_keeps_the_custom_separators() {
+    let message = CommitMessage::parse_with(COMMIT_WITH_CUSTOM_SEPARATORS, options()).add_trailer(
+        Trailer::new_with_separator("Closes".into(), "456".into(), "=".into()),
+    );
+
+    assert_eq!(
+        message
+            .get_trailers()
+            .iter()
+            .map(|trailer| String::from(trailer.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            "Fixes=123",
+            "Key:value",
+            "Reviewed-by: Billie Thompson <billie@example.com>",
+            "Closes=456",
+        ]
+    );
+}
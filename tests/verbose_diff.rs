@@ -0,0 +1,143 @@
+use mit_commit::{
+    CommitMessage,
+    diff::{Diff, FileStatus, Line},
+};
+use quickcheck_macros::quickcheck;
+
+const VERBOSE_COMMIT: &str = concat!(
+    "Tidy up the scripts\n",
+    "\n",
+    "# Please enter the commit message for your changes. Lines starting\n",
+    "# with '#' will be ignored, and an empty message aborts the commit.\n",
+    "#\n",
+    "# ------------------------ >8 ------------------------\n",
+    "# Do not modify or remove the line above.\n",
+    "# Everything below it will be ignored.\n",
+    "diff --git a/build.sh b/scripts/build.sh\n",
+    "old mode 100644\n",
+    "new mode 100755\n",
+    "similarity index 75%\n",
+    "rename from build.sh\n",
+    "rename to scripts/build.sh\n",
+    "index 5f3c0a1..0e8d6c4\n",
+    "--- a/build.sh\n",
+    "+++ b/scripts/build.sh\n",
+    "@@ -1,3 +1,3 @@\n",
+    " #!/bin/sh\n",
+    "-cargo build\n",
+    "+cargo build --release\n",
+    "--- a line that only looks like a header\n",
+    "+++ another one\n",
+    "diff --git a/logo.png b/logo.png\n",
+    "index 5f3c0a1..0e8d6c4 100644\n",
+    "Binary files a/logo.png and b/logo.png differ\n",
+    "diff --git a/old.txt b/old.txt\n",
+    "deleted file mode 100644\n",
+    "index 5f3c0a1..0000000\n",
+    "--- a/old.txt\n",
+    "+++ /dev/null\n",
+    "@@ -1,2 +0,0 @@\n",
+    "-one\n",
+    "-two\n",
+    "diff --git a/new.txt b/new.txt\n",
+    "new file mode 100644\n",
+    "index 0000000..5f3c0a1\n",
+    "--- /dev/null\n",
+    "+++ b/new.txt\n",
+    "@@ -0,0 +1 @@\n",
+    "+one\n",
+    "\\ No newline at end of file\n",
+);
+
+#[test]
+fn the_verbose_diff_is_split_into_files() {
+    let commit = CommitMessage::from(VERBOSE_COMMIT);
+    let scissors = commit.get_scissors().expect("there is a scissors line");
+    let diff = scissors.diff();
+
+    assert_eq!(
+        diff.iter()
+            .map(|file| (file.get_status(), file.get_old_path(), file.get_new_path()))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                FileStatus::Renamed,
+                Some("build.sh"),
+                Some("scripts/build.sh")
+            ),
+            (FileStatus::Modified, Some("logo.png"), Some("logo.png")),
+            (FileStatus::Deleted, Some("old.txt"), None),
+            (FileStatus::Added, None, Some("new.txt")),
+        ]
+    );
+    assert_eq!(diff.get_added(), 3);
+    assert_eq!(diff.get_removed(), 4);
+}
+
+#[test]
+fn renames_keep_their_mode_changes_and_lines() {
+    let commit = CommitMessage::from(VERBOSE_COMMIT);
+    let scissors = commit.get_scissors().expect("there is a scissors line");
+    let diff = scissors.diff();
+    let rename = &diff.get_files()[0];
+    let hunk = &rename.get_hunks()[0];
+
+    assert_eq!(rename.get_similarity(), Some(75));
+    assert_eq!(rename.get_old_mode(), Some("100644"));
+    assert_eq!(rename.get_new_mode(), Some("100755"));
+    assert_eq!(
+        (
+            hunk.get_old_start(),
+            hunk.get_old_lines(),
+            hunk.get_new_start(),
+            hunk.get_new_lines()
+        ),
+        (1, 3, 1, 3)
+    );
+    assert_eq!(
+        hunk.get_lines(),
+        &[
+            Line::Context("#!/bin/sh".into()),
+            Line::Removed("cargo build".into()),
+            Line::Added("cargo build --release".into()),
+            Line::Removed("-- a line that only looks like a header".into()),
+            Line::Added("++ another one".into()),
+        ]
+    );
+    assert!(diff.get_files()[1].is_binary());
+}
+
+#[test]
+fn messages_without_a_diff_have_no_files() {
+    let commit = CommitMessage::from(concat!(
+        "Tidy up the scripts\n",
+        "\n",
+        "# ------------------------ >8 ------------------------\n",
+        "# Do not modify or remove the line above.\n",
+    ));
+    let scissors = commit.get_scissors().expect("there is a scissors line");
+
+    assert!(scissors.diff().get_files().is_empty());
+}
+
+#[test]
+fn diffs_can_outlive_the_message() {
+    let diff = {
+        let commit = CommitMessage::from(VERBOSE_COMMIT);
+        commit
+            .get_scissors()
+            .expect("there is a scissors line")
+            .diff()
+            .into_owned()
+    };
+
+    assert_eq!(diff.get_files().len(), 4);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn never_panic(input: String) -> bool {
+    let diff = Diff::from(format!("diff --git a/a b/a\n{input}").as_str()).into_owned();
+
+    diff.get_files().len() <= input.lines().count() + 1
+}